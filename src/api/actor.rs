@@ -0,0 +1,97 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+
+use futures::{Stream, TryStreamExt};
+
+use super::constants::{API_PATH_ACTOR, API_PATH_ACTORS, API_PATH_ACTOR_LOGS, API_PATH_ACTOR_SYNC};
+use super::data;
+use super::errors::ApiError;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{Client, EventSourceOptions};
+use crate::resource::ActorSpec;
+use crate::sync::Synchronization;
+
+/// Represents the lines of the log of an actor, read as they are written.
+pub type LogStream = Pin<Box<dyn Stream<Item = Result<String, ApiError>> + Send>>;
+
+/// Provides access to the actors of the playbooks.
+pub struct Actors<'a> {
+    pub client: &'a Client,
+}
+
+impl Actors<'_> {
+    /// Returns a list of the actors of a playbook.
+    pub async fn list(&self, pid: &str) -> Result<Vec<ActorSpec>, ApiError> {
+        let path = API_PATH_ACTORS.replace("{pid}", pid);
+        let res = self
+            .client
+            .get::<Vec<ActorSpec>>(&path, None)
+            .await
+            .map_err(ApiError::ClientError)?;
+
+        data(&path, res)
+    }
+
+    /// Returns an actor of a playbook.
+    pub async fn get(&self, pid: &str, name: &str) -> Result<ActorSpec, ApiError> {
+        let path = API_PATH_ACTOR.replace("{pid}", pid).replace("{name}", name);
+        let res = self
+            .client
+            .get::<ActorSpec>(&path, None)
+            .await
+            .map_err(ApiError::ClientError)?;
+
+        data(&path, res)
+    }
+
+    /// Streams the log of an actor, following it until the server closes the
+    /// connection. The log is not resumed, reconnecting would replay it.
+    pub fn logs(&self, pid: &str, name: &str) -> LogStream {
+        let path = API_PATH_ACTOR_LOGS.replace("{pid}", pid).replace("{name}", name);
+        let options = EventSourceOptions {
+            max_reconnects: Some(0),
+            ..Default::default()
+        };
+        let events = self.client.events(&path, options);
+
+        Box::pin(events.map_ok(|event| event.data).map_err(ApiError::ClientError))
+    }
+
+    /// Synchronizes the changes of the local files to an actor running in live mode.
+    pub async fn sync(
+        &self,
+        pid: &str,
+        name: &str,
+        synchronization: &Synchronization,
+    ) -> Result<(), ApiError> {
+        let path = API_PATH_ACTOR_SYNC.replace("{pid}", pid).replace("{name}", name);
+        self.client
+            .post::<Empty, _>(&path, synchronization)
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(ApiError::ClientError)?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for ActorSpec {
+    type Output = ActorSpec;
+}
+
+impl Endpoint for Vec<ActorSpec> {
+    type Output = Vec<ActorSpec>;
+}
@@ -0,0 +1,59 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::constants::{API_PATH_CHARACTER, API_PATH_CHARACTERS};
+use super::data;
+use super::errors::ApiError;
+use crate::http::endpoint::Endpoint;
+use crate::http::Client;
+use crate::resource::CharacterSpec;
+
+/// Provides access to the characters of the playbooks.
+pub struct Characters<'a> {
+    pub client: &'a Client,
+}
+
+impl Characters<'_> {
+    /// Returns a list of the characters of a playbook.
+    pub async fn list(&self, pid: &str) -> Result<Vec<CharacterSpec>, ApiError> {
+        let path = API_PATH_CHARACTERS.replace("{pid}", pid);
+        let res = self
+            .client
+            .get::<Vec<CharacterSpec>>(&path, None)
+            .await
+            .map_err(ApiError::ClientError)?;
+
+        data(&path, res)
+    }
+
+    /// Returns a character of a playbook.
+    pub async fn get(&self, pid: &str, name: &str) -> Result<CharacterSpec, ApiError> {
+        let path = API_PATH_CHARACTER.replace("{pid}", pid).replace("{name}", name);
+        let res = self
+            .client
+            .get::<CharacterSpec>(&path, None)
+            .await
+            .map_err(ApiError::ClientError)?;
+
+        data(&path, res)
+    }
+}
+
+impl Endpoint for CharacterSpec {
+    type Output = CharacterSpec;
+}
+
+impl Endpoint for Vec<CharacterSpec> {
+    type Output = Vec<CharacterSpec>;
+}
@@ -0,0 +1,32 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The path of the playbooks.
+pub const API_PATH_PLAYBOOKS: &str = "/v1/playbooks";
+/// The path of a playbook.
+pub const API_PATH_PLAYBOOK: &str = "/v1/playbooks/{pid}";
+/// The path of the event stream of a playbook.
+pub const API_PATH_PLAYBOOK_EVENTS: &str = "/v1/playbooks/{pid}/events";
+/// The path of the actors of a playbook.
+pub const API_PATH_ACTORS: &str = "/v1/playbooks/{pid}/actors";
+/// The path of an actor.
+pub const API_PATH_ACTOR: &str = "/v1/actors/{pid}/{name}";
+/// The path of the log stream of an actor.
+pub const API_PATH_ACTOR_LOGS: &str = "/v1/actors/{pid}/{name}/logs";
+/// The path the file changes of an actor are synchronized to.
+pub const API_PATH_ACTOR_SYNC: &str = "/v1/actors/{pid}/{name}/sync";
+/// The path of the characters of a playbook.
+pub const API_PATH_CHARACTERS: &str = "/v1/playbooks/{pid}/characters";
+/// The path of a character.
+pub const API_PATH_CHARACTER: &str = "/v1/playbooks/{pid}/characters/{name}";
@@ -0,0 +1,28 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use reqwest::StatusCode;
+use thiserror::Error;
+
+use crate::http::HTTPError;
+
+/// Represents the possible errors thrown while interacting with the Amphitheatre API
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("Client Error: {0}")]
+    ClientError(#[source] HTTPError),
+
+    #[error("Unexpected Empty Response {1}: {0}")]
+    EmptyResponse(String, StatusCode),
+}
@@ -0,0 +1,110 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A typed client of the Amphitheatre API.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use amp_common::api::Client;
+//! use amp_common::config::Cluster;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::try_from(&Cluster::default())?;
+//! for playbook in client.playbooks().list().await? {
+//!     println!("{}: {}", playbook.id, playbook.title);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use self::actor::Actors;
+use self::character::Characters;
+use self::errors::ApiError;
+use self::playbook::Playbooks;
+use crate::config::Cluster;
+use crate::http::{self, Response};
+
+pub mod actor;
+pub mod character;
+pub mod constants;
+pub mod errors;
+pub mod playbook;
+
+/// The client of the Amphitheatre API.
+pub struct Client {
+    client: http::Client,
+}
+
+impl Client {
+    /// Creates a new client of the API at the server, authenticated with the token if any.
+    pub fn new(server: &str, token: Option<String>) -> Result<Self, ApiError> {
+        Ok(Self::from(
+            http::Client::new(server, token).map_err(ApiError::ClientError)?,
+        ))
+    }
+
+    /// Returns the playbooks.
+    pub fn playbooks(&self) -> Playbooks<'_> {
+        Playbooks { client: &self.client }
+    }
+
+    /// Returns the actors of the playbooks.
+    pub fn actors(&self) -> Actors<'_> {
+        Actors { client: &self.client }
+    }
+
+    /// Returns the characters of the playbooks.
+    pub fn characters(&self) -> Characters<'_> {
+        Characters { client: &self.client }
+    }
+}
+
+/// Creates a client using the given HTTP client, e.g. with a retry policy or middlewares.
+impl From<http::Client> for Client {
+    fn from(client: http::Client) -> Self {
+        Self { client }
+    }
+}
+
+/// Creates a client of the API server of the cluster, authenticated with its token.
+impl TryFrom<&Cluster> for Client {
+    type Error = ApiError;
+
+    fn try_from(cluster: &Cluster) -> Result<Self, Self::Error> {
+        Self::new(&cluster.server, cluster.token.clone())
+    }
+}
+
+/// Returns the data of a successful response.
+fn data<T>(path: &str, res: Response<T>) -> Result<T, ApiError> {
+    let res = res.error_for_status().map_err(ApiError::ClientError)?;
+    res.data
+        .ok_or_else(|| ApiError::EmptyResponse(path.to_string(), res.status))
+}
+
+#[cfg(test)]
+mod test {
+    use super::Client;
+    use crate::config::Cluster;
+
+    #[test]
+    fn create_client_from_cluster() {
+        let cluster = Cluster {
+            token: Some("AUTH_TOKEN".to_string()),
+            ..Default::default()
+        };
+        assert!(Client::try_from(&cluster).is_ok());
+    }
+}
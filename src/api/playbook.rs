@@ -0,0 +1,102 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::{API_PATH_PLAYBOOK, API_PATH_PLAYBOOKS, API_PATH_PLAYBOOK_EVENTS};
+use super::data;
+use super::errors::ApiError;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{Client, EventSource, EventSourceOptions};
+use crate::resource::{PlaybookSpec, Preface};
+
+/// Represents the payload to create a playbook.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlaybookPayload {
+    /// The title of the playbook
+    pub title: String,
+    /// The description of the playbook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The starting character of the playbook
+    pub preface: Preface,
+}
+
+/// Provides access to the playbooks.
+pub struct Playbooks<'a> {
+    pub client: &'a Client,
+}
+
+impl Playbooks<'_> {
+    /// Returns a list of the playbooks.
+    pub async fn list(&self) -> Result<Vec<PlaybookSpec>, ApiError> {
+        let res = self
+            .client
+            .get::<Vec<PlaybookSpec>>(API_PATH_PLAYBOOKS, None)
+            .await
+            .map_err(ApiError::ClientError)?;
+
+        data(API_PATH_PLAYBOOKS, res)
+    }
+
+    /// Creates a playbook, whose characters are resolved from the preface.
+    pub async fn create(&self, payload: &PlaybookPayload) -> Result<PlaybookSpec, ApiError> {
+        let res = self
+            .client
+            .post::<PlaybookSpec, _>(API_PATH_PLAYBOOKS, payload)
+            .await
+            .map_err(ApiError::ClientError)?;
+
+        data(API_PATH_PLAYBOOKS, res)
+    }
+
+    /// Returns a playbook.
+    pub async fn get(&self, pid: &str) -> Result<PlaybookSpec, ApiError> {
+        let path = API_PATH_PLAYBOOK.replace("{pid}", pid);
+        let res = self
+            .client
+            .get::<PlaybookSpec>(&path, None)
+            .await
+            .map_err(ApiError::ClientError)?;
+
+        data(&path, res)
+    }
+
+    /// Subscribes to the events of a playbook, e.g. the state changes of its
+    /// actors, the data of the events is JSON.
+    pub fn events(&self, pid: &str) -> EventSource {
+        let path = API_PATH_PLAYBOOK_EVENTS.replace("{pid}", pid);
+        self.client.events(&path, EventSourceOptions::default())
+    }
+
+    /// Deletes a playbook, along with its actors.
+    pub async fn delete(&self, pid: &str) -> Result<(), ApiError> {
+        let path = API_PATH_PLAYBOOK.replace("{pid}", pid);
+        self.client
+            .delete::<Empty>(&path)
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(ApiError::ClientError)?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for PlaybookSpec {
+    type Output = PlaybookSpec;
+}
+
+impl Endpoint for Vec<PlaybookSpec> {
+    type Output = Vec<PlaybookSpec>;
+}
@@ -13,11 +13,15 @@
 // limitations under the License.
 
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
 
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
-use super::ContextConfiguration;
+use super::{ContextConfiguration, Credential, RepositoryCredential};
+use crate::http::OAuth2Token;
+use crate::utils::http::host;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Configuration {
@@ -44,6 +48,70 @@ impl Configuration {
     pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
         confy::store_path(path, self).with_context(|| "unable to save configuration")
     }
+
+    /// replace the previous OAuth2 token of the repository credentials with the server
+    /// address by the refreshed one, in every context, returns true if any credential
+    /// was updated. Credentials of other accounts on the same server are left alone,
+    /// only the ones still holding the previous token are updated.
+    pub fn update_repository_token(
+        &mut self,
+        server: &str,
+        previous: &OAuth2Token,
+        token: &OAuth2Token,
+    ) -> bool {
+        let server = host(server);
+        let mut updated = false;
+        let clusters = self.context.iter_mut().flat_map(|context| context.iter_mut());
+        for (_, cluster) in clusters {
+            let repositories = cluster
+                .credentials
+                .iter_mut()
+                .flat_map(|credentials| credentials.repositories.iter_mut().flatten());
+            for credential in repositories {
+                let issued = match &previous.refresh_token {
+                    Some(refresh_token) => credential.refresh_token.as_ref() == Some(refresh_token),
+                    None => credential.token_any() == previous.access_token,
+                };
+                if server.is_some() && host(&credential.server) == server && issued {
+                    credential.set_oauth2_token(token);
+                    updated = true;
+                }
+            }
+        }
+
+        updated
+    }
+}
+
+/// Returns a hook that saves the refreshed OAuth2 tokens of the repository
+/// credential back to the configuration at the specified path, so that the
+/// rotated refresh token is not lost. Only the credentials holding the token
+/// being refreshed are updated, not other accounts on the same server.
+/// Failures are logged and ignored.
+///
+/// The hook loads and saves the file synchronously, `OAuth2::on_refresh` calls
+/// it on the blocking thread pool.
+pub fn persist_token(
+    path: impl Into<PathBuf>,
+    credential: &RepositoryCredential,
+) -> impl Fn(&OAuth2Token) + Send + Sync + 'static {
+    let path = path.into();
+    let server = credential.server.clone();
+    let previous = Mutex::new(credential.oauth2_token());
+
+    move |token: &OAuth2Token| {
+        let mut previous = previous.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = Configuration::load(&path).and_then(|mut config| {
+            match config.update_repository_token(&server, &previous, token) {
+                true => config.save(&path),
+                false => Ok(()),
+            }
+        });
+        match result {
+            Ok(()) => *previous = token.clone(),
+            Err(err) => warn!("failed to persist the refreshed token of {server}: {err:#}"),
+        }
+    }
 }
 
 impl Default for Configuration {
@@ -61,4 +129,86 @@ mod test {
         let config = Configuration::default();
         assert!(config.context.is_some());
     }
+
+    #[cfg(test)]
+    fn cluster(server: &str, token: &str, refresh_token: &str) -> crate::config::Cluster {
+        use crate::config::{Cluster, Credentials, RepositoryCredential};
+
+        Cluster {
+            credentials: Some(Credentials {
+                registries: vec![],
+                repositories: Some(vec![RepositoryCredential {
+                    server: server.to_string(),
+                    token: Some(token.to_string()),
+                    refresh_token: Some(refresh_token.to_string()),
+                    ..Default::default()
+                }]),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(test)]
+    fn repository_token(config: &super::Configuration, name: &str, server: &str) -> crate::http::OAuth2Token {
+        let context = config.context.as_ref().unwrap();
+        let credentials = context.get(name).unwrap().credentials.as_ref().unwrap();
+        credentials.find_repository(server).unwrap().oauth2_token()
+    }
+
+    #[test]
+    fn test_update_repository_token() {
+        use super::Configuration;
+        use crate::config::ContextConfiguration;
+        use crate::http::OAuth2Token;
+
+        let mut context = ContextConfiguration::default();
+        context
+            .add("gitlab", cluster("https://gitlab.com", "access-1", "refresh-1"))
+            .unwrap();
+        let mut config = Configuration {
+            context: Some(context),
+        };
+
+        let previous = repository_token(&config, "gitlab", "https://gitlab.com");
+        let token = OAuth2Token {
+            access_token: "access-2".to_string(),
+            refresh_token: Some("refresh-2".to_string()),
+            expires_at: Some("2099-01-01T00:00:00Z".parse().unwrap()),
+        };
+        assert!(config.update_repository_token("https://gitlab.com", &previous, &token));
+        assert!(!config.update_repository_token("https://github.com", &previous, &token));
+        assert_eq!(repository_token(&config, "gitlab", "https://gitlab.com"), token);
+    }
+
+    #[test]
+    fn test_update_repository_token_of_one_account() {
+        use super::Configuration;
+        use crate::config::ContextConfiguration;
+        use crate::http::OAuth2Token;
+
+        let mut context = ContextConfiguration::default();
+        context
+            .add("work", cluster("https://github.com", "access-1", "refresh-1"))
+            .unwrap();
+        context
+            .add("personal", cluster("https://github.com", "access-a", "refresh-a"))
+            .unwrap();
+        let mut config = Configuration {
+            context: Some(context),
+        };
+
+        let previous = repository_token(&config, "work", "https://github.com");
+        let personal = repository_token(&config, "personal", "https://github.com");
+        let token = OAuth2Token {
+            access_token: "access-2".to_string(),
+            refresh_token: Some("refresh-2".to_string()),
+            expires_at: None,
+        };
+        assert!(config.update_repository_token("https://github.com", &previous, &token));
+        assert_eq!(repository_token(&config, "work", "https://github.com"), token);
+        assert_eq!(
+            repository_token(&config, "personal", "https://github.com"),
+            personal
+        );
+    }
 }
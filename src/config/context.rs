@@ -83,6 +83,11 @@ impl ContextConfiguration {
         self.clusters.iter()
     }
 
+    /// impl iter_mut method for ContextConfiguration
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut Cluster)> {
+        self.clusters.iter_mut()
+    }
+
     /// Get the list of clusters.
     pub fn clusters(&self) -> &HashMap<String, Cluster> {
         &self.clusters
@@ -14,6 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::http::OAuth2Token;
 use crate::utils::http::host;
 
 /// `Credential` is used to provide common methods for accessing credentials.
@@ -65,6 +66,16 @@ impl Credentials {
         self.registries.iter().find(|registry| registry.default)
     }
 
+    /// Get the mutable credential of the specified repository by repository server address.
+    pub fn find_repository_mut(&mut self, server: &str) -> Option<&mut RepositoryCredential> {
+        let server = host(server);
+        self.repositories.as_mut().and_then(|repositories| {
+            repositories
+                .iter_mut()
+                .find(|repository| server.is_some() && host(&repository.server) == server)
+        })
+    }
+
     /// Get the credential of the specified repository by repository server address.
     pub fn find_repository(&self, server: &str) -> Option<&RepositoryCredential> {
         let server = host(server);
@@ -127,6 +138,67 @@ pub struct RepositoryCredential {
     pub password: Option<String>,
     /// the optional token of the repository
     pub token: Option<String>,
+    /// the optional GitHub App to authenticate as, instead of the token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<AppCredential>,
+    /// the optional refresh token, used to renew the token when it is an expiring OAuth2 token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// the optional expiry of the token, as a RFC 3339 timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// the optional OAuth2 application the token was issued to, required to refresh it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<OAuthCredential>,
+}
+
+impl RepositoryCredential {
+    /// Returns the OAuth2 token of the credential, an invalid expiry is
+    /// treated as unknown, so that the token is only refreshed when rejected.
+    pub fn oauth2_token(&self) -> OAuth2Token {
+        OAuth2Token {
+            access_token: self.token_any(),
+            refresh_token: self.refresh_token.clone(),
+            expires_at: self.expires_at.as_ref().and_then(|v| v.parse().ok()),
+        }
+    }
+
+    /// Replaces the token, refresh token and expiry with the refreshed OAuth2 token.
+    pub fn set_oauth2_token(&mut self, token: &OAuth2Token) {
+        self.token = Some(token.access_token.clone());
+        self.refresh_token = token.refresh_token.clone();
+        self.expires_at = token.expires_at.map(|v| v.to_string());
+    }
+}
+
+/// The OAuth2 application the token of a repository credential was issued to.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct OAuthCredential {
+    /// the client id of the application
+    pub client_id: String,
+    /// the optional client secret of the application
+    pub client_secret: Option<String>,
+    /// the optional redirect URI of the application, required by GitLab
+    pub redirect_uri: Option<String>,
+    /// the optional token endpoint, defaults to the one of the driver
+    pub token_url: Option<String>,
+}
+
+/// Access credentials of a GitHub App, used to mint installation access tokens.
+/// The installation is found by the id if given, otherwise by the repository
+/// or organization the app is installed on.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct AppCredential {
+    /// the id of the app
+    pub app_id: String,
+    /// the PEM encoded RSA private key of the app
+    pub private_key: String,
+    /// the optional id of the installation
+    pub installation_id: Option<u64>,
+    /// the optional repository (`owner/name`) the app is installed on
+    pub repository: Option<String>,
+    /// the optional organization the app is installed on
+    pub organization: Option<String>,
 }
 
 /// `RepositoryCredential` implements `Credential`
@@ -0,0 +1,314 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, USER_AGENT};
+use reqwest::{NoProxy, Proxy};
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::ClientConfig;
+use rustls_platform_verifier::Verifier;
+use tracing::warn;
+
+use super::limit::{Coalescer, HostLimiter};
+use super::{Auth, Client, HTTPError, Middleware, RetryPolicy, Transport};
+
+/// Builds a `Client` with custom timeouts, proxy, TLS and default headers.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use amp_common::http::{Auth, ClientBuilder};
+///
+/// let ca = std::fs::read("/etc/ssl/certs/corporate-ca.pem").unwrap();
+/// let client = ClientBuilder::new("https://gitlab.company.com")
+///     .auth(Auth::PrivateToken("AUTH_TOKEN".to_string()))
+///     .connect_timeout(Duration::from_secs(5))
+///     .proxy("http://proxy.company.com:3128")
+///     .no_proxy(["localhost", ".svc.cluster.local"])
+///     .root_certificate(ca)
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct ClientBuilder {
+    base_url: String,
+    auth: Auth,
+    retry: RetryPolicy,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    no_proxy: Vec<String>,
+    root_certificates: Vec<Vec<u8>>,
+    identity: Option<Vec<u8>>,
+    headers: HeaderMap,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    transport: Option<Arc<dyn Transport>>,
+    max_concurrency_per_host: Option<usize>,
+    coalesce: bool,
+    trusted_hosts: Vec<String>,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder of a client for the API at the base URL
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets how every request is authenticated, defaults to no authentication
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets the policy used to retry failed requests
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets the timeout for establishing a connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for each read of the response, reset after every successful read
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the total timeout of a request, from connecting until the response
+    /// body has been read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends every request through the HTTP(S) proxy, instead of the one
+    /// configured by the `HTTP(S)_PROXY` environment variables
+    pub fn proxy(mut self, url: &str) -> Self {
+        self.proxy = Some(url.to_string());
+        self
+    }
+
+    /// Sets the hosts that bypass the proxy set by `proxy`, e.g. `localhost`,
+    /// `.company.com` or `10.0.0.0/8`. Without `proxy` they are ignored, the
+    /// proxies of the environment are bypassed with the `NO_PROXY` variable.
+    pub fn no_proxy<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.no_proxy.extend(hosts.into_iter().map(Into::into));
+        self
+    }
+
+    /// Also sends the credentials to the hosts, over the scheme and port of
+    /// the base URL, e.g. to the upload host of an API. Other hosts, such as
+    /// the ones of absolute URLs returned by the API, never receive them.
+    pub fn trusted_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.trusted_hosts.extend(hosts.into_iter().map(Into::into));
+        self
+    }
+
+    /// Trusts the PEM encoded root certificates in addition to the ones of
+    /// the system, e.g. of a corporate PKI
+    pub fn root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Sets the PEM encoded client certificate and private key used for mutual TLS
+    pub fn identity(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.identity = Some(pem.into());
+        self
+    }
+
+    /// Sends the header with every request, replacing a default header of the same name
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sends the headers with every request, replacing default headers of the same names
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Appends the middleware to the ones every request is passed through,
+    /// see `Middleware` for the order they are called in
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sends the requests with the transport instead of over the network,
+    /// e.g. to an in-process server in tests, see `Transport`
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Limits the number of requests sent to the same host at once, e.g. so
+    /// that bursts do not trip the abuse detection of a provider. The other
+    /// requests wait until the body of a response has been read (or dropped),
+    /// so a streamed download or event stream keeps its slot until it ends, and a retried
+    /// request frees its slot while it waits for the next attempt.
+    pub fn max_concurrency_per_host(mut self, max: usize) -> Self {
+        self.max_concurrency_per_host = Some(max);
+        self
+    }
+
+    /// Sends identical GET requests (same URL and headers) that are in flight
+    /// at the same time once, sharing the response between them. Streamed
+    /// requests and downloads are never shared.
+    pub fn coalesce(mut self, enabled: bool) -> Self {
+        self.coalesce = enabled;
+        self
+    }
+
+    /// Builds the client
+    pub fn build(self) -> Result<Client, HTTPError> {
+        let tls = self.tls_config()?;
+
+        // Set the default headers for every request
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_static(concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"))),
+        );
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.extend(self.headers);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .tls_backend_preconfigured(tls);
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        match &self.proxy {
+            Some(url) => {
+                let proxy = Proxy::all(url).map_err(HTTPError::ReqwestError)?;
+                builder = builder.proxy(proxy.no_proxy(NoProxy::from_string(&self.no_proxy.join(","))));
+            }
+            None if !self.no_proxy.is_empty() => {
+                warn!("the hosts bypassing the proxy are ignored, as no proxy has been set");
+            }
+            None => {}
+        }
+
+        let client = builder.build().map_err(HTTPError::ReqwestError)?;
+        let transport = self.transport.unwrap_or_else(|| Arc::new(client.clone()));
+
+        Ok(Client {
+            base_url: self.base_url,
+            client,
+            auth: self.auth,
+            retry: self.retry,
+            middlewares: self.middlewares,
+            transport,
+            limiter: self
+                .max_concurrency_per_host
+                .map(|max| Arc::new(HostLimiter::new(max))),
+            coalescer: self.coalesce.then(|| Arc::new(Coalescer::default())),
+            trusted_hosts: self.trusted_hosts,
+        })
+    }
+
+    /// Returns the TLS configuration, verifying the certificates with the ones of
+    /// the system and the additional root certificates. The crypto provider
+    /// installed by the application is used if any, otherwise `ring`, which is
+    /// not installed as the process-wide default.
+    fn tls_config(&self) -> Result<ClientConfig, HTTPError> {
+        let provider = CryptoProvider::get_default()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(rustls::crypto::ring::default_provider()));
+
+        let mut roots = vec![];
+        for pem in &self.root_certificates {
+            for certificate in CertificateDer::pem_slice_iter(pem) {
+                roots.push(certificate.map_err(tls_error)?);
+            }
+        }
+        let verifier = match roots.is_empty() {
+            true => Verifier::new(provider.clone()),
+            false => extra_roots_verifier(roots, provider.clone()),
+        }
+        .map_err(tls_error)?;
+
+        let builder = ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(tls_error)?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(verifier));
+        let mut config = match &self.identity {
+            Some(pem) => {
+                let certificates = CertificateDer::pem_slice_iter(pem)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(tls_error)?;
+                let key = PrivateKeyDer::from_pem_slice(pem).map_err(tls_error)?;
+                builder
+                    .with_client_auth_cert(certificates, key)
+                    .map_err(tls_error)?
+            }
+            None => builder.with_no_client_auth(),
+        };
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+        Ok(config)
+    }
+}
+
+fn tls_error(err: impl std::fmt::Display) -> HTTPError {
+    HTTPError::Tls(err.to_string())
+}
+
+#[cfg(any(all(unix, not(target_os = "android")), target_os = "windows"))]
+fn extra_roots_verifier(
+    roots: Vec<CertificateDer<'static>>,
+    provider: Arc<CryptoProvider>,
+) -> Result<Verifier, rustls::Error> {
+    Verifier::new_with_extra_roots(roots, provider)
+}
+
+#[cfg(not(any(all(unix, not(target_os = "android")), target_os = "windows")))]
+fn extra_roots_verifier(
+    _roots: Vec<CertificateDer<'static>>,
+    _provider: Arc<CryptoProvider>,
+) -> Result<Verifier, rustls::Error> {
+    Err(rustls::Error::General(
+        "additional root certificates are not supported on this platform".to_string(),
+    ))
+}
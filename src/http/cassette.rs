@@ -0,0 +1,403 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use data_encoding::BASE64;
+use http::Extensions;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::form_urlencoded;
+
+use super::{redact_headers, HTTPError, Middleware, SensitiveParams};
+
+/// Records the requests sent by a `Client` and their responses to a file,
+/// and replays them later without sending any request, so that tests run
+/// against real responses without a network or credentials.
+///
+/// A request is answered by the first interaction not played yet with the
+/// same method, path, query and body, so a route can be recorded several
+/// times to serve a sequence of responses, e.g. the pages of a list or a
+/// failure followed by a success.
+///
+/// The values of credentials in headers, query parameters and forms, and the
+/// token fields of JSON response bodies (e.g. `access_token`) are replaced
+/// before they are written to the file. Other secrets a response body may hold
+/// are written as they are, check the recordings before committing them.
+/// Bodies that are not valid UTF-8, e.g. archives, are stored base64 encoded.
+///
+/// Register the cassette as the last middleware, so that the others see the
+/// replayed responses as if they came from the server.
+///
+/// # Examples
+///
+/// ```no_run
+/// use amp_common::http::{Cassette, Client};
+///
+/// let cassette = Cassette::replay("tests/fixtures/cassettes/list-branches.json").unwrap();
+/// let client = Client::builder("https://gitlab.com")
+///     .middleware(cassette.clone())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Cassette {
+    inner: Arc<CassetteInner>,
+}
+
+struct CassetteInner {
+    path: PathBuf,
+    recording: bool,
+    /// The interactions, along with whether they have been played.
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+/// Represents a request and the response it was answered with.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// Represents the parts of a request an interaction is matched on.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The body, compared as JSON if it is valid JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+/// Represents a recorded response.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub body: String,
+    /// `base64` if the body is not valid UTF-8 and has been encoded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+/// The fields of JSON response bodies whose values are never written to the file.
+const SENSITIVE_FIELDS: [&str; 6] = [
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "token",
+    "private_token",
+    "client_secret",
+];
+
+/// The encoding of the bodies that are not valid UTF-8.
+const BASE64_ENCODING: &str = "base64";
+
+impl Cassette {
+    /// Loads the interactions recorded in the file to be replayed, a request
+    /// that matches none of them fails with `HTTPError::Cassette`.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, HTTPError> {
+        let path = path.as_ref();
+        let content = std::fs::read(path).map_err(HTTPError::IoError)?;
+        let interactions: Vec<Interaction> =
+            serde_json::from_slice(&content).map_err(HTTPError::Deserialization)?;
+
+        Ok(Self::new(
+            path,
+            false,
+            interactions.into_iter().map(|i| (i, false)).collect(),
+        ))
+    }
+
+    /// Sends the requests to the server and records them along with their
+    /// responses to the file, which is written after every interaction.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::new(path.as_ref(), true, vec![])
+    }
+
+    fn new(path: &Path, recording: bool, interactions: Vec<(Interaction, bool)>) -> Self {
+        Self {
+            inner: Arc::new(CassetteInner {
+                path: path.to_path_buf(),
+                recording,
+                interactions: Mutex::new(interactions),
+            }),
+        }
+    }
+
+    /// Returns the interactions that have not been played yet, e.g. to
+    /// assert that a test sent every request it was expected to.
+    pub fn unplayed(&self) -> Vec<Interaction> {
+        let interactions = self.inner.interactions.lock().unwrap();
+        interactions
+            .iter()
+            .filter(|(_, played)| !played)
+            .map(|(interaction, _)| interaction.clone())
+            .collect()
+    }
+
+    /// Marks the first unplayed interaction matching the request as played
+    /// and returns its response.
+    fn play(&self, request: &RecordedRequest) -> Option<RecordedResponse> {
+        let mut interactions = self.inner.interactions.lock().unwrap();
+        let (interaction, played) = interactions
+            .iter_mut()
+            .find(|(interaction, played)| !*played && interaction.request.matches(request))?;
+        *played = true;
+
+        Some(interaction.response.clone())
+    }
+
+    /// Appends the interaction and writes all of them to the file.
+    fn append(&self, interaction: Interaction) -> Result<(), HTTPError> {
+        let mut interactions = self.inner.interactions.lock().unwrap();
+        interactions.push((interaction, true));
+
+        let all: Vec<&Interaction> = interactions.iter().map(|(interaction, _)| interaction).collect();
+        let content = serde_json::to_vec_pretty(&all).map_err(|e| HTTPError::Cassette(e.to_string()))?;
+        if let Some(parent) = self.inner.path.parent() {
+            std::fs::create_dir_all(parent).map_err(HTTPError::IoError)?;
+        }
+        std::fs::write(&self.inner.path, content).map_err(HTTPError::IoError)
+    }
+}
+
+#[async_trait]
+impl Middleware for Cassette {
+    async fn before(
+        &self,
+        request: &mut Request,
+        extensions: &mut Extensions,
+    ) -> Result<Option<Response>, HTTPError> {
+        let params = SensitiveParams::of(extensions);
+        let recorded = RecordedRequest::new(request, &params);
+        if self.inner.recording {
+            extensions.insert(recorded);
+            return Ok(None);
+        }
+
+        let response = self.play(&recorded).ok_or_else(|| {
+            HTTPError::Cassette(format!(
+                "no interaction left in {} for {} {}",
+                self.inner.path.display(),
+                recorded.method,
+                params.redact_url(request.url())
+            ))
+        })?;
+
+        let mut builder = http::Response::builder().status(response.status);
+        for (name, value) in &response.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(response.bytes()?)
+            .map_err(|e| HTTPError::Cassette(e.to_string()))?;
+
+        Ok(Some(Response::from(response)))
+    }
+
+    async fn after(&self, response: Response, extensions: &mut Extensions) -> Result<Response, HTTPError> {
+        let Some(request) = extensions.remove::<RecordedRequest>() else {
+            return Ok(response);
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(HTTPError::ReqwestError)?;
+        let (recorded, encoding) = match std::str::from_utf8(&body) {
+            Ok(text) => (redact_body(text), None),
+            Err(_) => (BASE64.encode(&body), Some(BASE64_ENCODING.to_string())),
+        };
+
+        self.append(Interaction {
+            request,
+            response: RecordedResponse {
+                status: status.as_u16(),
+                headers: redact_headers(&headers)
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+                body: recorded,
+                encoding,
+            },
+        })?;
+
+        // the body has been consumed, so the response is rebuilt from it.
+        let mut rebuilt = http::Response::new(body);
+        *rebuilt.status_mut() = status;
+        *rebuilt.headers_mut() = headers;
+
+        Ok(Response::from(rebuilt))
+    }
+}
+
+impl RecordedRequest {
+    /// Returns the request as recorded, with the values of the credentials replaced.
+    fn new(request: &Request, params: &SensitiveParams) -> Self {
+        let url = params.redact_url(request.url());
+        let form = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("application/x-www-form-urlencoded"));
+        let body = request.body().and_then(|body| body.as_bytes()).map(|body| {
+            let body = String::from_utf8_lossy(body);
+            if form {
+                params.redact_form(&body)
+            } else {
+                body.into_owned()
+            }
+        });
+
+        Self {
+            method: request.method().to_string(),
+            path: url.path().to_string(),
+            query: url.query().map(String::from),
+            body,
+        }
+    }
+}
+
+impl RecordedResponse {
+    /// Returns the body as it was received, decoding it if it has been encoded.
+    fn bytes(self) -> Result<Vec<u8>, HTTPError> {
+        match self.encoding.as_deref() {
+            None => Ok(self.body.into_bytes()),
+            Some(BASE64_ENCODING) => BASE64
+                .decode(self.body.as_bytes())
+                .map_err(|e| HTTPError::Cassette(format!("invalid base64 body: {e}"))),
+            Some(encoding) => Err(HTTPError::Cassette(format!("unknown body encoding {encoding}"))),
+        }
+    }
+}
+
+/// Returns the body with the values of the sensitive fields replaced if it is
+/// JSON, otherwise as it is.
+fn redact_body(body: &str) -> String {
+    let Ok(mut value) = serde_json::from_str::<Value>(body) else {
+        return body.to_string();
+    };
+    if !redact_value(&mut value) {
+        return body.to_string();
+    }
+
+    serde_json::to_string(&value).unwrap_or_else(|_| body.to_string())
+}
+
+/// Replaces the values of the sensitive fields at any depth, returns true if
+/// any has been replaced.
+fn redact_value(value: &mut Value) -> bool {
+    let mut redacted = false;
+    match value {
+        Value::Object(fields) => {
+            for (name, value) in fields.iter_mut() {
+                if SENSITIVE_FIELDS.contains(&name.to_lowercase().as_str()) && value.is_string() {
+                    *value = Value::String("***".to_string());
+                    redacted = true;
+                } else {
+                    redacted |= redact_value(value);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                redacted |= redact_value(value);
+            }
+        }
+        _ => {}
+    }
+
+    redacted
+}
+
+impl RecordedRequest {
+    /// Returns true if the requests are the same, regardless of the order
+    /// of the query parameters and of the formatting of JSON bodies.
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method.eq_ignore_ascii_case(&other.method)
+            && self.path == other.path
+            && query_pairs(&self.query) == query_pairs(&other.query)
+            && same_body(&self.body, &other.body)
+    }
+}
+
+fn query_pairs(query: &Option<String>) -> Vec<(String, String)> {
+    let mut pairs: Vec<(String, String)> =
+        form_urlencoded::parse(query.as_deref().unwrap_or_default().as_bytes())
+            .into_owned()
+            .collect();
+    pairs.sort();
+    pairs
+}
+
+fn same_body(a: &Option<String>, b: &Option<String>) -> bool {
+    match (a.as_deref().unwrap_or_default(), b.as_deref().unwrap_or_default()) {
+        (a, b) if a == b => true,
+        (a, b) => match (serde_json::from_str::<Value>(a), serde_json::from_str::<Value>(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{redact_body, RecordedRequest};
+
+    fn request(query: Option<&str>, body: Option<&str>) -> RecordedRequest {
+        RecordedRequest {
+            method: "POST".to_string(),
+            path: "/api/v4/projects".to_string(),
+            query: query.map(String::from),
+            body: body.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_match_query_in_any_order() {
+        let recorded = request(Some("page=2&per_page=30"), None);
+        assert!(recorded.matches(&request(Some("per_page=30&page=2"), None)));
+        assert!(!recorded.matches(&request(Some("page=3&per_page=30"), None)));
+        assert!(!recorded.matches(&request(None, None)));
+    }
+
+    #[test]
+    fn test_redact_token_fields() {
+        let body = r#"{"access_token":"gho_secret","token_type":"bearer","user":{"token":"secret"}}"#;
+        let redacted = redact_body(body);
+        assert!(!redacted.contains("secret"));
+        assert!(redacted.contains(r#""token_type":"bearer""#));
+
+        let body = r#"[{"id": 1, "name": "main"}]"#;
+        assert_eq!(redact_body(body), body);
+        assert_eq!(redact_body("token=secret"), "token=secret");
+    }
+
+    #[test]
+    fn test_match_json_body() {
+        let recorded = request(None, Some(r#"{"name": "v1.0.0", "ref": "main"}"#));
+        assert!(recorded.matches(&request(None, Some(r#"{"ref":"main","name":"v1.0.0"}"#))));
+        assert!(!recorded.matches(&request(None, Some(r#"{"ref":"dev","name":"v1.0.0"}"#))));
+        assert!(!recorded.matches(&request(None, None)));
+    }
+}
@@ -13,15 +13,28 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 
+use bytes::Bytes;
+use futures::TryStreamExt;
+use http::Extensions;
 use reqwest::{
-    header::{self, HeaderValue},
-    ClientBuilder, RequestBuilder,
+    header::{HeaderMap, AUTHORIZATION, CONTENT_LENGTH},
+    multipart::Form,
+    Method, Request, RequestBuilder, StatusCode,
 };
 use serde::Serialize;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
 use url::Url;
 
-use super::{endpoint::Endpoint, HTTPError, Response};
+use super::limit::{hold, Coalescer, HostLimiter, RawResponse};
+use super::{
+    endpoint::Endpoint, with_checksum, with_progress, Auth, ByteStream, ClientBuilder, DownloadOptions,
+    EventSource, EventSourceOptions, HTTPError, Middleware, Payload, Response, RetryPolicy, SensitiveParams,
+    StreamResponse, Transport,
+};
 
 /// Represents the Rust client for the API
 ///
@@ -38,8 +51,15 @@ use super::{endpoint::Endpoint, HTTPError, Response};
 /// ```
 #[derive(Clone)]
 pub struct Client {
-    base_url: String,
-    client: reqwest::Client,
+    pub(super) base_url: String,
+    pub(super) client: reqwest::Client,
+    pub(super) auth: Auth,
+    pub(super) retry: RetryPolicy,
+    pub(super) middlewares: Vec<Arc<dyn Middleware>>,
+    pub(super) transport: Arc<dyn Transport>,
+    pub(super) limiter: Option<Arc<HostLimiter>>,
+    pub(super) coalescer: Option<Arc<Coalescer>>,
+    pub(super) trusted_hosts: Vec<String>,
 }
 
 impl Client {
@@ -58,32 +78,123 @@ impl Client {
     /// `base_url`: the base URL of the API
     /// `token`: the bearer authentication token
     pub fn new(base_url: &str, token: Option<String>) -> Result<Client, HTTPError> {
-        let base_url = String::from(base_url);
+        Client::with_auth(base_url, token.map(Auth::Bearer).unwrap_or_default())
+    }
 
-        // Set the default headers for every request
-        let mut headers = header::HeaderMap::new();
-        headers.insert(
-            header::USER_AGENT,
-            HeaderValue::from_static(concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"))),
-        );
-        headers.insert(
-            reqwest::header::ACCEPT,
-            HeaderValue::from_static("application/json"),
-        );
+    /// Creates a new client authenticating every request with the given scheme
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use amp_common::http::{Auth, Client};
+    /// let auth = Auth::PrivateToken("AUTH_TOKEN".to_string());
+    /// let client = Client::with_auth("https://gitlab.com", auth);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `base_url`: the base URL of the API
+    /// `auth`: the authentication scheme and credentials
+    pub fn with_auth(base_url: &str, auth: Auth) -> Result<Client, HTTPError> {
+        ClientBuilder::new(base_url).auth(auth).build()
+    }
 
-        if let Some(token) = token {
-            headers.insert(
-                reqwest::header::AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {token}")).map_err(HTTPError::InvalidHeaderValue)?,
-            );
-        }
+    /// Returns a builder to configure timeouts, proxy, TLS and default headers
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use amp_common::http::Client;
+    /// let client = Client::builder("https://cloud.amphitheatre.app")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build();
+    /// ```
+    pub fn builder(base_url: &str) -> ClientBuilder {
+        ClientBuilder::new(base_url)
+    }
+
+    /// Sets the policy used to retry failed requests
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use amp_common::http::{Client, RetryPolicy};
+    /// let client = Client::new("https://cloud.amphitheatre.app", None)
+    ///     .unwrap()
+    ///     .with_retry(RetryPolicy::none());
+    /// ```
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Appends the middleware to the ones every request is passed through
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use amp_common::http::{Client, TracingMiddleware};
+    /// let client = Client::new("https://cloud.amphitheatre.app", None)
+    ///     .unwrap()
+    ///     .with_middleware(TracingMiddleware);
+    /// ```
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
 
-        let client = ClientBuilder::new()
-            .default_headers(headers)
-            .build()
-            .map_err(HTTPError::ReqwestError)?;
+    /// Returns a client sending the requests like this one but without any
+    /// credentials, e.g. to follow signed links to a storage service
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use amp_common::http::Client;
+    /// let client = Client::new("https://cloud.amphitheatre.app", Some("AUTH_TOKEN".to_string()))
+    ///     .unwrap()
+    ///     .without_auth();
+    /// ```
+    pub fn without_auth(&self) -> Self {
+        self.clone().with_authentication(Auth::None)
+    }
 
-        Ok(Client { base_url, client })
+    /// Replaces the credentials every request is authenticated with, keeping
+    /// the rest of the configuration
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use amp_common::http::{Auth, Client};
+    /// let client = Client::builder("https://gitlab.com")
+    ///     .build()
+    ///     .unwrap()
+    ///     .with_authentication(Auth::PrivateToken("AUTH_TOKEN".to_string()));
+    /// ```
+    pub fn with_authentication(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Also sends the credentials to the hosts, over the scheme and port of
+    /// the base URL, e.g. to the upload host of an API
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use amp_common::http::Client;
+    /// let client = Client::new("https://api.github.com", Some("AUTH_TOKEN".to_string()))
+    ///     .unwrap()
+    ///     .with_trusted_hosts(["uploads.github.com"]);
+    /// ```
+    pub fn with_trusted_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.trusted_hosts.extend(hosts.into_iter().map(Into::into));
+        self
     }
 }
 
@@ -112,6 +223,122 @@ impl Client {
         self.execute::<E>(request).await
     }
 
+    /// Sends a GET request to the API and streams the response body,
+    /// instead of buffering and decoding it as JSON
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `options`: optionally the query parameters to be sent to the server
+    /// `headers`: optionally the headers to be sent in addition to (or instead of)
+    /// the default headers, e.g. to negotiate a raw media type
+    pub async fn get_stream(
+        &self,
+        path: &str,
+        options: Option<HashMap<String, String>>,
+        headers: Option<HeaderMap>,
+    ) -> Result<StreamResponse, HTTPError> {
+        let mut request = self.client.get(self.url(path)?);
+        if let Some(options) = options {
+            for (key, value) in options {
+                request = request.query(&[(key, value)]);
+            }
+        }
+        if let Some(headers) = headers {
+            request = request.headers(headers);
+        }
+
+        let response = self
+            .send(request.build().map_err(HTTPError::ReqwestError)?)
+            .await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let stream = Box::pin(response.bytes_stream().map_err(HTTPError::ReqwestError));
+
+        Ok(StreamResponse {
+            status,
+            headers,
+            stream,
+        })
+    }
+
+    /// Subscribes to the server-sent events of the endpoint, the connection
+    /// is opened when the stream is first polled, see `EventSource`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use amp_common::http::{Client, EventSourceOptions};
+    /// use futures::StreamExt;
+    ///
+    /// # async fn run() {
+    /// let client = Client::new("https://cloud.amphitheatre.app", None).unwrap();
+    /// let mut events = client.events("/v1/playbooks/1/events", EventSourceOptions::default());
+    /// while let Some(Ok(event)) = events.next().await {
+    ///     println!("{}: {}", event.event, event.data);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `options`: the query parameters, headers and reconnection settings
+    pub fn events(&self, path: &str, options: EventSourceOptions) -> EventSource {
+        EventSource::connect(self.clone(), path, options)
+    }
+
+    /// Downloads the response body of a GET request to the file, without
+    /// buffering it in memory. The body is written to a `.part` file next to
+    /// the destination first, which is renamed once the download is complete
+    /// and the checksum, if any, matches. Returns the number of bytes written.
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `dest`: the path of the file to be written
+    /// `options`: the query parameters, headers, checksum and progress callback
+    pub async fn download_to(
+        &self,
+        path: &str,
+        dest: impl AsRef<Path>,
+        options: DownloadOptions,
+    ) -> Result<u64, HTTPError> {
+        let res = self
+            .get_stream(path, options.query, options.headers)
+            .await?
+            .error_for_status()
+            .await?;
+
+        let total = res
+            .headers
+            .get(CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+        let mut stream = res.stream;
+        if let Some(callback) = options.progress {
+            stream = with_progress(stream, total, callback);
+        }
+        if let Some(checksum) = options.checksum {
+            stream = with_checksum(stream, checksum);
+        }
+
+        let dest = dest.as_ref();
+        let mut part = dest.as_os_str().to_owned();
+        part.push(".part");
+        let result = write_to(stream, Path::new(&part)).await;
+        match result {
+            Ok(size) => {
+                fs::rename(&part, dest).await.map_err(HTTPError::IoError)?;
+                Ok(size)
+            }
+            Err(err) => {
+                let _ = fs::remove_file(&part).await;
+                Err(err)
+            }
+        }
+    }
+
     /// Sends a POST request to the API
     ///
     /// # Arguments
@@ -127,6 +354,127 @@ impl Client {
         self.execute::<E>(request).await
     }
 
+    /// Sends a POST request to the API with additional headers
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `data`: the json payload to be sent to the server
+    /// `headers`: the headers to be sent in addition to (or instead of) the default headers
+    pub async fn post_with_headers<E, T>(
+        &self,
+        path: &str,
+        data: &T,
+        headers: HeaderMap,
+    ) -> Result<Response<E::Output>, HTTPError>
+    where
+        E: Endpoint,
+        T: Serialize + ?Sized,
+    {
+        let request = self.client.post(self.url(path)?).json(data).headers(headers);
+        self.execute::<E>(request).await
+    }
+
+    /// Sends a POST request to the API with a raw body, e.g. to upload a file
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `options`: optionally the query parameters to be sent to the server
+    /// `data`: the raw payload to be sent to the server
+    /// `headers`: the headers to be sent in addition to (or instead of) the default
+    /// headers, usually the content type of the payload
+    pub async fn post_bytes<E>(
+        &self,
+        path: &str,
+        options: Option<HashMap<String, String>>,
+        data: Bytes,
+        headers: HeaderMap,
+    ) -> Result<Response<E::Output>, HTTPError>
+    where
+        E: Endpoint,
+    {
+        self.post_stream::<E>(path, options, data.into(), headers).await
+    }
+
+    /// Sends a POST request to the API with a payload streamed as it is read,
+    /// e.g. to upload a large file without buffering it in memory
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `options`: optionally the query parameters to be sent to the server
+    /// `payload`: the payload to be sent to the server
+    /// `headers`: the headers to be sent in addition to (or instead of) the default
+    /// headers, usually the content type of the payload
+    pub async fn post_stream<E>(
+        &self,
+        path: &str,
+        options: Option<HashMap<String, String>>,
+        payload: Payload,
+        headers: HeaderMap,
+    ) -> Result<Response<E::Output>, HTTPError>
+    where
+        E: Endpoint,
+    {
+        let request = self.client.post(self.url(path)?);
+        self.execute::<E>(with_payload(request, options, payload, headers))
+            .await
+    }
+
+    /// Sends a PUT request to the API with a payload streamed as it is read
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `options`: optionally the query parameters to be sent to the server
+    /// `payload`: the payload to be sent to the server
+    /// `headers`: the headers to be sent in addition to (or instead of) the default
+    /// headers, usually the content type of the payload
+    pub async fn put_stream<E>(
+        &self,
+        path: &str,
+        options: Option<HashMap<String, String>>,
+        payload: Payload,
+        headers: HeaderMap,
+    ) -> Result<Response<E::Output>, HTTPError>
+    where
+        E: Endpoint,
+    {
+        let request = self.client.put(self.url(path)?);
+        self.execute::<E>(with_payload(request, options, payload, headers))
+            .await
+    }
+
+    /// Sends a POST request to the API with a multipart form body
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `form`: the multipart form to be sent to the server
+    pub async fn post_multipart<E>(&self, path: &str, form: Form) -> Result<Response<E::Output>, HTTPError>
+    where
+        E: Endpoint,
+    {
+        let request = self.client.post(self.url(path)?).multipart(form);
+        self.execute::<E>(request).await
+    }
+
+    /// Sends a POST request to the API with a URL encoded form body
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `data`: the form payload to be sent to the server
+    pub async fn post_form<E, T>(&self, path: &str, data: &T) -> Result<Response<E::Output>, HTTPError>
+    where
+        E: Endpoint,
+        T: Serialize + ?Sized,
+    {
+        let request = self.client.post(self.url(path)?).form(data);
+        self.execute::<E>(request).await
+    }
+
     /// Sends a PUT request to the API
     ///
     /// # Arguments
@@ -174,31 +522,203 @@ impl Client {
     where
         E: Endpoint,
     {
-        let result = request.send().await;
+        let request = request.build().map_err(HTTPError::ReqwestError)?;
+        let (status, headers, body) = match &self.coalescer {
+            Some(coalescer) if request.method() == Method::GET => coalescer.fetch(self, request).await?,
+            _ => self.fetch(request).await?,
+        };
 
-        match result {
-            Ok(response) => {
-                let status = response.status();
-
-                let mut data = None;
-                let body = response.bytes().await.ok();
-                if let Some(bytes) = &body {
-                    data = serde_json::from_slice(bytes)
-                        .map_err(HTTPError::Deserialization)
-                        .ok();
-                }
+        // error bodies rarely have the shape of the endpoint, they are kept
+        // in `body` and can be turned into an error by `error_for_status`.
+        let data = match status.is_success() && !body.is_empty() {
+            true => Some(serde_json::from_slice(&body).map_err(HTTPError::Deserialization)?),
+            false => None,
+        };
+
+        Ok(Response {
+            status,
+            headers,
+            data,
+            body: Some(body),
+        })
+    }
+
+    /// Sends the request and reads the response body
+    pub(super) async fn fetch(&self, request: Request) -> Result<RawResponse, HTTPError> {
+        let response = self.send(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(HTTPError::ReqwestError)?;
+
+        Ok((status, headers, body))
+    }
+
+    /// Sends the request, retrying transient failures according to the retry policy
+    async fn send(&self, mut request: Request) -> Result<reqwest::Response, HTTPError> {
+        let mut attempt = 1;
+
+        loop {
+            // requests with a streaming body can not be cloned, and are sent once.
+            let next = match attempt < self.retry.max_attempts && self.retry.allows(request.method()) {
+                true => request.try_clone(),
+                false => None,
+            };
 
-                Ok(Response { status, data, body })
+            let result = self.authorize_and_send(request).await;
+            let Some(next) = next else {
+                return result;
+            };
+            let delay = match &result {
+                Ok(response) => self
+                    .retry
+                    .retry_response(attempt, response.status(), response.headers()),
+                Err(err) => self.retry.retry_error(attempt, err),
+            };
+            let Some(delay) = delay else {
+                return result;
+            };
+
+            // the response is dropped so that its slot is free while waiting.
+            drop(result);
+            tokio::time::sleep(delay).await;
+            request = next;
+            attempt += 1;
+        }
+    }
+
+    /// Authenticates and sends the request, a request rejected with `401` is
+    /// sent once more with a new token when the token comes from a source.
+    async fn authorize_and_send(&self, mut request: Request) -> Result<reqwest::Response, HTTPError> {
+        if !self.is_trusted(request.url()) {
+            return self.dispatch(request).await;
+        }
+
+        let retry = match &self.auth {
+            Auth::Source(_) if !request.headers().contains_key(AUTHORIZATION) => request.try_clone(),
+            _ => None,
+        };
+
+        self.auth.apply(&mut request).await?;
+        let token = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(String::from);
+        let response = self.dispatch(request).await?;
+
+        match (&self.auth, retry, token) {
+            (Auth::Source(source), Some(mut request), Some(token))
+                if response.status() == StatusCode::UNAUTHORIZED =>
+            {
+                drop(response);
+                source.invalidate(&token).await;
+                self.auth.apply(&mut request).await?;
+                self.dispatch(request).await
             }
-            Err(err) => Err(HTTPError::ReqwestError(err)),
+            _ => Ok(response),
         }
     }
 
-    /// Helper function to create a URL from a path by joining it with the base URL
+    /// Passes the request through the middlewares and sends it, unless one of
+    /// them responds to it
+    async fn dispatch(&self, mut request: Request) -> Result<reqwest::Response, HTTPError> {
+        let mut extensions = Extensions::new();
+        if let Auth::QueryParam { name, .. } = &self.auth {
+            extensions.insert(SensitiveParams(vec![name.clone()]));
+        }
+        let mut response = None;
+        let mut called = 0;
+        for middleware in &self.middlewares {
+            called += 1;
+            response = middleware.before(&mut request, &mut extensions).await?;
+            if response.is_some() {
+                break;
+            }
+        }
+
+        let mut response = match response {
+            Some(response) => response,
+            None => {
+                let permit = match &self.limiter {
+                    Some(limiter) => Some(limiter.acquire(request.url()).await),
+                    None => None,
+                };
+                let response = self.transport.send(request).await?;
+                match permit {
+                    Some(permit) => hold(response, permit),
+                    None => response,
+                }
+            }
+        };
+        for middleware in self.middlewares[..called].iter().rev() {
+            response = middleware.after(response, &mut extensions).await?;
+        }
+
+        Ok(response)
+    }
+
+    /// Helper function to create a URL from a path by joining it with the base URL,
+    /// absolute URLs (e.g. pagination links or download locations) are used as is.
     #[inline]
     pub fn url(&self, path: &str) -> Result<Url, HTTPError> {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return Url::parse(path).map_err(HTTPError::UrlParse);
+        }
         Url::parse(format!("{}{}", self.base_url, path).as_str()).map_err(HTTPError::UrlParse)
     }
+
+    /// Returns true if the credentials are sent with requests to the URL, which
+    /// is the case for the host of the base URL and the trusted hosts (e.g.
+    /// `uploads.github.com` for `api.github.com`) over the same scheme and port,
+    /// but not for the hosts that absolute URLs returned by the API may point
+    /// to, e.g. a CDN or S3.
+    fn is_trusted(&self, url: &Url) -> bool {
+        let Ok(base) = Url::parse(&self.base_url) else {
+            return false;
+        };
+        if url.scheme() != base.scheme() || url.port_or_known_default() != base.port_or_known_default() {
+            return false;
+        }
+
+        url.host() == base.host()
+            || url.host_str().is_some_and(|host| {
+                self.trusted_hosts
+                    .iter()
+                    .any(|trusted| trusted.eq_ignore_ascii_case(host))
+            })
+    }
+}
+
+/// Sets the query parameters, headers and streamed body of the request
+fn with_payload(
+    mut request: RequestBuilder,
+    options: Option<HashMap<String, String>>,
+    payload: Payload,
+    headers: HeaderMap,
+) -> RequestBuilder {
+    if let Some(options) = options {
+        for (key, value) in options {
+            request = request.query(&[(key, value)]);
+        }
+    }
+    if let Some(length) = payload.length() {
+        request = request.header(CONTENT_LENGTH, length);
+    }
+    request.headers(headers).body(payload.into_body())
+}
+
+/// Writes the stream to the file, returns the number of bytes written
+async fn write_to(mut stream: ByteStream, path: &Path) -> Result<u64, HTTPError> {
+    let mut file = fs::File::create(path).await.map_err(HTTPError::IoError)?;
+    let mut size = 0;
+    while let Some(chunk) = stream.try_next().await? {
+        file.write_all(&chunk).await.map_err(HTTPError::IoError)?;
+        size += chunk.len() as u64;
+    }
+    file.flush().await.map_err(HTTPError::IoError)?;
+
+    Ok(size)
 }
 
 #[cfg(test)]
@@ -212,4 +732,45 @@ mod tests {
         let client = Client::new(BASE_URL, Some(token.to_string())).unwrap();
         assert_eq!(client.base_url.as_str(), BASE_URL);
     }
+
+    #[test]
+    fn trusts_the_base_url_and_the_trusted_hosts() {
+        let client = Client::new("https://api.github.com", None)
+            .unwrap()
+            .with_trusted_hosts(["uploads.github.com"]);
+        let trusted = |url: &str| client.is_trusted(&url.parse().unwrap());
+        assert!(trusted("https://api.github.com/user"));
+        assert!(trusted("https://api.github.com:443/user"));
+        assert!(trusted(
+            "https://uploads.github.com/repos/octocat/Hello-World/releases/1/assets"
+        ));
+        assert!(!trusted("http://api.github.com/user"));
+        assert!(!trusted("https://api.github.com:8443/user"));
+        assert!(!trusted(
+            "http://uploads.github.com/repos/octocat/Hello-World/releases/1/assets"
+        ));
+        assert!(!trusted("https://github-cloud.s3.amazonaws.com/alambic/media/1"));
+        assert!(!trusted("https://gist.github.com/octocat"));
+
+        let client = Client::new("https://scm.co.uk", None).unwrap();
+        assert!(!client.is_trusted(&"https://evil.co.uk/user".parse().unwrap()));
+
+        let client = Client::new("http://127.0.0.1:8080", None).unwrap();
+        assert!(client.is_trusted(&"http://127.0.0.1:8080/user".parse().unwrap()));
+        assert!(!client.is_trusted(&"http://127.0.0.1:9090/user".parse().unwrap()));
+        assert!(!client.is_trusted(&"http://10.0.0.1:8080/user".parse().unwrap()));
+    }
+
+    #[test]
+    fn joins_paths_and_keeps_absolute_urls() {
+        let client = Client::new(BASE_URL, None).unwrap();
+        assert_eq!(
+            client.url("/v1/playbooks").unwrap().as_str(),
+            "https://cloud.amphitheatre.app/v1/playbooks"
+        );
+        assert_eq!(
+            client.url("https://example.com/download").unwrap().as_str(),
+            "https://example.com/download"
+        );
+    }
 }
@@ -12,18 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use serde::de::DeserializeOwned;
+use serde::de::{DeserializeOwned, IgnoredAny};
 
 /// Defines the Endpoint trait for the different API endpoints
 pub trait Endpoint {
     type Output: DeserializeOwned;
 }
 
-/// Represents an empty endpoint, used for endpoints that don't return any data
+/// Represents an empty endpoint, used for endpoints that don't return any data,
+/// or whose data is not of interest
 pub struct Empty;
 
 impl Endpoint for Empty {
-    type Output = ();
+    type Output = IgnoredAny;
 }
 
 /// Represents a JSON value endpoint, used for endpoints that return a raw JSON value
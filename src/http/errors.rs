@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use reqwest::header::InvalidHeaderValue;
+use reqwest::header::{InvalidHeaderName, InvalidHeaderValue};
+use reqwest::StatusCode;
+use serde_json::Value;
 use thiserror::Error;
 
 /// Represents the possible errors thrown while interacting with the Amphitheatre API
@@ -21,6 +23,9 @@ pub enum HTTPError {
     #[error("Deserialization Error {0}")]
     Deserialization(#[source] serde_json::Error),
 
+    #[error("Invalid Header Name {0}")]
+    InvalidHeaderName(InvalidHeaderName),
+
     #[error("Invalid Header Value {0}")]
     InvalidHeaderValue(InvalidHeaderValue),
 
@@ -29,4 +34,95 @@ pub enum HTTPError {
 
     #[error("Url Parse Error {0}")]
     UrlParse(#[source] url::ParseError),
+
+    #[error("Authentication Error {0}")]
+    Authentication(String),
+
+    #[error("IO Error {0}")]
+    IoError(#[source] std::io::Error),
+
+    #[error("Checksum Mismatch, expected {expected} but got {actual}")]
+    Checksum { expected: String, actual: String },
+
+    #[error("TLS Error {0}")]
+    Tls(String),
+
+    #[error("Transport Error {0}")]
+    Transport(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("Coalesced Request Error {0}")]
+    Coalesced(#[source] std::sync::Arc<HTTPError>),
+
+    #[error("Cassette Error {0}")]
+    Cassette(String),
+
+    #[error("Status Error {status}: {message}")]
+    Status {
+        status: StatusCode,
+        body: String,
+        message: String,
+    },
+}
+
+/// Returns the message of a JSON error body, understanding the common shapes:
+///
+/// - `{"message": "Not Found"}` (GitHub, Gitea, GitLab)
+/// - `{"message": {"name": ["has already been taken"]}}` (GitLab validation)
+/// - `{"error": "invalid_grant", "error_description": "..."}` (OAuth2)
+/// - `{"error": {"message": "..."}}`
+/// - `{"errors": [{"message": "..."}]}`, appended to the message
+pub(crate) fn error_message(body: &[u8]) -> Option<String> {
+    let value: Value = serde_json::from_slice(body).ok()?;
+
+    let message = match (&value["message"], &value["error_description"], &value["error"]) {
+        (Value::String(message), _, _) => Some(message.clone()),
+        (Value::Object(_) | Value::Array(_), _, _) => Some(value["message"].to_string()),
+        (_, Value::String(description), _) => Some(description.clone()),
+        (_, _, Value::String(error)) => Some(error.clone()),
+        (_, _, Value::Object(error)) => error.get("message").and_then(Value::as_str).map(String::from),
+        _ => None,
+    };
+
+    let errors: Vec<&str> = value["errors"]
+        .as_array()
+        .map(|errors| {
+            errors
+                .iter()
+                .filter_map(|e| e.as_str().or_else(|| e["message"].as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    match (message, errors.is_empty()) {
+        (Some(message), true) => Some(message),
+        (Some(message), false) => Some(format!("{message}: {}", errors.join(", "))),
+        (None, false) => Some(errors.join(", ")),
+        (None, true) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::error_message;
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            error_message(br#"{"message": "Not Found"}"#),
+            Some("Not Found".to_string())
+        );
+        assert_eq!(
+            error_message(br#"{"message": {"name": ["has already been taken"]}}"#),
+            Some(r#"{"name":["has already been taken"]}"#.to_string())
+        );
+        assert_eq!(
+            error_message(br#"{"error": "invalid_grant", "error_description": "The token expired"}"#),
+            Some("The token expired".to_string())
+        );
+        assert_eq!(
+            error_message(br#"{"message": "Validation Failed", "errors": [{"message": "tag exists"}]}"#),
+            Some("Validation Failed: tag exists".to_string())
+        );
+        assert_eq!(error_message(b"<html>Bad Gateway</html>"), None);
+    }
 }
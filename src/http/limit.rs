@@ -0,0 +1,185 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use futures::future::{BoxFuture, Shared};
+use futures::{FutureExt, StreamExt};
+use http_body_util::BodyDataStream;
+use reqwest::header::HeaderMap;
+use reqwest::{Body, Request, Response, ResponseBuilderExt, StatusCode};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use url::Url;
+
+use super::{Client, HTTPError};
+
+/// The status, headers and body of a response that has been read.
+pub(super) type RawResponse = (StatusCode, HeaderMap, Bytes);
+
+type SharedResponse = Shared<BoxFuture<'static, Result<RawResponse, Arc<HTTPError>>>>;
+
+/// Limits the number of requests sent to the same host at once, the others
+/// wait for a slot in the order they were sent.
+pub(super) struct HostLimiter {
+    max: usize,
+    hosts: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl HostLimiter {
+    pub(super) fn new(max: usize) -> Self {
+        Self {
+            max: max.max(1),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits for a slot of the host of the URL, which is freed when the permit is dropped.
+    pub(super) async fn acquire(&self, url: &Url) -> OwnedSemaphorePermit {
+        let host = format!(
+            "{}:{}",
+            url.host_str().unwrap_or_default(),
+            url.port_or_known_default().unwrap_or_default()
+        );
+        let semaphore = self
+            .hosts
+            .lock()
+            .unwrap()
+            .entry(host)
+            .or_insert_with(|| Arc::new(Semaphore::new(self.max)))
+            .clone();
+
+        semaphore
+            .acquire_owned()
+            .await
+            .expect("the semaphore is never closed")
+    }
+}
+
+/// Returns the response holding the permit until its body has been read or
+/// dropped, so that a download keeps its slot for as long as it lasts.
+pub(super) fn hold(response: Response, permit: OwnedSemaphorePermit) -> Response {
+    let url = response.url().clone();
+    let (parts, body) = http::Response::from(response).into_parts();
+    let stream = BodyDataStream::new(body).map(move |chunk| {
+        let _ = &permit;
+        chunk
+    });
+
+    let mut response = http::Response::builder()
+        .url(url)
+        .body(Body::wrap_stream(stream))
+        .expect("the response is valid");
+    *response.status_mut() = parts.status;
+    *response.version_mut() = parts.version;
+    *response.headers_mut() = parts.headers;
+
+    Response::from(response)
+}
+
+type Registry = Arc<Mutex<HashMap<RequestKey, Inflight>>>;
+
+/// Sends identical GET requests in flight at the same time once, and shares
+/// the response between them.
+#[derive(Default)]
+pub(super) struct Coalescer {
+    inflight: Registry,
+}
+
+/// A request in flight and the number of callers waiting for its response.
+struct Inflight {
+    response: SharedResponse,
+    waiters: usize,
+}
+
+/// Forgets the request in flight when its last waiter is dropped before the
+/// response has been received (e.g. on a timeout), the request is cancelled
+/// and sent again by the next caller instead of being shared half-done.
+struct Waiter {
+    key: RequestKey,
+    response: SharedResponse,
+    registry: Registry,
+}
+
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        let mut inflight = self.registry.lock().unwrap();
+        let Some(entry) = inflight.get_mut(&self.key) else {
+            return;
+        };
+        if !entry.response.ptr_eq(&self.response) {
+            return;
+        }
+        entry.waiters -= 1;
+        if entry.waiters == 0 {
+            inflight.remove(&self.key);
+        }
+    }
+}
+
+/// Identifies the requests that are the same: the URL and the headers set
+/// on the request, the authentication is the same for every request of a client.
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct RequestKey {
+    url: String,
+    headers: Vec<(String, Vec<u8>)>,
+}
+
+impl Coalescer {
+    /// Returns the response of the request, sending it unless the same one is
+    /// in flight already. A failure is shared as `HTTPError::Coalesced`, the
+    /// error is returned as is when the request was not shared.
+    pub(super) async fn fetch(&self, client: &Client, request: Request) -> Result<RawResponse, HTTPError> {
+        let mut headers: Vec<(String, Vec<u8>)> = request
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec()))
+            .collect();
+        headers.sort();
+        let key = RequestKey {
+            url: request.url().to_string(),
+            headers,
+        };
+
+        let waiter = {
+            let mut inflight = self.inflight.lock().unwrap();
+            let entry = inflight.entry(key.clone()).or_insert_with(|| {
+                let client = client.clone();
+                let registry = self.inflight.clone();
+                let id = key.clone();
+                let response = async move {
+                    let result = client.fetch(request).await.map_err(Arc::new);
+                    registry.lock().unwrap().remove(&id);
+                    result
+                }
+                .boxed()
+                .shared();
+                Inflight { response, waiters: 0 }
+            });
+            entry.waiters += 1;
+            Waiter {
+                key,
+                response: entry.response.clone(),
+                registry: self.inflight.clone(),
+            }
+        };
+
+        waiter
+            .response
+            .clone()
+            .await
+            .map_err(|err| Arc::try_unwrap(err).unwrap_or_else(HTTPError::Coalesced))
+    }
+}
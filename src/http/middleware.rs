@@ -0,0 +1,259 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::{Request, Response};
+use tracing::{debug, field, info_span, Span};
+use url::{form_urlencoded, Url};
+
+use super::HTTPError;
+
+/// The headers whose values are never logged.
+const SENSITIVE_HEADERS: [&str; 6] = [
+    "authorization",
+    "proxy-authorization",
+    "private-token",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// The query parameters whose values are never logged.
+const SENSITIVE_PARAMS: [&str; 6] = [
+    "access_token",
+    "private_token",
+    "token",
+    "client_secret",
+    "refresh_token",
+    "code",
+];
+
+/// The names of the query parameters carrying credentials in addition to the
+/// well-known ones, e.g. the one of `Auth::QueryParam`. The client passes them
+/// to the middlewares in the extensions of every request.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SensitiveParams(pub Vec<String>);
+
+impl SensitiveParams {
+    /// Returns the names set in the extensions of the request, if any.
+    pub fn of(extensions: &Extensions) -> Self {
+        extensions.get::<Self>().cloned().unwrap_or_default()
+    }
+
+    /// Returns the URL with the values of these and the well-known sensitive
+    /// query parameters replaced.
+    pub fn redact_url(&self, url: &Url) -> Url {
+        if url.query().is_none() {
+            return url.clone();
+        }
+
+        let mut redacted = url.clone();
+        redacted
+            .query_pairs_mut()
+            .clear()
+            .extend_pairs(self.redact_pairs(url.query_pairs()));
+
+        redacted
+    }
+
+    /// Returns the URL encoded form with the values of these and the well-known
+    /// sensitive fields replaced.
+    pub fn redact_form(&self, form: &str) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.redact_pairs(form_urlencoded::parse(form.as_bytes())))
+            .finish()
+    }
+
+    fn redact_pairs(&self, pairs: form_urlencoded::Parse) -> Vec<(String, String)> {
+        pairs
+            .map(|(name, value)| {
+                let sensitive = SENSITIVE_PARAMS.contains(&name.to_lowercase().as_str())
+                    || self.0.iter().any(|v| v.eq_ignore_ascii_case(&name));
+                let value = if sensitive { "***".into() } else { value };
+                (name.into_owned(), value.into_owned())
+            })
+            .collect()
+    }
+}
+
+/// Hooks into every request sent by a `Client`, e.g. to add tracing, metrics
+/// or signatures, or to serve the response without sending the request.
+///
+/// The `before` hooks are called in the order the middlewares are registered,
+/// after the request has been authenticated, and the `after` hooks in reverse
+/// order. Every attempt of a retried request goes through the middlewares.
+/// State is passed from `before` to `after` through the extensions, which are
+/// scoped to a single attempt.
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent. Returning a response short-circuits
+    /// the request: it is not sent, the `before` hooks of the following
+    /// middlewares are skipped, and the response goes through the `after` hooks
+    /// of this and the previous middlewares. Build one from a `http::Response`
+    /// with `reqwest::Response::from`.
+    async fn before(
+        &self,
+        _request: &mut Request,
+        _extensions: &mut Extensions,
+    ) -> Result<Option<Response>, HTTPError> {
+        Ok(None)
+    }
+
+    /// Called after the response has been received, before its body is read.
+    async fn after(&self, response: Response, _extensions: &mut Extensions) -> Result<Response, HTTPError> {
+        Ok(response)
+    }
+}
+
+/// Wraps every request in a `tracing` span named `http.request`, with the
+/// method, the redacted URL, the status and the duration of the request.
+#[derive(Clone, Debug, Default)]
+pub struct TracingMiddleware;
+
+#[async_trait]
+impl Middleware for TracingMiddleware {
+    async fn before(
+        &self,
+        request: &mut Request,
+        extensions: &mut Extensions,
+    ) -> Result<Option<Response>, HTTPError> {
+        let span = info_span!(
+            "http.request",
+            http.request.method = %request.method(),
+            url.full = %SensitiveParams::of(extensions).redact_url(request.url()),
+            http.response.status_code = field::Empty,
+            duration_ms = field::Empty,
+        );
+        extensions.insert(RequestSpan(span, Instant::now()));
+
+        Ok(None)
+    }
+
+    async fn after(&self, response: Response, extensions: &mut Extensions) -> Result<Response, HTTPError> {
+        if let Some(RequestSpan(span, start)) = extensions.remove::<RequestSpan>() {
+            span.record("http.response.status_code", response.status().as_u16());
+            span.record("duration_ms", start.elapsed().as_millis() as u64);
+        }
+
+        Ok(response)
+    }
+}
+
+#[derive(Clone)]
+struct RequestSpan(Span, Instant);
+
+/// Logs every request and response at the debug level, with the values of
+/// credentials in headers and query parameters replaced by `***`.
+#[derive(Clone, Debug, Default)]
+pub struct LoggingMiddleware;
+
+#[async_trait]
+impl Middleware for LoggingMiddleware {
+    async fn before(
+        &self,
+        request: &mut Request,
+        extensions: &mut Extensions,
+    ) -> Result<Option<Response>, HTTPError> {
+        debug!(
+            "--> {} {} {:?}",
+            request.method(),
+            SensitiveParams::of(extensions).redact_url(request.url()),
+            redact_headers(request.headers())
+        );
+        extensions.insert(RequestStart(Instant::now()));
+
+        Ok(None)
+    }
+
+    async fn after(&self, response: Response, extensions: &mut Extensions) -> Result<Response, HTTPError> {
+        let elapsed = extensions.get::<RequestStart>().map(|v| v.0.elapsed());
+        debug!(
+            "<-- {} {} ({elapsed:?}) {:?}",
+            response.status(),
+            SensitiveParams::of(extensions).redact_url(response.url()),
+            redact_headers(response.headers())
+        );
+
+        Ok(response)
+    }
+}
+
+#[derive(Clone)]
+struct RequestStart(Instant);
+
+/// Returns the URL with the values of sensitive query parameters replaced.
+pub fn redact_url(url: &Url) -> Url {
+    SensitiveParams::default().redact_url(url)
+}
+
+/// Returns the URL encoded form with the values of sensitive fields replaced.
+pub fn redact_form(form: &str) -> String {
+    SensitiveParams::default().redact_form(form)
+}
+
+/// Returns the headers as name and value pairs, with the values of sensitive
+/// headers replaced.
+pub fn redact_headers(headers: &HeaderMap) -> Vec<(&HeaderName, &str)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let sensitive = value.is_sensitive() || SENSITIVE_HEADERS.contains(&name.as_str());
+            let value = if sensitive {
+                "***"
+            } else {
+                value.to_str().unwrap_or("<binary>")
+            };
+            (name, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION};
+    use url::Url;
+
+    use super::{redact_headers, redact_url, SensitiveParams};
+
+    #[test]
+    fn test_redact_url() {
+        let url = Url::parse("https://gitlab.com/api/v4/projects?private_token=secret&page=2").unwrap();
+        assert_eq!(
+            redact_url(&url).as_str(),
+            "https://gitlab.com/api/v4/projects?private_token=***&page=2"
+        );
+
+        let url = Url::parse("https://gitlab.com/api/v4/projects?apikey=secret&page=2").unwrap();
+        let params = SensitiveParams(vec!["apiKey".into()]);
+        assert_eq!(
+            params.redact_url(&url).as_str(),
+            "https://gitlab.com/api/v4/projects?apikey=***&page=2"
+        );
+    }
+
+    #[test]
+    fn test_redact_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer secret"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        let redacted = format!("{:?}", redact_headers(&headers));
+        assert!(!redacted.contains("secret"));
+        assert!(redacted.contains("application/json"));
+    }
+}
@@ -1,21 +1,100 @@
+mod auth;
+mod builder;
+#[cfg(feature = "testing")]
+mod cassette;
 mod client;
 pub mod endpoint;
 mod errors;
+mod limit;
+mod middleware;
+mod oauth;
+mod retry;
+mod sse;
+mod transfer;
+mod transport;
+
+use std::pin::Pin;
 
 use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use reqwest::header::HeaderMap;
 use reqwest::StatusCode;
 
 // Re-export the client and errors
+pub use self::auth::*;
+pub use self::builder::*;
+#[cfg(feature = "testing")]
+pub use self::cassette::*;
 pub use self::client::*;
 pub use self::errors::*;
+pub use self::middleware::*;
+pub use self::oauth::*;
+pub use self::retry::*;
+pub use self::sse::*;
+pub use self::transfer::*;
+pub use self::transport::*;
 
 /// Represents the response from an API call
 #[derive(Debug)]
 pub struct Response<T> {
     /// The HTTP Status Code
     pub status: StatusCode,
-    /// The object or a Vec<T> objects (the type `T` will depend on the endpoint).
+    /// The response headers, e.g. pagination links, rate limits and ETags
+    pub headers: HeaderMap,
+    /// The object or a Vec<T> objects (the type `T` will depend on the endpoint),
+    /// only decoded for successful responses.
     pub data: Option<T>,
     /// The body's raw bytes
     pub body: Option<Bytes>,
 }
+
+impl<T> Response<T> {
+    /// Returns the response if the status is successful, otherwise an
+    /// `HTTPError::Status` with the message of the error body.
+    pub fn error_for_status(self) -> Result<Self, HTTPError> {
+        if self.status.is_success() {
+            return Ok(self);
+        }
+
+        Err(status_error(self.status, &self.body.unwrap_or_default()))
+    }
+}
+
+/// Represents a stream of raw bytes from the response body
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, HTTPError>> + Send>>;
+
+/// Represents the streaming response from an API call
+pub struct StreamResponse {
+    /// The HTTP Status Code
+    pub status: StatusCode,
+    /// The response headers
+    pub headers: HeaderMap,
+    /// The body's raw bytes, read as they arrive
+    pub stream: ByteStream,
+}
+
+impl StreamResponse {
+    /// Returns the response if the status is successful, otherwise reads the
+    /// body and returns an `HTTPError::Status`, see `Response::error_for_status`.
+    pub async fn error_for_status(self) -> Result<Self, HTTPError> {
+        if self.status.is_success() {
+            return Ok(self);
+        }
+
+        let body: Vec<u8> = self.stream.map_ok(|chunk| chunk.to_vec()).try_concat().await?;
+        Err(status_error(self.status, &body))
+    }
+}
+
+/// Returns the error of an unsuccessful response, with the message of the body
+fn status_error(status: StatusCode, body: &[u8]) -> HTTPError {
+    let message = error_message(body)
+        .or_else(|| status.canonical_reason().map(String::from))
+        .unwrap_or_default();
+
+    HTTPError::Status {
+        status,
+        body: String::from_utf8_lossy(body).into_owned(),
+        message,
+    }
+}
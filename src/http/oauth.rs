@@ -0,0 +1,214 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jiff::{SignedDuration, Timestamp};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use super::endpoint::Endpoint;
+use super::{Auth, Client, HTTPError, TokenSource};
+
+/// Access tokens are refreshed a bit before they expire so that a token
+/// never expires in the middle of a request.
+const TOKEN_REFRESH_MARGIN: SignedDuration = SignedDuration::from_mins(5);
+
+/// Called with the new token after every refresh, e.g. to persist it.
+type RefreshHook = Arc<dyn Fn(&OAuth2Token) + Send + Sync>;
+
+/// Represents an OAuth2 access token along with what is needed to refresh it.
+#[derive(Clone, Default, PartialEq)]
+pub struct OAuth2Token {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    /// The expiry of the access token, `None` if it is unknown.
+    pub expires_at: Option<Timestamp>,
+}
+
+impl OAuth2Token {
+    /// Returns true if the token expires within the refresh margin.
+    pub fn expires_soon(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at - TOKEN_REFRESH_MARGIN <= Timestamp::now())
+    }
+}
+
+impl fmt::Debug for OAuth2Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2Token")
+            .field("access_token", &"***")
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| "***"))
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Authenticates with an OAuth2 access token, which is refreshed through the
+/// token endpoint of the provider with the refresh token before it expires,
+/// or when the server rejects it.
+///
+/// Spec: https://datatracker.ietf.org/doc/html/rfc6749#section-6
+pub struct OAuth2 {
+    token_url: String,
+    client: Client,
+    client_id: String,
+    client_secret: Option<String>,
+    redirect_uri: Option<String>,
+    token: Mutex<OAuth2Token>,
+    on_refresh: Option<RefreshHook>,
+    /// The number of refreshes, and the one whose token was last passed to the hook.
+    refreshes: AtomicU64,
+    notified: Arc<std::sync::Mutex<u64>>,
+}
+
+impl OAuth2 {
+    /// Creates a new OAuth2 token source, `token_url` is the token endpoint of
+    /// the provider, e.g. `https://gitlab.com/oauth/token`.
+    pub fn new(
+        token_url: &str,
+        client_id: &str,
+        client_secret: Option<String>,
+        token: OAuth2Token,
+    ) -> Result<Self, HTTPError> {
+        Ok(Self {
+            token_url: token_url.to_string(),
+            client: Client::with_auth(token_url, Auth::None)?,
+            client_id: client_id.to_string(),
+            client_secret,
+            redirect_uri: None,
+            token: Mutex::new(token),
+            on_refresh: None,
+            refreshes: AtomicU64::new(0),
+            notified: Default::default(),
+        })
+    }
+
+    /// Sets the redirect URI of the application, required by some providers
+    /// (e.g. GitLab) when refreshing the token.
+    pub fn redirect_uri(mut self, redirect_uri: Option<String>) -> Self {
+        self.redirect_uri = redirect_uri;
+        self
+    }
+
+    /// Sets the function called with the new token after every refresh. It is
+    /// called on the blocking thread pool, so it may do blocking I/O without
+    /// holding up the requests waiting for the token, and never with a token
+    /// older than the one it was last called with.
+    pub fn on_refresh(mut self, hook: impl Fn(&OAuth2Token) + Send + Sync + 'static) -> Self {
+        self.on_refresh = Some(Arc::new(hook));
+        self
+    }
+
+    /// Returns the current token, which may be expired.
+    pub async fn current(&self) -> OAuth2Token {
+        self.token.lock().await.clone()
+    }
+
+    /// Exchanges the refresh token for a new access token.
+    async fn refresh(&self, token: &OAuth2Token) -> Result<OAuth2Token, HTTPError> {
+        let refresh_token = token
+            .refresh_token
+            .as_deref()
+            .ok_or_else(|| HTTPError::Authentication(format!("no refresh token for {}", self.token_url)))?;
+
+        let mut form = vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", self.client_id.as_str()),
+        ];
+        if let Some(secret) = &self.client_secret {
+            form.push(("client_secret", secret));
+        }
+        if let Some(uri) = &self.redirect_uri {
+            form.push(("redirect_uri", uri));
+        }
+
+        let res = self
+            .client
+            .post_form::<OAuth2TokenResponse, _>(&self.token_url, &form)
+            .await?;
+        match res.data {
+            Some(data) if res.status.is_success() => Ok(OAuth2Token {
+                access_token: data.access_token,
+                // providers that do not rotate refresh tokens omit it.
+                refresh_token: data.refresh_token.or_else(|| token.refresh_token.clone()),
+                expires_at: data
+                    .expires_in
+                    .map(|secs| Timestamp::now() + SignedDuration::from_secs(secs)),
+            }),
+            _ => Err(HTTPError::Authentication(format!(
+                "failed to refresh the access token at {}: {}",
+                self.token_url, res.status
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl TokenSource for OAuth2 {
+    /// Returns the access token, refreshing it first if it is about to expire.
+    async fn token(&self) -> Result<String, HTTPError> {
+        let mut cached = self.token.lock().await;
+        if !cached.expires_soon() {
+            return Ok(cached.access_token.clone());
+        }
+
+        let token = self.refresh(&cached).await?;
+        *cached = token.clone();
+        let access_token = token.access_token.clone();
+        let refresh = self.refreshes.fetch_add(1, Ordering::SeqCst) + 1;
+        drop(cached);
+
+        if let Some(hook) = self.on_refresh.clone() {
+            let notified = self.notified.clone();
+            let notify = move || {
+                let mut last = notified.lock().unwrap_or_else(|e| e.into_inner());
+                if *last < refresh {
+                    *last = refresh;
+                    hook(&token);
+                }
+            };
+            if let Err(err) = tokio::task::spawn_blocking(notify).await {
+                warn!("the refresh hook of {} failed: {err}", self.token_url);
+            }
+        }
+
+        Ok(access_token)
+    }
+
+    /// Marks the access token as expired, unless it has been refreshed since
+    /// the rejected request was sent.
+    async fn invalidate(&self, token: &str) {
+        let mut cached = self.token.lock().await;
+        if cached.access_token == token {
+            cached.expires_at = Some(Timestamp::UNIX_EPOCH);
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OAuth2TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<i64>,
+}
+
+impl Endpoint for OAuth2TokenResponse {
+    type Output = OAuth2TokenResponse;
+}
@@ -0,0 +1,213 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use jiff::fmt::rfc2822::DateTimeParser;
+use jiff::Timestamp;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+
+use super::HTTPError;
+
+/// Specifies when and how often a failed request is sent again.
+///
+/// A request is retried when the connection fails or times out, or when the
+/// server responds with one of the retryable statuses, waiting an exponentially
+/// growing delay between the attempts, or as long as the `Retry-After` header
+/// asks for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one, `1` disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for every following retry.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between attempts, a `Retry-After` asking
+    /// for longer is not honored and the response is returned as is.
+    pub max_backoff: Duration,
+    /// Whether the delay is randomized (full jitter) to spread out the retries
+    /// of concurrent clients.
+    pub jitter: bool,
+    /// The response statuses that are retried.
+    pub statuses: Vec<StatusCode>,
+    /// Whether only idempotent methods are retried, `POST` and `PATCH` requests
+    /// may otherwise be applied twice.
+    pub idempotent_only: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            idempotent_only: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns a policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if requests with the method may be sent again.
+    pub fn allows(&self, method: &Method) -> bool {
+        !self.idempotent_only || is_idempotent(method)
+    }
+
+    /// Returns the delay before the next attempt if the response should be
+    /// retried, `attempt` is the number of the attempt that just finished.
+    pub fn retry_response(&self, attempt: u32, status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+        if !self.statuses.contains(&status) {
+            return None;
+        }
+
+        let backoff = self.backoff(attempt);
+        match retry_after(headers) {
+            Some(delay) if delay > self.max_backoff => None,
+            Some(delay) => Some(delay.max(backoff)),
+            None => Some(backoff),
+        }
+    }
+
+    /// Returns the delay before the next attempt if the error is transient.
+    pub fn retry_error(&self, attempt: u32, error: &HTTPError) -> Option<Duration> {
+        match error {
+            HTTPError::ReqwestError(e) if e.is_connect() || e.is_timeout() || e.is_request() => {
+                Some(self.backoff(attempt))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the exponential backoff after the attempt, with jitter if enabled.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self.initial_backoff.saturating_mul(factor).min(self.max_backoff);
+        match self.jitter {
+            true => backoff.mul_f64(fastrand::f64()),
+            false => backoff,
+        }
+    }
+}
+
+/// Returns true if sending the request with the method twice has the same effect as once.
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS | Method::TRACE
+    )
+}
+
+/// Returns the delay asked for by the `Retry-After` header, given either in
+/// seconds or as a HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTimeParser::new().parse_timestamp(value).ok()?;
+    let delay = date.duration_since(Timestamp::now());
+    Some(Duration::try_from(delay).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+    use reqwest::{Method, StatusCode};
+
+    use super::RetryPolicy;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_exponential_backoff() {
+        let policy = policy();
+        let headers = HeaderMap::new();
+        let status = StatusCode::SERVICE_UNAVAILABLE;
+
+        assert_eq!(
+            policy.retry_response(1, status, &headers),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.retry_response(3, status, &headers),
+            Some(Duration::from_millis(800))
+        );
+        assert_eq!(
+            policy.retry_response(20, status, &headers),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(policy.retry_response(1, StatusCode::NOT_FOUND, &headers), None);
+    }
+
+    #[test]
+    fn test_honor_retry_after() {
+        let policy = policy();
+        let status = StatusCode::TOO_MANY_REQUESTS;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("2"));
+        assert_eq!(
+            policy.retry_response(1, status, &headers),
+            Some(Duration::from_secs(2))
+        );
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3600"));
+        assert_eq!(policy.retry_response(1, status, &headers), None);
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(
+            policy.retry_response(1, status, &headers),
+            Some(Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn test_idempotent_methods_only() {
+        let policy = policy();
+        assert!(policy.allows(&Method::GET));
+        assert!(policy.allows(&Method::DELETE));
+        assert!(!policy.allows(&Method::POST));
+
+        let policy = RetryPolicy {
+            idempotent_only: false,
+            ..policy
+        };
+        assert!(policy.allows(&Method::POST));
+    }
+}
@@ -0,0 +1,459 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::{stream, Stream, StreamExt};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CACHE_CONTROL};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use tracing::debug;
+
+use super::{ByteStream, Client, HTTPError};
+
+/// The delay before reconnecting, until the server asks for another one.
+const DEFAULT_RETRY: Duration = Duration::from_secs(3);
+
+/// Represents an event of a server-sent events stream.
+///
+/// Spec: https://html.spec.whatwg.org/multipage/server-sent-events.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Event {
+    /// The type of the event, `message` if the server did not name it.
+    pub event: String,
+    /// The data of the event, the lines of multi-line data are joined with `\n`.
+    pub data: String,
+    /// The ID of the last event received, the stream resumes after it when reconnecting.
+    pub id: Option<String>,
+    /// The delay before reconnecting asked for along with the event.
+    pub retry: Option<Duration>,
+}
+
+impl Event {
+    /// Decodes the data of the event as JSON.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, HTTPError> {
+        serde_json::from_str(&self.data).map_err(HTTPError::Deserialization)
+    }
+}
+
+/// Parses the chunks of a server-sent events stream into events, lines and
+/// events may be split across chunks.
+#[derive(Debug, Default)]
+pub struct EventParser {
+    /// The bytes of the line being read.
+    line: Vec<u8>,
+    /// Whether the last byte was a `\r`, which a `\n` may follow.
+    after_cr: bool,
+    /// Whether the first line, which may start with a BOM, has been read.
+    started: bool,
+    event: String,
+    data: String,
+    retry: Option<Duration>,
+    /// The ID set by the event being read, which becomes the last event ID
+    /// once the event is complete.
+    id: Option<String>,
+    last_event_id: Option<String>,
+    reconnection_time: Option<Duration>,
+}
+
+impl EventParser {
+    /// Feeds the chunk to the parser and returns the events it completes.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<Event> {
+        let mut events = vec![];
+        for &byte in chunk {
+            match byte {
+                b'\n' if self.after_cr => self.after_cr = false,
+                b'\r' | b'\n' => {
+                    self.after_cr = byte == b'\r';
+                    let line = std::mem::take(&mut self.line);
+                    events.extend(self.process(&String::from_utf8_lossy(&line)));
+                }
+                _ => {
+                    self.after_cr = false;
+                    self.line.push(byte);
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Returns the ID of the last event, to be sent as `Last-Event-ID` when reconnecting.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Returns the delay before reconnecting last asked for by the server.
+    pub fn reconnection_time(&self) -> Option<Duration> {
+        self.reconnection_time
+    }
+
+    /// Discards the event being read when the connection is lost, keeping
+    /// the last event ID and the reconnection time.
+    pub fn reset(&mut self) {
+        *self = Self {
+            id: self.last_event_id.clone(),
+            last_event_id: self.last_event_id.take(),
+            reconnection_time: self.reconnection_time,
+            ..Default::default()
+        };
+    }
+
+    /// Processes a line, returns the event if the line completes one.
+    fn process(&mut self, line: &str) -> Option<Event> {
+        let line = match self.started {
+            true => line,
+            false => {
+                self.started = true;
+                line.strip_prefix('\u{feff}').unwrap_or(line)
+            }
+        };
+
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = value.to_string(),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => {
+                self.id = Some(value.to_string()).filter(|id| !id.is_empty());
+            }
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                let retry = value.parse().ok().map(Duration::from_millis);
+                self.retry = retry;
+                self.reconnection_time = retry.or(self.reconnection_time);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Returns the event read so far, unless it has no data.
+    fn dispatch(&mut self) -> Option<Event> {
+        self.last_event_id = self.id.clone();
+        let event = std::mem::take(&mut self.event);
+        let mut data = std::mem::take(&mut self.data);
+        let retry = self.retry.take();
+        if data.is_empty() {
+            return None;
+        }
+
+        data.pop();
+        Some(Event {
+            event: if event.is_empty() {
+                "message".to_string()
+            } else {
+                event
+            },
+            data,
+            id: self.last_event_id.clone(),
+            retry,
+        })
+    }
+}
+
+/// Specifies how the events of an endpoint are received by `Client::events`.
+#[derive(Clone, Debug, Default)]
+pub struct EventSourceOptions {
+    /// The query parameters to be sent to the server.
+    pub query: Option<HashMap<String, String>>,
+    /// The headers to be sent in addition to (or instead of) the default headers.
+    pub headers: Option<HeaderMap>,
+    /// The ID of the last event received before, to resume a previous stream.
+    pub last_event_id: Option<String>,
+    /// The delay before reconnecting until the server asks for another one,
+    /// defaults to 3 seconds.
+    pub retry: Option<Duration>,
+    /// The maximum number of reconnections in a row without receiving an
+    /// event, `Some(0)` never reconnects, unlimited if not set.
+    pub max_reconnects: Option<u32>,
+}
+
+/// Represents a stream of server-sent events.
+///
+/// The events of `Client::events` are received over a connection that is
+/// reopened when it is closed or lost, sending the ID of the last event
+/// received as `Last-Event-ID` so that the server resumes after it. The
+/// stream ends when the server responds with `204 No Content`, and fails
+/// when the first connection can not be opened. Reconnections failing with
+/// a transient error are retried with the backoff of the retry policy of the
+/// client, until `max_reconnects` is reached.
+pub struct EventSource {
+    inner: Pin<Box<dyn Stream<Item = Result<Event, HTTPError>> + Send>>,
+}
+
+impl EventSource {
+    /// Returns the events of the stream, which is read once without reconnecting.
+    pub fn from_stream(stream: ByteStream) -> Self {
+        let state = (stream, EventParser::default(), VecDeque::new());
+        let events = stream::unfold(state, |(mut stream, mut parser, mut pending)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    return Some((Ok(event), (stream, parser, pending)));
+                }
+                match stream.next().await? {
+                    Ok(chunk) => pending.extend(parser.feed(&chunk)),
+                    Err(err) => return Some((Err(err), (stream, parser, pending))),
+                }
+            }
+        });
+
+        Self {
+            inner: Box::pin(events),
+        }
+    }
+
+    /// Returns the events of the endpoint, reconnecting as described above.
+    pub(crate) fn connect(client: Client, path: &str, options: EventSourceOptions) -> Self {
+        let parser = EventParser {
+            id: options.last_event_id.clone(),
+            last_event_id: options.last_event_id.clone(),
+            ..Default::default()
+        };
+        let state = Connection {
+            client,
+            path: path.to_string(),
+            options,
+            parser,
+            stream: None,
+            pending: VecDeque::new(),
+            reconnects: 0,
+            failures: 0,
+            backoff: None,
+            closed: false,
+        };
+
+        Self {
+            inner: Box::pin(stream::unfold(state, Connection::next)),
+        }
+    }
+
+    /// Decodes the data of every event as JSON.
+    pub fn json<T>(self) -> impl Stream<Item = Result<T, HTTPError>> + Send
+    where
+        T: DeserializeOwned,
+    {
+        self.map(|event| event?.json())
+    }
+}
+
+impl Stream for EventSource {
+    type Item = Result<Event, HTTPError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// The state of the connection of an `EventSource`.
+struct Connection {
+    client: Client,
+    path: String,
+    options: EventSourceOptions,
+    parser: EventParser,
+    stream: Option<ByteStream>,
+    pending: VecDeque<Event>,
+    /// The number of reconnections since the last event was received.
+    reconnects: u32,
+    /// The number of reconnections in a row that failed to open.
+    failures: u32,
+    /// The delay before retrying a reconnection that failed to open.
+    backoff: Option<Duration>,
+    closed: bool,
+}
+
+impl Connection {
+    /// Returns the next event, (re)connecting as needed.
+    async fn next(mut self) -> Option<(Result<Event, HTTPError>, Self)> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some((Ok(event), self));
+            }
+            if self.closed {
+                return None;
+            }
+
+            let Some(stream) = self.stream.as_mut() else {
+                match self.open().await {
+                    Ok(stream) => {
+                        self.failures = 0;
+                        self.closed = stream.is_none();
+                        self.stream = stream;
+                    }
+                    Err(err) => match self.retry(&err) {
+                        Some(backoff) => {
+                            debug!("failed to reconnect to the event stream of {}: {err}", self.path);
+                            self.backoff = Some(backoff);
+                            self.reconnects += 1;
+                        }
+                        None => {
+                            self.closed = true;
+                            return Some((Err(err), self));
+                        }
+                    },
+                }
+                continue;
+            };
+
+            match stream.next().await {
+                Some(Ok(chunk)) => {
+                    let events = self.parser.feed(&chunk);
+                    if !events.is_empty() {
+                        self.reconnects = 0;
+                    }
+                    self.pending.extend(events);
+                }
+                Some(Err(err)) => {
+                    debug!("the event stream of {} was lost: {err}", self.path);
+                    self.lost();
+                }
+                None => self.lost(),
+            }
+        }
+    }
+
+    /// Opens the connection, waiting before reconnecting. Returns `None` if
+    /// the stream should not be reopened.
+    async fn open(&mut self) -> Result<Option<ByteStream>, HTTPError> {
+        if self.reconnects > 0 {
+            if self
+                .options
+                .max_reconnects
+                .is_some_and(|max| self.reconnects > max)
+            {
+                return Ok(None);
+            }
+            let retry = self.parser.reconnection_time();
+            let delay = retry.or(self.options.retry).unwrap_or(DEFAULT_RETRY);
+            tokio::time::sleep(self.backoff.take().map_or(delay, |backoff| backoff.max(delay))).await;
+        }
+
+        let mut headers = self.options.headers.clone().unwrap_or_default();
+        headers.insert(ACCEPT, HeaderValue::from_static("text/event-stream"));
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+        if let Some(id) = self.parser.last_event_id() {
+            let value = HeaderValue::from_str(id).map_err(HTTPError::InvalidHeaderValue)?;
+            headers.insert(HeaderName::from_static("last-event-id"), value);
+        }
+
+        let res = self
+            .client
+            .get_stream(&self.path, self.options.query.clone(), Some(headers))
+            .await?
+            .error_for_status()
+            .await?;
+        match res.status {
+            StatusCode::NO_CONTENT => Ok(None),
+            _ => Ok(Some(res.stream)),
+        }
+    }
+
+    /// Returns the delay before retrying a reconnection that failed to open,
+    /// unless the error is not transient or no reconnection is left.
+    fn retry(&mut self, err: &HTTPError) -> Option<Duration> {
+        if self.reconnects == 0
+            || self
+                .options
+                .max_reconnects
+                .is_some_and(|max| self.reconnects >= max)
+        {
+            return None;
+        }
+
+        self.failures += 1;
+        self.client.retry.retry_error(self.failures, err)
+    }
+
+    /// Drops the connection to reopen it.
+    fn lost(&mut self) {
+        self.stream = None;
+        self.parser.reset();
+        self.reconnects += 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Event, EventParser};
+
+    fn parse(chunks: &[&str]) -> Vec<Event> {
+        let mut parser = EventParser::default();
+        chunks
+            .iter()
+            .flat_map(|chunk| parser.feed(chunk.as_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let events =
+            parse(&["\u{feff}: comment\nevent: log\ndata: first\ndata:second\nid: 1\nretry: 500\n\n"]);
+        assert_eq!(
+            events,
+            [Event {
+                event: "log".to_string(),
+                data: "first\nsecond".to_string(),
+                id: Some("1".to_string()),
+                retry: Some(Duration::from_millis(500)),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_split_chunks_and_line_endings() {
+        let events = parse(&["data: a\r", "\n\r", "\ndata", ": b\r\rda", "ta: c\n\n"]);
+        let data: Vec<&str> = events.iter().map(|e| e.data.as_str()).collect();
+        assert_eq!(data, ["a", "b", "c"]);
+        assert!(events.iter().all(|e| e.event == "message"));
+    }
+
+    #[test]
+    fn test_keep_last_event_id() {
+        let events = parse(&["id: 7\ndata: a\n\nevent: ping\n\ndata: b\n\nid\ndata: c\n\n"]);
+        let ids: Vec<Option<&str>> = events.iter().map(|e| e.id.as_deref()).collect();
+        assert_eq!(ids, [Some("7"), Some("7"), None]);
+        assert_eq!(events[1].event, "message");
+    }
+
+    #[test]
+    fn test_reset_discards_partial_event() {
+        let mut parser = EventParser::default();
+        assert!(parser
+            .feed(b"id: 3\nretry: 10\n\nid: 4\ndata: partial")
+            .is_empty());
+        parser.reset();
+
+        let events = parser.feed(b"data: next\n\n");
+        assert_eq!(events[0].data, "next");
+        assert_eq!(parser.last_event_id(), Some("3"));
+        assert_eq!(parser.reconnection_time(), Some(Duration::from_millis(10)));
+    }
+}
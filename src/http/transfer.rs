@@ -0,0 +1,275 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use data_encoding::HEXLOWER;
+use futures::{stream, StreamExt, TryStreamExt};
+use reqwest::header::HeaderMap;
+use reqwest::multipart::Part;
+use reqwest::Body;
+use sha2::{Digest, Sha256};
+use tokio_util::io::ReaderStream;
+
+use super::{ByteStream, HTTPError};
+
+/// Reports how many bytes of a transfer have been sent or received.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Progress {
+    pub transferred: u64,
+    /// The size of the transfer, if known.
+    pub total: Option<u64>,
+}
+
+/// Called with the progress after every chunk of a transfer.
+pub type ProgressFn = Arc<dyn Fn(Progress) + Send + Sync>;
+
+/// Represents the expected digest of a transfer, as a hex string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Checksum {
+    Sha256(String),
+}
+
+/// Calls the callback with the progress after every chunk of the stream.
+pub fn with_progress(stream: ByteStream, total: Option<u64>, callback: ProgressFn) -> ByteStream {
+    let mut transferred = 0;
+    Box::pin(stream.inspect_ok(move |chunk| {
+        transferred += chunk.len() as u64;
+        callback(Progress { transferred, total });
+    }))
+}
+
+/// Computes the digest of the stream as it is read, and fails the stream at
+/// its end with `HTTPError::Checksum` if it does not match the checksum.
+pub fn with_checksum(stream: ByteStream, checksum: Checksum) -> ByteStream {
+    let Checksum::Sha256(expected) = checksum;
+    let state = (stream, Some(Sha256::new()), expected);
+
+    Box::pin(stream::unfold(
+        state,
+        |(mut stream, mut hasher, expected)| async move {
+            let digest = hasher.as_mut()?;
+            match stream.next().await {
+                Some(Ok(chunk)) => {
+                    digest.update(&chunk);
+                    Some((Ok(chunk), (stream, hasher, expected)))
+                }
+                Some(Err(err)) => Some((Err(err), (stream, None, expected))),
+                None => {
+                    let err = verify(&expected, hasher.take()?).err()?;
+                    Some((Err(err), (stream, None, expected)))
+                }
+            }
+        },
+    ))
+}
+
+/// Returns `HTTPError::Checksum` if the digest does not match the expected one.
+fn verify(expected: &str, digest: Sha256) -> Result<(), HTTPError> {
+    let actual = HEXLOWER.encode(&digest.finalize());
+    if actual.eq_ignore_ascii_case(expected) {
+        return Ok(());
+    }
+    Err(HTTPError::Checksum {
+        expected: expected.to_string(),
+        actual,
+    })
+}
+
+/// Represents the body of an upload, either in memory or streamed from a
+/// file or another stream, so that it is never buffered as a whole.
+pub struct Payload {
+    body: PayloadBody,
+    length: Option<u64>,
+}
+
+enum PayloadBody {
+    Bytes(Bytes),
+    Stream(ByteStream),
+}
+
+impl Payload {
+    /// Creates a payload streaming the chunks, `length` is sent as the
+    /// `Content-Length` if known, some providers reject uploads without it.
+    pub fn new(stream: ByteStream, length: Option<u64>) -> Self {
+        Self {
+            body: PayloadBody::Stream(stream),
+            length,
+        }
+    }
+
+    /// Creates a payload streaming the content of the file.
+    pub async fn from_file(path: impl AsRef<Path>) -> Result<Self, HTTPError> {
+        let file = tokio::fs::File::open(path).await.map_err(HTTPError::IoError)?;
+        let length = file.metadata().await.map_err(HTTPError::IoError)?.len();
+        let stream = ReaderStream::new(file).map_err(HTTPError::IoError);
+
+        Ok(Self::new(Box::pin(stream), Some(length)))
+    }
+
+    /// Returns the size of the payload, if known.
+    pub fn length(&self) -> Option<u64> {
+        self.length
+    }
+
+    /// Calls the callback with the progress after every chunk that is sent.
+    pub fn with_progress(self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        let length = self.length;
+        self.map(|stream| with_progress(stream, length, Arc::new(callback)))
+    }
+
+    /// Verifies the payload against the checksum, the upload fails if it does
+    /// not match. In-memory payloads are verified before anything is sent, but
+    /// streamed payloads are verified while they are sent, so the mismatch is
+    /// only reported after the last chunk and the upload is not aborted: the
+    /// server may already have accepted the content.
+    pub fn with_checksum(self, checksum: Checksum) -> Self {
+        let PayloadBody::Bytes(bytes) = &self.body else {
+            return self.map(|stream| with_checksum(stream, checksum));
+        };
+
+        let Checksum::Sha256(expected) = checksum;
+        match verify(&expected, Sha256::new_with_prefix(bytes)) {
+            Ok(()) => self,
+            Err(err) => Self::new(Box::pin(stream::once(async move { Err(err) })), self.length),
+        }
+    }
+
+    /// Returns the payload as a multipart part.
+    pub fn into_part(self) -> Part {
+        match (self.body, self.length) {
+            (PayloadBody::Bytes(bytes), _) => Part::stream(bytes),
+            (PayloadBody::Stream(stream), Some(length)) => {
+                Part::stream_with_length(Body::wrap_stream(stream), length)
+            }
+            (PayloadBody::Stream(stream), None) => Part::stream(Body::wrap_stream(stream)),
+        }
+    }
+
+    /// Returns the payload as a request body, in-memory payloads can be sent again.
+    pub(crate) fn into_body(self) -> Body {
+        match self.body {
+            PayloadBody::Bytes(bytes) => Body::from(bytes),
+            PayloadBody::Stream(stream) => Body::wrap_stream(stream),
+        }
+    }
+
+    fn map(self, f: impl FnOnce(ByteStream) -> ByteStream) -> Self {
+        let stream = match self.body {
+            PayloadBody::Bytes(bytes) => Box::pin(stream::once(async move { Ok(bytes) })),
+            PayloadBody::Stream(stream) => stream,
+        };
+        Self::new(f(stream), self.length)
+    }
+}
+
+impl From<Bytes> for Payload {
+    fn from(bytes: Bytes) -> Self {
+        Self {
+            length: Some(bytes.len() as u64),
+            body: PayloadBody::Bytes(bytes),
+        }
+    }
+}
+
+impl From<Vec<u8>> for Payload {
+    fn from(data: Vec<u8>) -> Self {
+        Bytes::from(data).into()
+    }
+}
+
+/// Specifies how a file is downloaded by `Client::download_to`.
+#[derive(Clone, Default)]
+pub struct DownloadOptions {
+    /// The query parameters to be sent to the server.
+    pub query: Option<HashMap<String, String>>,
+    /// The headers to be sent in addition to (or instead of) the default headers.
+    pub headers: Option<HeaderMap>,
+    /// The expected digest of the file, the download fails if it does not match.
+    pub checksum: Option<Checksum>,
+    /// Called with the progress after every chunk that is received.
+    pub progress: Option<ProgressFn>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use bytes::Bytes;
+    use futures::{stream, StreamExt, TryStreamExt};
+
+    use super::{with_checksum, with_progress, Checksum, Payload, PayloadBody, Progress};
+    use crate::http::{ByteStream, HTTPError};
+
+    // sha256 of "hello world"
+    const DIGEST: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+    fn chunks() -> ByteStream {
+        Box::pin(stream::iter(vec![
+            Ok::<Bytes, HTTPError>(Bytes::from_static(b"hello ")),
+            Ok(Bytes::from_static(b"world")),
+        ]))
+    }
+
+    #[tokio::test]
+    async fn test_with_progress() {
+        let reports = Arc::new(Mutex::new(vec![]));
+        let callback = reports.clone();
+        let stream = with_progress(
+            chunks(),
+            Some(11),
+            Arc::new(move |p| callback.lock().unwrap().push(p)),
+        );
+        let _: Vec<Bytes> = stream.try_collect().await.unwrap();
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(
+            reports[1],
+            Progress {
+                transferred: 11,
+                total: Some(11)
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_checksum() {
+        let stream = with_checksum(chunks(), Checksum::Sha256(DIGEST.to_uppercase()));
+        let data: Vec<Bytes> = stream.try_collect().await.unwrap();
+        assert_eq!(data.concat(), b"hello world");
+
+        let stream = with_checksum(chunks(), Checksum::Sha256("0".repeat(64)));
+        let result: Result<Vec<Bytes>, HTTPError> = stream.try_collect().await;
+        assert!(matches!(result, Err(HTTPError::Checksum { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_verify_in_memory_payload_before_sending() {
+        let payload = Payload::from(b"hello world".to_vec()).with_checksum(Checksum::Sha256(DIGEST.into()));
+        assert!(matches!(payload.body, PayloadBody::Bytes(_)));
+
+        let payload = Payload::from(b"hello world".to_vec()).with_checksum(Checksum::Sha256("0".repeat(64)));
+        let PayloadBody::Stream(mut stream) = payload.body else {
+            panic!("expected the payload to fail");
+        };
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(HTTPError::Checksum { .. }))
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::future::poll_fn;
+use http_body_util::BodyDataStream;
+use reqwest::{Body, Request, Response, ResponseBuilderExt};
+use tower_service::Service;
+
+use super::HTTPError;
+
+/// Sends the requests of a `Client` and returns the responses, once they
+/// have been authenticated and passed through the middlewares.
+///
+/// The default transport is a `reqwest::Client` that sends requests over
+/// the network, with the timeouts, proxy and TLS settings of the builder.
+/// Another transport, e.g. an in-process stand-in of a server, can be set
+/// with `ClientBuilder::transport`; these settings do not apply to it.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Sends the request and returns the response, whose body is read later.
+    async fn send(&self, request: Request) -> Result<Response, HTTPError>;
+}
+
+#[async_trait]
+impl Transport for reqwest::Client {
+    async fn send(&self, request: Request) -> Result<Response, HTTPError> {
+        self.execute(request).await.map_err(HTTPError::ReqwestError)
+    }
+}
+
+/// Sends the requests to a `tower` service in the same process, e.g. an
+/// `axum::Router`, without opening sockets.
+///
+/// # Examples
+///
+/// ```ignore
+/// use amp_common::http::{Client, ServiceTransport};
+///
+/// let router = axum::Router::new().route("/v1/playbooks", axum::routing::get(list_playbooks));
+/// let client = Client::builder("http://localhost")
+///     .transport(ServiceTransport::new(router))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ServiceTransport<S> {
+    service: S,
+}
+
+impl<S> ServiceTransport<S> {
+    /// Creates a transport sending the requests to the service, which is
+    /// cloned for every request.
+    pub fn new(service: S) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait]
+impl<S, B> Transport for ServiceTransport<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<B>> + Clone + Send + Sync + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    S::Future: Send,
+    B: http_body::Body + Send + 'static,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+    Bytes: From<B::Data>,
+{
+    async fn send(&self, request: Request) -> Result<Response, HTTPError> {
+        let url = request.url().clone();
+        let request = http::Request::try_from(request).map_err(HTTPError::ReqwestError)?;
+
+        let mut service = self.service.clone();
+        poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .map_err(|e| HTTPError::Transport(e.into()))?;
+        let response = service
+            .call(request)
+            .await
+            .map_err(|e| HTTPError::Transport(e.into()))?;
+
+        // the URL is set so that it is known to the middlewares, as it is for
+        // responses received over the network.
+        let (parts, body) = response.into_parts();
+        let mut response = http::Response::builder()
+            .url(url)
+            .body(Body::wrap_stream(BodyDataStream::new(body)))
+            .map_err(|e| HTTPError::Transport(e.into()))?;
+        *response.status_mut() = parts.status;
+        *response.version_mut() = parts.version;
+        *response.headers_mut() = parts.headers;
+
+        Ok(Response::from(response))
+    }
+}
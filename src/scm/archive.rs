@@ -0,0 +1,502 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use futures::TryStreamExt;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tokio_util::io::{StreamReader, SyncIoBridge};
+
+use super::errors::SCMError;
+use crate::http::{ByteStream, HTTPError};
+
+/// Represents the format of a repository archive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArchiveFormat {
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Returns the file extension of the archive format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+}
+
+/// Extracts a repository archive stream into the destination directory.
+///
+/// Archives produced by the providers wrap everything in a single top-level
+/// directory (e.g. `octocat-Hello-World-7fd1a60`), which is stripped. When
+/// `subpath` is given, only the entries below it are extracted, relative to it.
+/// Entries that would escape the destination directory are rejected.
+///
+/// Tarballs are unpacked while they are received, zipballs have their index at
+/// the end and are spooled to a temporary file first.
+///
+/// Returns the relative paths of the extracted files.
+pub async fn extract(
+    stream: ByteStream,
+    format: ArchiveFormat,
+    dest: &Path,
+    subpath: Option<&str>,
+) -> Result<Vec<PathBuf>, SCMError> {
+    let dest = dest.to_path_buf();
+    let subpath = subpath.map(PathBuf::from);
+
+    match format {
+        ArchiveFormat::TarGz => {
+            let reader = SyncIoBridge::new(StreamReader::new(stream.map_err(io::Error::other)));
+            blocking(move || {
+                fs::create_dir_all(&dest).map_err(SCMError::IoError)?;
+                unpack_tar(reader, &dest, subpath.as_deref())
+            })
+            .await
+            .map_err(client_error)
+        }
+        ArchiveFormat::Zip => {
+            let spool = std::env::temp_dir().join(format!("amp-archive-{:016x}.zip", fastrand::u64(..)));
+            let result = match spool_to(stream, &spool).await {
+                Ok(file) => blocking(move || unpack(file, format, &dest, subpath.as_deref())).await,
+                Err(err) => Err(err),
+            };
+            let _ = tokio::fs::remove_file(&spool).await;
+            result
+        }
+    }
+}
+
+/// Writes the stream to the file, returning it opened for reading.
+async fn spool_to(mut stream: ByteStream, path: &Path) -> Result<fs::File, SCMError> {
+    let mut file = tokio::fs::File::options()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .await
+        .map_err(SCMError::IoError)?;
+    while let Some(chunk) = stream.try_next().await.map_err(SCMError::ClientError)? {
+        file.write_all(&chunk).await.map_err(SCMError::IoError)?;
+    }
+    file.flush().await.map_err(SCMError::IoError)?;
+    file.rewind().await.map_err(SCMError::IoError)?;
+
+    Ok(file.into_std().await)
+}
+
+async fn blocking<F>(f: F) -> Result<Vec<PathBuf>, SCMError>
+where
+    F: FnOnce() -> Result<Vec<PathBuf>, SCMError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| SCMError::IoError(io::Error::other(e)))?
+}
+
+/// Returns the error of the download if the archive could not be read because of it.
+fn client_error(err: SCMError) -> SCMError {
+    match err {
+        SCMError::IoError(e) if e.get_ref().is_some_and(|inner| inner.is::<HTTPError>()) => {
+            match e.into_inner().map(|inner| inner.downcast::<HTTPError>()) {
+                Some(Ok(inner)) => SCMError::ClientError(*inner),
+                _ => unreachable!("the inner error is a HTTPError"),
+            }
+        }
+        err => err,
+    }
+}
+
+/// Unpacks a repository archive from the reader into the destination directory,
+/// see [`extract`] for the path handling rules.
+pub fn unpack<R>(
+    reader: R,
+    format: ArchiveFormat,
+    dest: &Path,
+    subpath: Option<&Path>,
+) -> Result<Vec<PathBuf>, SCMError>
+where
+    R: Read + io::Seek,
+{
+    fs::create_dir_all(dest).map_err(SCMError::IoError)?;
+    match format {
+        ArchiveFormat::TarGz => unpack_tar(reader, dest, subpath),
+        ArchiveFormat::Zip => unpack_zip(reader, dest, subpath),
+    }
+}
+
+fn unpack_tar<R: Read>(reader: R, dest: &Path, subpath: Option<&Path>) -> Result<Vec<PathBuf>, SCMError> {
+    let mut archive = tar::Archive::new(GzDecoder::new(reader));
+    let mut files = vec![];
+
+    for entry in archive.entries().map_err(SCMError::IoError)? {
+        let mut entry = entry.map_err(SCMError::IoError)?;
+        let path = entry.path().map_err(SCMError::IoError)?.into_owned();
+        let Some(relative) = relative_path(&path, subpath)? else {
+            continue;
+        };
+
+        let kind = entry.header().entry_type();
+        if kind.is_symlink() {
+            let target = entry.link_name().map_err(SCMError::IoError)?.unwrap_or_default();
+            ensure_link_within(&relative, &target)?;
+        } else if !(kind.is_file() || kind.is_dir()) {
+            // skip hard links, pax headers and other special entries,
+            // which are never produced by `git archive`.
+            continue;
+        }
+
+        ensure_no_link_on_disk(dest, &relative)?;
+        let target = dest.join(&relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(SCMError::IoError)?;
+        }
+        entry.unpack(&target).map_err(SCMError::IoError)?;
+        if !kind.is_dir() {
+            files.push(relative);
+        }
+    }
+
+    Ok(files)
+}
+
+fn unpack_zip<R>(reader: R, dest: &Path, subpath: Option<&Path>) -> Result<Vec<PathBuf>, SCMError>
+where
+    R: Read + io::Seek,
+{
+    let mut archive = zip::ZipArchive::new(reader).map_err(|e| SCMError::IoError(e.into()))?;
+    let mut files = vec![];
+
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(|e| SCMError::IoError(e.into()))?;
+        let Some(path) = file.enclosed_name() else {
+            return Err(SCMError::UnsafeArchiveEntry(
+                file.name_raw().escape_ascii().to_string(),
+            ));
+        };
+        let Some(relative) = relative_path(&path, subpath)? else {
+            continue;
+        };
+
+        ensure_no_link_on_disk(dest, &relative)?;
+        let target = dest.join(&relative);
+        if file.is_dir() {
+            fs::create_dir_all(&target).map_err(SCMError::IoError)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(SCMError::IoError)?;
+        }
+
+        if file.is_symlink() {
+            let mut link = String::new();
+            file.read_to_string(&mut link).map_err(SCMError::IoError)?;
+            ensure_link_within(&relative, Path::new(&link))?;
+            if !symlink(Path::new(&link), &target)? {
+                continue;
+            }
+        } else {
+            let mut out = fs::File::create(&target).map_err(SCMError::IoError)?;
+            io::copy(&mut file, &mut out).map_err(SCMError::IoError)?;
+        }
+        files.push(relative);
+    }
+
+    Ok(files)
+}
+
+/// Returns the entry path relative to the extraction root, stripping the top-level
+/// directory and the subpath, or `None` if the entry is outside of the subpath.
+fn relative_path(path: &Path, subpath: Option<&Path>) -> Result<Option<PathBuf>, SCMError> {
+    let mut components = vec![];
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => components.push(part),
+            Component::CurDir => {}
+            _ => return Err(SCMError::UnsafeArchiveEntry(path.display().to_string())),
+        }
+    }
+
+    // the first component is the top-level directory of the archive.
+    let mut relative: PathBuf = components.iter().skip(1).collect();
+    if let Some(subpath) = subpath {
+        let subpath: PathBuf = subpath
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .collect();
+        match relative.strip_prefix(&subpath) {
+            Ok(stripped) => relative = stripped.to_path_buf(),
+            Err(_) => return Ok(None),
+        }
+    }
+
+    if relative.as_os_str().is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(relative))
+}
+
+/// Ensures a link at `path` pointing to `target` does not escape the extraction root.
+fn ensure_link_within(path: &Path, target: &Path) -> Result<(), SCMError> {
+    let unsafe_link = || SCMError::UnsafeArchiveEntry(format!("{} -> {}", path.display(), target.display()));
+    if target.is_absolute() {
+        return Err(unsafe_link());
+    }
+
+    // links are resolved relative to the directory containing them.
+    let mut depth = path.components().count() as isize - 1;
+    for component in target.components() {
+        match component {
+            Component::ParentDir => depth -= 1,
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => {}
+            _ => return Err(unsafe_link()),
+        }
+        if depth < 0 {
+            return Err(unsafe_link());
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensures none of the components of the entry path is a link extracted by a
+/// previous entry. Links are only checked textually by `ensure_link_within`, a
+/// chain of them (e.g. `a/b -> ..` and `a/b/c -> ..`) could otherwise be used to
+/// write outside of the destination directory.
+fn ensure_no_link_on_disk(dest: &Path, relative: &Path) -> Result<(), SCMError> {
+    let mut path = dest.to_path_buf();
+    for component in relative.components() {
+        path.push(component);
+        match fs::symlink_metadata(&path) {
+            Ok(metadata) if metadata.file_type().is_symlink() => {
+                return Err(SCMError::UnsafeArchiveEntry(relative.display().to_string()));
+            }
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => break,
+            Err(e) => return Err(SCMError::IoError(e)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the link, returns false if links are not supported on the platform.
+#[cfg(unix)]
+fn symlink(original: &Path, link: &Path) -> Result<bool, SCMError> {
+    std::os::unix::fs::symlink(original, link).map_err(SCMError::IoError)?;
+    Ok(true)
+}
+
+#[cfg(not(unix))]
+fn symlink(_original: &Path, _link: &Path) -> Result<bool, SCMError> {
+    Ok(false)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, Write};
+    use std::path::{Path, PathBuf};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use bytes::Bytes;
+    use futures::stream;
+
+    use super::{ensure_link_within, extract, relative_path, unpack, ArchiveFormat};
+    use crate::http::{ByteStream, HTTPError};
+    use crate::scm::errors::SCMError;
+
+    fn tarball(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(GzEncoder::new(vec![], Compression::default()));
+        for (path, content) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, content.as_bytes())
+                .unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn symlinks_tarball(links: &[(&str, &str)], file: &str) -> Vec<u8> {
+        let mut builder = tar::Builder::new(GzEncoder::new(vec![], Compression::default()));
+        for (path, target) in links {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            header.set_mode(0o777);
+            builder.append_link(&mut header, path, target).unwrap();
+        }
+        let mut header = tar::Header::new_gnu();
+        header.set_size(4);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, file, &b"evil"[..]).unwrap();
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn symlinks_zipball(links: &[(&str, &str)], file: &str) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(vec![]));
+        let options = zip::write::SimpleFileOptions::default();
+        for (path, target) in links {
+            writer.add_symlink(*path, *target, options).unwrap();
+        }
+        writer.start_file(file, options).unwrap();
+        writer.write_all(b"evil").unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn zipball(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(vec![]));
+        for (path, content) in entries {
+            writer
+                .start_file(*path, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn tempdir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("amp-archive-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let path = Path::new("octocat-Hello-World-7fd1a60/src/main.rs");
+        assert_eq!(
+            relative_path(path, None).unwrap(),
+            Some(PathBuf::from("src/main.rs"))
+        );
+        assert_eq!(
+            relative_path(path, Some(Path::new("src"))).unwrap(),
+            Some(PathBuf::from("main.rs"))
+        );
+        assert_eq!(relative_path(path, Some(Path::new("docs"))).unwrap(), None);
+        assert!(relative_path(Path::new("root/../../etc/passwd"), None).is_err());
+    }
+
+    #[test]
+    fn test_ensure_link_within() {
+        assert!(ensure_link_within(Path::new("a/b"), Path::new("../c")).is_ok());
+        assert!(ensure_link_within(Path::new("a/b"), Path::new("../../c")).is_err());
+        assert!(ensure_link_within(Path::new("a"), Path::new("/etc/passwd")).is_err());
+    }
+
+    #[test]
+    fn test_unpack_tar_gz() {
+        let data = tarball(&[
+            ("repo-sha/README.md", "hello"),
+            ("repo-sha/app/main.rs", "fn main() {}"),
+        ]);
+        let dest = tempdir("tar");
+
+        let files = unpack(
+            Cursor::new(data),
+            ArchiveFormat::TarGz,
+            &dest,
+            Some(Path::new("app")),
+        )
+        .unwrap();
+        assert_eq!(files, vec![PathBuf::from("main.rs")]);
+        assert_eq!(
+            std::fs::read_to_string(dest.join("main.rs")).unwrap(),
+            "fn main() {}"
+        );
+        assert!(!dest.join("README.md").exists());
+
+        std::fs::remove_dir_all(dest).unwrap();
+    }
+
+    #[test]
+    fn test_unpack_zip() {
+        let data = zipball(&[
+            ("repo-sha/README.md", "hello"),
+            ("repo-sha/app/main.rs", "fn main() {}"),
+        ]);
+        let dest = tempdir("zip");
+
+        let files = unpack(Cursor::new(data), ArchiveFormat::Zip, &dest, None).unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(std::fs::read_to_string(dest.join("README.md")).unwrap(), "hello");
+
+        std::fs::remove_dir_all(dest).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_extract_stream() {
+        let entries = [
+            ("repo-sha/README.md", "hello"),
+            ("repo-sha/app/main.rs", "fn main() {}"),
+        ];
+        let archives = [
+            (ArchiveFormat::TarGz, tarball(&entries)),
+            (ArchiveFormat::Zip, zipball(&entries)),
+        ];
+
+        for (format, data) in archives {
+            let chunks: Vec<Result<Bytes, HTTPError>> =
+                data.chunks(64).map(|c| Ok(Bytes::copy_from_slice(c))).collect();
+            let stream: ByteStream = Box::pin(stream::iter(chunks));
+            let dest = tempdir(&format!("stream-{}", format.extension()));
+
+            let files = extract(stream, format, &dest, Some("app")).await.unwrap();
+            assert_eq!(files, vec![PathBuf::from("main.rs")], "{format:?}");
+            assert_eq!(
+                std::fs::read_to_string(dest.join("main.rs")).unwrap(),
+                "fn main() {}"
+            );
+
+            std::fs::remove_dir_all(dest).unwrap();
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reject_symlink_chain() {
+        // each link stays within the root on its own, `a/b/c` resolves to its parent.
+        let links = [("repo-sha/a/b", ".."), ("repo-sha/a/b/c", "..")];
+        let file = "repo-sha/a/b/c/evil";
+        let archives = [
+            (ArchiveFormat::TarGz, symlinks_tarball(&links, file)),
+            (ArchiveFormat::Zip, symlinks_zipball(&links, file)),
+        ];
+
+        for (format, data) in archives {
+            let root = tempdir(&format!("symlinks-{}", format.extension()));
+            let dest = root.join("dest");
+
+            let result = unpack(Cursor::new(data), format, &dest, None);
+            assert!(
+                matches!(result, Err(SCMError::UnsafeArchiveEntry(_))),
+                "{format:?}"
+            );
+            assert!(!root.join("evil").exists(), "{format:?}");
+            assert!(!dest.join("evil").exists(), "{format:?}");
+
+            std::fs::remove_dir_all(root).unwrap();
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::search::SearchCapabilities;
+
+/// Describes the services and features supported by a driver, so callers can
+/// tell ahead of time whether an operation will return `SCMError::Unsupported`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Capabilities {
+    pub contents: bool,
+    pub git: bool,
+    /// Whether branches and tags can be created and deleted.
+    pub git_write: bool,
+    pub repositories: bool,
+    pub users: bool,
+    pub organizations: bool,
+    pub issues: bool,
+    pub releases: bool,
+    /// Whether release assets can be listed and uploaded.
+    pub release_assets: bool,
+    /// Whether releases can be deleted.
+    pub release_delete: bool,
+    pub deploy_keys: bool,
+    pub search: SearchCapabilities,
+    /// Whether `GitService::get_tree` lists the whole tree in a single request
+    /// when asked to recurse, otherwise only the top level is returned.
+    pub recursive_tree: bool,
+    /// Whether `GitService::archive` can restrict the archive to a path.
+    pub archive_path: bool,
+}
@@ -12,11 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::content::ContentService;
+use super::capabilities::Capabilities;
+use super::content::{ContentService, File};
+use super::discovery::{self, Discovery};
 use super::driver::{Driver, DriverTrait};
 use super::errors::SCMError;
 use super::git::GitService;
+use super::issue::IssueService;
+use super::key::DeployKeyService;
+use super::org::OrganizationService;
+use super::release::ReleaseService;
 use super::repo::RepositoryService;
+use super::search::SearchService;
+use super::user::UserService;
+use super::walk::{self, WalkOptions};
 use crate::config::Credentials;
 
 /// Specifies optional pagination
@@ -56,6 +65,62 @@ impl Client {
     pub fn repositories(&self) -> Box<dyn RepositoryService> {
         self.driver.repositories()
     }
+
+    pub fn users(&self) -> Box<dyn UserService> {
+        self.driver.users()
+    }
+
+    pub fn organizations(&self) -> Box<dyn OrganizationService> {
+        self.driver.organizations()
+    }
+
+    pub fn issues(&self) -> Box<dyn IssueService> {
+        self.driver.issues()
+    }
+
+    pub fn releases(&self) -> Box<dyn ReleaseService> {
+        self.driver.releases()
+    }
+
+    pub fn deploy_keys(&self) -> Box<dyn DeployKeyService> {
+        self.driver.deploy_keys()
+    }
+
+    pub fn search(&self) -> Box<dyn SearchService> {
+        self.driver.search()
+    }
+
+    pub fn capabilities(&self) -> Capabilities {
+        self.driver.capabilities()
+    }
+}
+
+impl Client {
+    /// Returns every file under the path of the repository at the reference,
+    /// filtered by the include and exclude patterns of the options.
+    pub async fn walk(
+        &self,
+        repo: &str,
+        path: &str,
+        reference: &str,
+        options: &WalkOptions,
+    ) -> Result<Vec<File>, SCMError> {
+        let git = self.git();
+        let contents = self.contents();
+        // list the directories instead when the trees API can not recurse.
+        let git = match self.capabilities().recursive_tree {
+            true => Some(git.as_ref()),
+            false => None,
+        };
+
+        walk::walk(git, contents.as_ref(), repo, path, reference, options).await
+    }
+
+    /// Finds and parses every character manifest (`.amp.toml` and `devcontainer.json`)
+    /// in the repository at the reference.
+    pub async fn discover(&self, repo: &str, reference: &str) -> Result<Vec<Discovery>, SCMError> {
+        discovery::discover(self, repo, reference).await
+    }
 }
 
 impl Client {
@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use super::errors::SCMError;
+use crate::http::ByteStream;
 
 /// represents a file content in a repository.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -44,6 +45,10 @@ pub trait ContentService: Send + Sync {
     /// Returns the repository file content by path.
     async fn find(&self, repo: &str, path: &str, reference: &str) -> Result<Content, SCMError>;
 
+    /// Returns a stream of the raw file content by path, without buffering it in memory.
+    /// Git LFS pointer files are resolved to the real content.
+    async fn raw(&self, repo: &str, path: &str, reference: &str) -> Result<ByteStream, SCMError>;
+
     /// Returns the file list in a repository folder.
     async fn list(&self, repo: &str, path: &str, reference: &str) -> Result<Vec<File>, SCMError>;
 }
@@ -0,0 +1,232 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::{stream, StreamExt};
+
+use super::client::Client;
+use super::errors::SCMError;
+use super::walk::WalkOptions;
+use crate::devcontainer::{self, DevContainer};
+use crate::schema::{Build, Character, Deploy, DockerfileConfig};
+
+/// The file name of the Amphitheatre character manifest.
+pub const CHARACTER_MANIFEST: &str = ".amp.toml";
+
+/// The file names of the dev container configuration.
+pub const DEVCONTAINER_MANIFESTS: [&str; 2] = ["devcontainer.json", ".devcontainer.json"];
+
+/// Represents a character manifest found in a repository.
+#[derive(Debug)]
+pub struct Discovery {
+    /// The path of the manifest file in the repository.
+    pub path: String,
+    /// The parsed character, or the reason it could not be fetched or parsed.
+    pub character: Result<Character, SCMError>,
+}
+
+/// Finds every character manifest in the repository at the reference, then
+/// fetches and parses them. Errors of a single manifest are reported on its
+/// `Discovery` rather than failing the whole discovery.
+pub async fn discover(client: &Client, repo: &str, reference: &str) -> Result<Vec<Discovery>, SCMError> {
+    let repository = client
+        .repositories()
+        .find(repo)
+        .await?
+        .ok_or_else(|| SCMError::NotFound(repo.to_string()))?;
+
+    let mut include = vec![format!("**/{CHARACTER_MANIFEST}")];
+    include.extend(DEVCONTAINER_MANIFESTS.iter().map(|name| format!("**/{name}")));
+    let options = WalkOptions {
+        include,
+        ..Default::default()
+    };
+    let files = client.walk(repo, "", reference, &options).await?;
+
+    let contents = client.contents();
+    let contents = contents.as_ref();
+    let repository = repository.clone.as_str();
+    let discoveries = stream::iter(files)
+        .map(|file| async move {
+            let character = match contents.find(repo, &file.path, reference).await {
+                Ok(content) => parse(&file.path, &content.data, repository),
+                Err(err) => Err(err),
+            };
+            Discovery {
+                path: file.path,
+                character,
+            }
+        })
+        .buffered(options.concurrency)
+        .collect()
+        .await;
+
+    Ok(discoveries)
+}
+
+/// Parses the manifest at the path into a character, by its file name.
+pub fn parse(path: &str, data: &[u8], repository: &str) -> Result<Character, SCMError> {
+    let (dir, name) = path.rsplit_once('/').unwrap_or(("", path));
+    let invalid = |e: String| SCMError::InvalidManifest(path.to_string(), e);
+
+    if name == CHARACTER_MANIFEST {
+        let content = std::str::from_utf8(data).map_err(|e| invalid(e.to_string()))?;
+        return toml::from_str(content).map_err(|e| invalid(e.to_string()));
+    }
+
+    if DEVCONTAINER_MANIFESTS.contains(&name) {
+        let container: DevContainer = devcontainer::from_slice(data).map_err(|e| invalid(e.to_string()))?;
+        return Ok(from_devcontainer(container, dir, repository));
+    }
+
+    Err(invalid("unknown manifest".to_string()))
+}
+
+/// Converts a dev container configuration to a character. The character is
+/// rooted at the directory holding the `.devcontainer` folder (or the file),
+/// and the build paths are made relative to it.
+fn from_devcontainer(container: DevContainer, dir: &str, repository: &str) -> Character {
+    let (root, base) = match dir.rsplit_once('/') {
+        Some((root, ".devcontainer")) => (root, ".devcontainer"),
+        None if dir == ".devcontainer" => ("", ".devcontainer"),
+        _ => (dir, ""),
+    };
+
+    let name = container
+        .common
+        .name
+        .clone()
+        .or_else(|| {
+            root.rsplit('/')
+                .next()
+                .filter(|v| !v.is_empty())
+                .map(String::from)
+        })
+        .or_else(|| repository_name(repository))
+        .unwrap_or_default();
+
+    let mut character = Character::new(&name);
+    character.meta.repository = repository.to_string();
+
+    if let Some(build) = container.build {
+        character.build = Some(Build {
+            dockerfile: Some(DockerfileConfig {
+                dockerfile: join(base, &build.dockerfile),
+            }),
+            context: Some(join(base, build.context.as_deref().unwrap_or("."))),
+            ..Default::default()
+        });
+    }
+
+    let image = container.image_container.map(|v| v.image);
+    let env = container.common.container_env;
+    if image.is_some() || env.is_some() {
+        character.deploy = Some(Deploy {
+            image,
+            env,
+            ..Default::default()
+        });
+    }
+
+    character
+}
+
+/// Returns the repository name from its clone address.
+fn repository_name(repository: &str) -> Option<String> {
+    let name = repository.trim_end_matches('/').rsplit('/').next()?;
+    Some(name.trim_end_matches(".git").to_string()).filter(|v| !v.is_empty())
+}
+
+/// Joins the relative path to the base directory, resolving `.` and `..` parts.
+fn join(base: &str, path: &str) -> String {
+    let mut parts: Vec<&str> = base.split('/').filter(|v| !v.is_empty()).collect();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+
+    match parts.is_empty() {
+        true => ".".to_string(),
+        false => parts.join("/"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{join, parse};
+
+    const REPOSITORY: &str = "https://github.com/amphitheatre-app/amp-example-rust.git";
+
+    #[test]
+    fn test_join() {
+        assert_eq!(join(".devcontainer", "Dockerfile"), ".devcontainer/Dockerfile");
+        assert_eq!(join(".devcontainer", ".."), ".");
+        assert_eq!(join("", "./Dockerfile"), "Dockerfile");
+    }
+
+    #[test]
+    fn test_parse_character_manifest() {
+        let data = r#"
+            name = "amp-example-rust"
+            version = "0.0.1"
+            repository = "https://github.com/amphitheatre-app/amp-example-rust.git"
+        "#;
+
+        let character = parse("services/api/.amp.toml", data.as_bytes(), REPOSITORY).unwrap();
+        assert_eq!(character.meta.name, "amp-example-rust");
+        assert_eq!(character.meta.version, Some("0.0.1".to_string()));
+    }
+
+    #[test]
+    fn test_parse_invalid_manifest() {
+        let result = parse(".amp.toml", b"name = ", REPOSITORY);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_devcontainer() {
+        let data = r#"{
+            // the development container of the api
+            "build": { "dockerfile": "Dockerfile", "context": ".." },
+            "containerEnv": { "RUST_LOG": "debug" }
+        }"#;
+
+        let character = parse(
+            "services/api/.devcontainer/devcontainer.json",
+            data.as_bytes(),
+            REPOSITORY,
+        )
+        .unwrap();
+        assert_eq!(character.meta.name, "api");
+        assert_eq!(character.meta.repository, REPOSITORY);
+
+        let build = character.build.unwrap();
+        assert_eq!(build.dockerfile.unwrap().dockerfile, ".devcontainer/Dockerfile");
+        assert_eq!(build.context, Some(".".to_string()));
+        assert!(character.deploy.unwrap().env.unwrap().contains_key("RUST_LOG"));
+    }
+
+    #[test]
+    fn test_parse_root_devcontainer_uses_repository_name() {
+        let data = r#"{ "image": "rust:1" }"#;
+
+        let character = parse(".devcontainer.json", data.as_bytes(), REPOSITORY).unwrap();
+        assert_eq!(character.meta.name, "amp-example-rust");
+        assert_eq!(character.deploy.unwrap().image, Some("rust:1".to_string()));
+    }
+}
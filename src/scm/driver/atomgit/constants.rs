@@ -14,10 +14,39 @@
 
 pub const ATOMGIT_ENDPOINT: &str = "https://api.atomgit.com";
 
+// The hosts of atomgit.com, besides the API, that are sent the credentials
+// (the Git LFS batch API)
+pub const ATOMGIT_API_HOST: &str = "api.atomgit.com";
+pub const ATOMGIT_TRUSTED_HOSTS: [&str; 1] = ["atomgit.com"];
+
 // REST API paths
 pub const ATOMGIT_PATH_CONTENTS: &str = "/repos/{repo}/contents/{file}";
 pub const ATOMGIT_PATH_BRANCHES: &str = "/repos/{repo}/branches";
+pub const ATOMGIT_PATH_BRANCH: &str = "/repos/{repo}/branches/{branch}";
 pub const ATOMGIT_PATH_TAGS: &str = "/repos/{repo}/tags";
+pub const ATOMGIT_PATH_TAG: &str = "/repos/{repo}/tags/{tag}";
 pub const ATOMGIT_PATH_COMMITS: &str = "/repos/{repo}/commits/{reference}";
 pub const ATOMGIT_PATH_REPOS: &str = "/repos/{repo}";
 pub const ATOMGIT_PATH_GIT_TREES: &str = "/repos/{repo}/trees/{tree_sha}";
+pub const ATOMGIT_PATH_ARCHIVE: &str = "/repos/{repo}/{format}/{reference}";
+pub const ATOMGIT_PATH_RAW: &str = "/repos/{repo}/raw/{file}";
+pub const ATOMGIT_PATH_USER: &str = "/user";
+pub const ATOMGIT_PATH_USERS: &str = "/users/{login}";
+pub const ATOMGIT_PATH_USER_EMAILS: &str = "/user/emails";
+pub const ATOMGIT_PATH_USER_ORGS: &str = "/user/orgs";
+pub const ATOMGIT_PATH_ORGS: &str = "/orgs/{org}";
+pub const ATOMGIT_PATH_ORG_MEMBERS: &str = "/orgs/{org}/members";
+pub const ATOMGIT_PATH_ISSUES: &str = "/repos/{repo}/issues";
+pub const ATOMGIT_PATH_ISSUE: &str = "/repos/{repo}/issues/{number}";
+pub const ATOMGIT_PATH_ISSUE_COMMENTS: &str = "/repos/{repo}/issues/{number}/comments";
+pub const ATOMGIT_PATH_ISSUE_COMMENT: &str = "/repos/{repo}/issues/comments/{id}";
+pub const ATOMGIT_PATH_PULL_COMMENTS: &str = "/repos/{repo}/pulls/{number}/comments";
+pub const ATOMGIT_PATH_PULL_COMMENT: &str = "/repos/{repo}/pulls/comments/{id}";
+pub const ATOMGIT_PATH_RELEASES: &str = "/repos/{repo}/releases";
+pub const ATOMGIT_PATH_RELEASE: &str = "/repos/{repo}/releases/{id}";
+pub const ATOMGIT_PATH_RELEASE_BY_TAG: &str = "/repos/{repo}/releases/tags/{tag}";
+pub const ATOMGIT_PATH_RELEASE_LATEST: &str = "/repos/{repo}/releases/latest";
+pub const ATOMGIT_PATH_KEYS: &str = "/repos/{repo}/keys";
+pub const ATOMGIT_PATH_KEY: &str = "/repos/{repo}/keys/{id}";
+pub const ATOMGIT_PATH_COLLABORATOR_PERMISSION: &str = "/repos/{repo}/collaborators/{user}/permission";
+pub const ATOMGIT_PATH_LANGUAGES: &str = "/repos/{repo}/languages";
@@ -18,10 +18,13 @@ use async_trait::async_trait;
 use data_encoding::BASE64_MIME as BASE64;
 use serde::{Deserialize, Serialize};
 
-use super::constants::ATOMGIT_PATH_CONTENTS;
-use crate::http::{endpoint::Endpoint, Client};
+use super::constants::{ATOMGIT_PATH_CONTENTS, ATOMGIT_PATH_RAW};
+use super::repo::AtomGitRepoService;
+use crate::http::{endpoint::Endpoint, ByteStream, Client};
 use crate::scm::content::{Content, ContentService, File};
 use crate::scm::errors::SCMError;
+use crate::scm::lfs;
+use crate::scm::utils;
 
 pub struct AtomGitContentService {
     pub client: Client,
@@ -51,6 +54,27 @@ impl ContentService for AtomGitContentService {
         }
     }
 
+    /// Gets the raw contents of a file in a repository.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-raw
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/raw/README.md?ref=master
+    async fn raw(&self, repo: &str, file: &str, reference: &str) -> Result<ByteStream, SCMError> {
+        let path = ATOMGIT_PATH_RAW.replace("{repo}", repo).replace("{file}", file);
+        let options = Some(HashMap::from([("ref".to_string(), reference.to_string())]));
+        let res = self
+            .client
+            .get_stream(&path, options, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        let repositories = AtomGitRepoService {
+            client: self.client.clone(),
+        };
+        lfs::resolve(&self.client, res.stream, &repositories, repo).await
+    }
+
     /// Gets the file list of a directory in a repository.
     ///
     /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-conent/
@@ -14,12 +14,25 @@
 
 use super::content::AtomGitContentService;
 use super::git::AtomGitService;
+use super::issue::AtomGitIssueService;
+use super::key::AtomGitDeployKeyService;
+use super::org::AtomGitOrganizationService;
+use super::release::AtomGitReleaseService;
 use super::repo::AtomGitRepoService;
+use super::search::AtomGitSearchService;
+use super::user::AtomGitUserService;
 use crate::http::Client;
+use crate::scm::capabilities::Capabilities;
 use crate::scm::content::ContentService;
 use crate::scm::driver::DriverTrait;
 use crate::scm::git::GitService;
+use crate::scm::issue::IssueService;
+use crate::scm::key::DeployKeyService;
+use crate::scm::org::OrganizationService;
+use crate::scm::release::ReleaseService;
 use crate::scm::repo::RepositoryService;
+use crate::scm::search::SearchService;
+use crate::scm::user::UserService;
 
 pub struct AtomGitDriver {
     pub client: Client,
@@ -43,11 +56,69 @@ impl DriverTrait for AtomGitDriver {
             client: self.client.clone(),
         })
     }
+
+    fn users(&self) -> Box<dyn UserService> {
+        Box::new(AtomGitUserService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn organizations(&self) -> Box<dyn OrganizationService> {
+        Box::new(AtomGitOrganizationService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn issues(&self) -> Box<dyn IssueService> {
+        Box::new(AtomGitIssueService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn releases(&self) -> Box<dyn ReleaseService> {
+        Box::new(AtomGitReleaseService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn deploy_keys(&self) -> Box<dyn DeployKeyService> {
+        Box::new(AtomGitDeployKeyService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn search(&self) -> Box<dyn SearchService> {
+        Box::new(AtomGitSearchService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            contents: true,
+            git: true,
+            git_write: true,
+            repositories: true,
+            users: true,
+            organizations: true,
+            issues: true,
+            releases: true,
+            release_assets: false,
+            release_delete: false,
+            deploy_keys: true,
+            search: self.search().capabilities(),
+            recursive_tree: true,
+            archive_path: false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::scm::client::ListOptions;
     use crate::scm::driver::{atomgit, DriverTrait};
+    use crate::scm::errors::SCMError;
+    use crate::scm::search::SearchOptions;
 
     #[test]
     fn return_git_service() {
@@ -58,4 +129,16 @@ mod test {
     fn return_repo_service() {
         atomgit::default().unwrap().repositories();
     }
+
+    #[tokio::test]
+    async fn unsupported_search_returns_error() {
+        let driver = atomgit::default().unwrap();
+        assert!(!driver.capabilities().search.code);
+
+        let result = driver
+            .search()
+            .code(&SearchOptions::default(), ListOptions::default())
+            .await;
+        assert!(matches!(result, Err(SCMError::Unsupported(_))));
+    }
 }
@@ -17,14 +17,17 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::constants::{
-    ATOMGIT_PATH_BRANCHES, ATOMGIT_PATH_COMMITS, ATOMGIT_PATH_GIT_TREES, ATOMGIT_PATH_TAGS,
+    ATOMGIT_PATH_ARCHIVE, ATOMGIT_PATH_BRANCH, ATOMGIT_PATH_BRANCHES, ATOMGIT_PATH_COMMITS,
+    ATOMGIT_PATH_GIT_TREES, ATOMGIT_PATH_TAG, ATOMGIT_PATH_TAGS,
 };
 use super::utils::convert_list_options;
 use super::AtomGitFile;
-use crate::http::{endpoint::Endpoint, Client};
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{ByteStream, Client};
+use crate::scm::archive::ArchiveFormat;
 use crate::scm::client::ListOptions;
 use crate::scm::errors::SCMError;
-use crate::scm::git::{Commit, GitService, Reference, Signature, Tree, TreeEntry};
+use crate::scm::git::{Commit, GitService, Reference, ReferenceInput, Signature, TagInput, Tree, TreeEntry};
 use crate::scm::utils;
 
 pub struct AtomGitService {
@@ -73,6 +76,103 @@ impl GitService for AtomGitService {
         Ok(vec![])
     }
 
+    /// Returns a single branch by name.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-branch
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/branches/master
+    async fn find_branch(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        let path = ATOMGIT_PATH_BRANCH
+            .replace("{repo}", repo)
+            .replace("{branch}", name);
+        let res = self
+            .client
+            .get::<AtomGitBranch>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| (&v).into()))
+    }
+
+    /// Returns a single tag by name.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-tag
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/tags/v1.0.0
+    async fn find_tag(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        let path = ATOMGIT_PATH_TAG.replace("{repo}", repo).replace("{tag}", name);
+        let res = self
+            .client
+            .get::<AtomGitTag>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| (&v).into()))
+    }
+
+    /// Creates a branch pointing to the commit.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/post-branch
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/branches
+    async fn create_branch(&self, repo: &str, input: &ReferenceInput) -> Result<Reference, SCMError> {
+        let path = ATOMGIT_PATH_BRANCHES.replace("{repo}", repo);
+        let body = AtomGitBranchInput {
+            branch_name: input.name.clone(),
+            refs: input.sha.clone(),
+        };
+        let res = self
+            .client
+            .post::<AtomGitBranch, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(branch) if res.status.is_success() => Ok((&branch).into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a branch by name.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/delete-branch
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/branches/feature
+    async fn delete_branch(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        let path = ATOMGIT_PATH_BRANCH
+            .replace("{repo}", repo)
+            .replace("{branch}", name);
+        self.delete(&path).await
+    }
+
+    /// Creates a tag pointing to the commit, the tag is annotated when a message is given.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/post-tag
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/tags
+    async fn create_tag(&self, repo: &str, input: &TagInput) -> Result<Reference, SCMError> {
+        let path = ATOMGIT_PATH_TAGS.replace("{repo}", repo);
+        let body = AtomGitTagInput {
+            tag_name: input.name.clone(),
+            refs: input.sha.clone(),
+            tag_message: input.message.clone(),
+        };
+        let res = self
+            .client
+            .post::<AtomGitTag, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(tag) if res.status.is_success() => Ok((&tag).into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a tag by name.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/delete-tag
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/tags/v1.0.0
+    async fn delete_tag(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        let path = ATOMGIT_PATH_TAG.replace("{repo}", repo).replace("{tag}", name);
+        self.delete(&path).await
+    }
+
     /// Returns the contents of a single commit reference.
     ///
     /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-ref-commit
@@ -118,6 +218,53 @@ impl GitService for AtomGitService {
         };
         Ok(Option::from(tree))
     }
+
+    /// Downloads a tarball or zipball archive of a repository at the reference.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-archive
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/tarball/master
+    async fn archive(
+        &self,
+        repo: &str,
+        reference: &str,
+        format: ArchiveFormat,
+        _path: Option<&str>,
+    ) -> Result<ByteStream, SCMError> {
+        let format = match format {
+            ArchiveFormat::TarGz => "tarball",
+            ArchiveFormat::Zip => "zipball",
+        };
+        let path = ATOMGIT_PATH_ARCHIVE
+            .replace("{repo}", repo)
+            .replace("{format}", format)
+            .replace("{reference}", reference);
+        let res = self
+            .client
+            .get_stream(&path, None, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        Ok(res.stream)
+    }
+}
+
+impl AtomGitService {
+    /// Deletes the resource at the path, e.g. a branch or a tag.
+    async fn delete(&self, path: &str) -> Result<(), SCMError> {
+        let res = self
+            .client
+            .delete::<Empty>(path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path.to_string(), res.status));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -201,6 +348,45 @@ impl Endpoint for Vec<AtomGitBranch> {
     type Output = Vec<AtomGitBranch>;
 }
 
+impl Endpoint for AtomGitBranch {
+    type Output = AtomGitBranch;
+}
+
+#[derive(Debug, Serialize)]
+pub struct AtomGitBranchInput {
+    pub branch_name: String,
+    pub refs: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitTag {
+    pub name: String,
+    pub message: Option<String>,
+    pub commit: AtomGitSimpleCommit,
+}
+
+impl From<&AtomGitTag> for Reference {
+    fn from(val: &AtomGitTag) -> Self {
+        Self {
+            name: utils::trim_ref(&val.name),
+            path: utils::expand_ref(&val.name, "refs/tags/"),
+            sha: val.commit.sha.clone(),
+        }
+    }
+}
+
+impl Endpoint for AtomGitTag {
+    type Output = AtomGitTag;
+}
+
+#[derive(Debug, Serialize)]
+pub struct AtomGitTagInput {
+    pub tag_name: String,
+    pub refs: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_message: Option<String>,
+}
+
 impl Endpoint for AtomGitCommit {
     type Output = AtomGitCommit;
 }
@@ -0,0 +1,328 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    ATOMGIT_PATH_ISSUE, ATOMGIT_PATH_ISSUES, ATOMGIT_PATH_ISSUE_COMMENT, ATOMGIT_PATH_ISSUE_COMMENTS,
+    ATOMGIT_PATH_PULL_COMMENT, ATOMGIT_PATH_PULL_COMMENTS,
+};
+use super::user::AtomGitUser;
+use super::utils::convert_list_options;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::issue::{Comment, CommentInput, Issue, IssueInput, IssueService, Thread};
+
+pub struct AtomGitIssueService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl IssueService for AtomGitIssueService {
+    /// Returns an issue by number.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-issue
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues/1347
+    async fn find(&self, repo: &str, number: u64) -> Result<Option<Issue>, SCMError> {
+        let path = ATOMGIT_PATH_ISSUE
+            .replace("{repo}", repo)
+            .replace("{number}", &number.to_string());
+        let res = self
+            .client
+            .get::<AtomGitIssue>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns a list of the open issues, pull requests listed as issues are filtered out.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-issue-list
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Issue>, SCMError> {
+        let path = ATOMGIT_PATH_ISSUES.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<AtomGitIssue>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(issues) = res.data {
+            return Ok(issues
+                .into_iter()
+                .filter(|v| v.pull_request.is_none())
+                .map(|v| v.into())
+                .collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates an issue.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/post-issue
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues
+    async fn create(&self, repo: &str, input: &IssueInput) -> Result<Issue, SCMError> {
+        let path = ATOMGIT_PATH_ISSUES.replace("{repo}", repo);
+        let body = AtomGitIssueInput {
+            title: input.title.clone(),
+            body: input.body.clone(),
+            labels: input.labels.clone(),
+        };
+        let res = self
+            .client
+            .post::<AtomGitIssue, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(issue) if res.status.is_success() => Ok(issue.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Closes an issue by number.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/patch-issue
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues/1347
+    async fn close(&self, repo: &str, number: u64) -> Result<(), SCMError> {
+        let path = ATOMGIT_PATH_ISSUE
+            .replace("{repo}", repo)
+            .replace("{number}", &number.to_string());
+        let body = AtomGitIssueState {
+            state: "closed".to_string(),
+        };
+        let res = self
+            .client
+            .patch::<Empty, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a list of comments on the issue or pull request.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-issue-comments
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues/1347/comments
+    async fn list_comments(
+        &self,
+        repo: &str,
+        thread: Thread,
+        opts: ListOptions,
+    ) -> Result<Vec<Comment>, SCMError> {
+        let path = comments_path(repo, thread);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<AtomGitComment>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(comments) = res.data {
+            return Ok(comments.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates a comment on the issue or pull request.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/post-issue-comment
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues/1347/comments
+    async fn create_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = comments_path(repo, thread);
+        let body = AtomGitCommentInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .post::<AtomGitComment, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(comment) if res.status.is_success() => Ok(comment.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Updates a comment on the issue or pull request.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/patch-issue-comment
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues/comments/1
+    async fn update_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        id: u64,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = comment_path(repo, thread, id);
+        let body = AtomGitCommentInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .patch::<AtomGitComment, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(comment) if res.status.is_success() => Ok(comment.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a comment on the issue or pull request.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/delete-issue-comment
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/issues/comments/1
+    async fn delete_comment(&self, repo: &str, thread: Thread, id: u64) -> Result<(), SCMError> {
+        let path = comment_path(repo, thread, id);
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the path of the comments, pull requests have their own comments API.
+fn comments_path(repo: &str, thread: Thread) -> String {
+    let path = match thread {
+        Thread::Issue(_) => ATOMGIT_PATH_ISSUE_COMMENTS,
+        Thread::PullRequest(_) => ATOMGIT_PATH_PULL_COMMENTS,
+    };
+    path.replace("{repo}", repo)
+        .replace("{number}", &thread.number().to_string())
+}
+
+/// Returns the path of a single comment, addressed by id only.
+fn comment_path(repo: &str, thread: Thread, id: u64) -> String {
+    let path = match thread {
+        Thread::Issue(_) => ATOMGIT_PATH_ISSUE_COMMENT,
+        Thread::PullRequest(_) => ATOMGIT_PATH_PULL_COMMENT,
+    };
+    path.replace("{repo}", repo).replace("{id}", &id.to_string())
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String,
+    pub labels: Vec<AtomGitLabel>,
+    pub user: AtomGitUser,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub pull_request: Option<serde_json::Value>,
+}
+
+impl From<AtomGitIssue> for Issue {
+    fn from(val: AtomGitIssue) -> Self {
+        Self {
+            number: val.number,
+            title: val.title,
+            body: val.body.unwrap_or_default(),
+            labels: val.labels.into_iter().map(|v| v.name).collect(),
+            closed: val.state == "closed",
+            author: val.user.into(),
+            link: val.html_url,
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for AtomGitIssue {
+    type Output = AtomGitIssue;
+}
+
+impl Endpoint for Vec<AtomGitIssue> {
+    type Output = Vec<AtomGitIssue>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AtomGitIssueInput {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AtomGitIssueState {
+    pub state: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitComment {
+    pub id: u64,
+    pub body: Option<String>,
+    pub user: AtomGitUser,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<AtomGitComment> for Comment {
+    fn from(val: AtomGitComment) -> Self {
+        Self {
+            id: val.id,
+            body: val.body.unwrap_or_default(),
+            author: val.user.into(),
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for AtomGitComment {
+    type Output = AtomGitComment;
+}
+
+impl Endpoint for Vec<AtomGitComment> {
+    type Output = Vec<AtomGitComment>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct AtomGitCommentInput {
+    pub body: String,
+}
@@ -0,0 +1,129 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{ATOMGIT_PATH_KEY, ATOMGIT_PATH_KEYS};
+use super::utils::convert_list_options;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::key::{DeployKey, DeployKeyInput, DeployKeyService};
+
+pub struct AtomGitDeployKeyService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl DeployKeyService for AtomGitDeployKeyService {
+    /// Returns a list of deploy keys.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-keys
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/keys
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<DeployKey>, SCMError> {
+        let path = ATOMGIT_PATH_KEYS.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<AtomGitDeployKey>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(keys) = res.data {
+            return Ok(keys.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates a deploy key.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/post-repo-keys
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/keys
+    async fn create(&self, repo: &str, input: &DeployKeyInput) -> Result<DeployKey, SCMError> {
+        let path = ATOMGIT_PATH_KEYS.replace("{repo}", repo);
+        let body = AtomGitDeployKeyInput {
+            title: input.title.clone(),
+            key: input.key.clone(),
+            read_only: input.read_only,
+        };
+        let res = self
+            .client
+            .post::<AtomGitDeployKey, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(key) if res.status.is_success() => Ok(key.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a deploy key by id.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/delete-repo-keys-id
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/keys/1
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError> {
+        let path = ATOMGIT_PATH_KEY.replace("{repo}", repo).replace("{id}", id);
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitDeployKey {
+    pub id: u64,
+    pub title: String,
+    pub key: String,
+    pub read_only: Option<bool>,
+    pub created_at: Option<String>,
+}
+
+impl From<AtomGitDeployKey> for DeployKey {
+    fn from(val: AtomGitDeployKey) -> Self {
+        Self {
+            id: val.id.to_string(),
+            title: val.title,
+            key: val.key,
+            read_only: val.read_only.unwrap_or_default(),
+            created: val.created_at.unwrap_or_default(),
+        }
+    }
+}
+
+impl Endpoint for AtomGitDeployKey {
+    type Output = AtomGitDeployKey;
+}
+
+impl Endpoint for Vec<AtomGitDeployKey> {
+    type Output = Vec<AtomGitDeployKey>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct AtomGitDeployKeyInput {
+    pub title: String,
+    pub key: String,
+    pub read_only: bool,
+}
@@ -16,14 +16,21 @@ pub mod constants;
 pub mod content;
 pub mod driver;
 pub mod git;
+pub mod issue;
+pub mod key;
+pub mod org;
 pub mod pr;
+pub mod release;
 pub mod repo;
+pub mod search;
+pub mod user;
 mod utils;
 
-use self::constants::ATOMGIT_ENDPOINT;
+use self::constants::{ATOMGIT_API_HOST, ATOMGIT_ENDPOINT, ATOMGIT_TRUSTED_HOSTS};
 use self::pr::AtomGitFile;
 use super::Driver;
-use crate::http::Client;
+use crate::config::Credential;
+use crate::http::{Auth, Client};
 use crate::scm::driver::atomgit::driver::AtomGitDriver;
 use crate::scm::errors::SCMError;
 
@@ -36,11 +43,31 @@ pub fn default() -> Result<Driver, SCMError> {
 /// Returns a new AtomGit driver.
 #[inline]
 pub fn new(url: &str, token: Option<String>) -> Result<Driver, SCMError> {
-    from(Client::new(url, token).map_err(SCMError::ClientError)?)
+    from(Client::with_auth(url, token.map(Auth::Bearer).unwrap_or_default()).map_err(SCMError::ClientError)?)
 }
 
-/// Returns a new AtomGit driver using the given client.
+/// Returns a new AtomGit driver authenticated with the credential.
+pub fn with_credential(url: &str, credential: &impl Credential) -> Result<Driver, SCMError> {
+    from(Client::with_auth(url, auth(credential)).map_err(SCMError::ClientError)?)
+}
+
+/// Returns the authentication of the credential, AtomGit accepts access tokens
+/// as bearer tokens.
+pub fn auth(credential: &impl Credential) -> Auth {
+    super::auth(credential, Auth::Bearer)
+}
+
+/// Returns a new AtomGit driver using the given client, the credentials of a
+/// client for api.atomgit.com are also sent to the other hosts of atomgit.com
+/// the API links to.
 pub fn from(client: Client) -> Result<Driver, SCMError> {
+    let client = match client
+        .url("")
+        .is_ok_and(|url| url.host_str() == Some(ATOMGIT_API_HOST))
+    {
+        true => client.with_trusted_hosts(ATOMGIT_TRUSTED_HOSTS),
+        false => client,
+    };
     Ok(Driver::AtomGit(AtomGitDriver { client }))
 }
 
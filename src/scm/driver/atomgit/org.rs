@@ -0,0 +1,173 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{ATOMGIT_PATH_ORGS, ATOMGIT_PATH_ORG_MEMBERS, ATOMGIT_PATH_USER_ORGS};
+use super::utils::convert_list_options;
+use crate::http::{endpoint::Endpoint, Client};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::org::{Member, Organization, OrganizationService, Role};
+
+/// The maximum page size of the AtomGit API.
+const ATOMGIT_MAX_PAGE_SIZE: i32 = 100;
+
+pub struct AtomGitOrganizationService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl OrganizationService for AtomGitOrganizationService {
+    /// Returns the organizations of the authenticated user.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-user-orgs
+    /// Example: https://api.atomgit.com/user/orgs
+    async fn list(&self, opts: ListOptions) -> Result<Vec<Organization>, SCMError> {
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<AtomGitOrganization>>(ATOMGIT_PATH_USER_ORGS, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(orgs) = res.data {
+            return Ok(orgs.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Returns an organization by name.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-org
+    /// Example: https://api.atomgit.com/orgs/openharmony
+    async fn find(&self, name: &str) -> Result<Option<Organization>, SCMError> {
+        let path = ATOMGIT_PATH_ORGS.replace("{org}", name);
+        let res = self
+            .client
+            .get::<AtomGitOrganization>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the members of an organization, the members API does not return
+    /// the role, so the admins are listed separately to tell them apart.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-org-members
+    /// Example: https://api.atomgit.com/orgs/openharmony/members
+    async fn list_members(&self, name: &str, opts: ListOptions) -> Result<Vec<Member>, SCMError> {
+        let path = ATOMGIT_PATH_ORG_MEMBERS.replace("{org}", name);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<AtomGitMember>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        let Some(members) = res.data else {
+            return Ok(vec![]);
+        };
+        let admins = self.list_admins(&path).await?;
+
+        Ok(members
+            .into_iter()
+            .map(|v| {
+                let role = match admins.contains(&v.login) {
+                    true => Role::Admin,
+                    false => Role::Member,
+                };
+                Member {
+                    login: v.login,
+                    avatar: v.avatar_url.unwrap_or_default(),
+                    role,
+                }
+            })
+            .collect())
+    }
+}
+
+impl AtomGitOrganizationService {
+    /// Returns the logins of every admin of the organization.
+    async fn list_admins(&self, path: &str) -> Result<HashSet<String>, SCMError> {
+        let mut admins = HashSet::new();
+        let mut page = 1;
+
+        loop {
+            let mut options = convert_list_options(ListOptions {
+                page,
+                size: ATOMGIT_MAX_PAGE_SIZE,
+                ..Default::default()
+            });
+            options.insert("role".to_string(), "admin".to_string());
+            let res = self
+                .client
+                .get::<Vec<AtomGitMember>>(path, Some(options))
+                .await
+                .map_err(SCMError::ClientError)?;
+
+            let members = res.data.unwrap_or_default();
+            let count = members.len();
+            admins.extend(members.into_iter().map(|v| v.login));
+            if count < ATOMGIT_MAX_PAGE_SIZE as usize {
+                return Ok(admins);
+            }
+            page += 1;
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitOrganization {
+    pub id: u64,
+    pub login: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub html_url: Option<String>,
+}
+
+impl From<AtomGitOrganization> for Organization {
+    fn from(val: AtomGitOrganization) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val.name.unwrap_or_else(|| val.login.clone()),
+            link: val.html_url.unwrap_or_default(),
+            login: val.login,
+            avatar: val.avatar_url.unwrap_or_default(),
+        }
+    }
+}
+
+impl Endpoint for AtomGitOrganization {
+    type Output = AtomGitOrganization;
+}
+
+impl Endpoint for Vec<AtomGitOrganization> {
+    type Output = Vec<AtomGitOrganization>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitMember {
+    pub login: String,
+    pub avatar_url: Option<String>,
+}
+
+impl Endpoint for Vec<AtomGitMember> {
+    type Output = Vec<AtomGitMember>;
+}
@@ -0,0 +1,204 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    ATOMGIT_PATH_RELEASE, ATOMGIT_PATH_RELEASES, ATOMGIT_PATH_RELEASE_BY_TAG, ATOMGIT_PATH_RELEASE_LATEST,
+};
+use super::utils::convert_list_options;
+use crate::http::endpoint::Endpoint;
+use crate::http::{Client, Payload};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::release::{Asset, Release, ReleaseInput, ReleaseService};
+
+pub struct AtomGitReleaseService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl ReleaseService for AtomGitReleaseService {
+    /// Returns a list of releases.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-releases
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/releases
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Release>, SCMError> {
+        let path = ATOMGIT_PATH_RELEASES.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<AtomGitRelease>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(releases) = res.data {
+            return Ok(releases.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Finds a release by tag.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-releases-tags-tag
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/releases/tags/v1.0.0
+    async fn find_by_tag(&self, repo: &str, tag: &str) -> Result<Option<Release>, SCMError> {
+        let path = ATOMGIT_PATH_RELEASE_BY_TAG
+            .replace("{repo}", repo)
+            .replace("{tag}", tag);
+        let res = self
+            .client
+            .get::<AtomGitRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the latest release.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-releases-latest
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/releases/latest
+    async fn latest(&self, repo: &str) -> Result<Option<Release>, SCMError> {
+        let path = ATOMGIT_PATH_RELEASE_LATEST.replace("{repo}", repo);
+        let res = self
+            .client
+            .get::<AtomGitRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Creates a release, the tag is created from the target if it does not exist.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/post-repo-releases
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/releases
+    async fn create(&self, repo: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        let path = ATOMGIT_PATH_RELEASES.replace("{repo}", repo);
+        let res = self
+            .client
+            .post::<AtomGitRelease, _>(&path, &AtomGitReleaseInput::from(input))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Updates a release by id.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/patch-repo-releases-id
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/releases/1
+    async fn update(&self, repo: &str, id: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        let path = ATOMGIT_PATH_RELEASE.replace("{repo}", repo).replace("{id}", id);
+        let res = self
+            .client
+            .patch::<AtomGitRelease, _>(&path, &AtomGitReleaseInput::from(input))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// AtomGit does not provide an API to delete releases.
+    async fn delete(&self, _repo: &str, _id: &str) -> Result<(), SCMError> {
+        Err(SCMError::Unsupported("AtomGit release deletion".to_string()))
+    }
+
+    /// AtomGit does not provide an API for release assets.
+    async fn list_assets(&self, _repo: &str, _id: &str) -> Result<Vec<Asset>, SCMError> {
+        Err(SCMError::Unsupported("AtomGit release assets".to_string()))
+    }
+
+    /// AtomGit does not provide an API for release assets.
+    async fn upload_asset(
+        &self,
+        _repo: &str,
+        _id: &str,
+        _name: &str,
+        _content_type: &str,
+        _data: Payload,
+    ) -> Result<Asset, SCMError> {
+        Err(SCMError::Unsupported("AtomGit release assets".to_string()))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitRelease {
+    pub id: u64,
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub html_url: String,
+    pub created_at: String,
+    pub published_at: Option<String>,
+}
+
+impl From<AtomGitRelease> for Release {
+    fn from(val: AtomGitRelease) -> Self {
+        Self {
+            id: val.id.to_string(),
+            tag: val.tag_name,
+            title: val.name.unwrap_or_default(),
+            description: val.body.unwrap_or_default(),
+            draft: val.draft,
+            prerelease: val.prerelease,
+            link: val.html_url,
+            created: val.created_at,
+            published: val.published_at,
+        }
+    }
+}
+
+impl Endpoint for AtomGitRelease {
+    type Output = AtomGitRelease;
+}
+
+impl Endpoint for Vec<AtomGitRelease> {
+    type Output = Vec<AtomGitRelease>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct AtomGitReleaseInput {
+    pub tag_name: String,
+    pub name: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+impl From<&ReleaseInput> for AtomGitReleaseInput {
+    fn from(val: &ReleaseInput) -> Self {
+        Self {
+            tag_name: val.tag.clone(),
+            name: val.title.clone(),
+            body: val.description.clone(),
+            target_commitish: val.target.clone(),
+            draft: val.draft,
+            prerelease: val.prerelease,
+        }
+    }
+}
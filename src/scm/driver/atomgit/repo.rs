@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::http::{endpoint::Endpoint, Client};
 use crate::scm::errors::SCMError;
-use crate::scm::repo::{Repository, RepositoryService};
+use crate::scm::repo::{Permission, Repository, RepositoryService};
+use crate::scm::utils;
 
-use super::constants::ATOMGIT_PATH_REPOS;
+use super::constants::{ATOMGIT_PATH_COLLABORATOR_PERMISSION, ATOMGIT_PATH_LANGUAGES, ATOMGIT_PATH_REPOS};
 
 pub struct AtomGitRepoService {
     pub client: Client,
@@ -41,6 +45,46 @@ impl RepositoryService for AtomGitRepoService {
 
         Ok(res.data.map(|v| v.into()))
     }
+
+    /// Returns the permission of a user.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-collaborators-username-permission
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/collaborators/jia-hao-li/permission
+    async fn permission(&self, repo: &str, user: &str) -> Result<Permission, SCMError> {
+        let path = ATOMGIT_PATH_COLLABORATOR_PERMISSION
+            .replace("{repo}", repo)
+            .replace("{user}", user);
+        let res = self
+            .client
+            .get::<AtomGitPermission>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if res.status == StatusCode::NOT_FOUND {
+            return Ok(Permission::None);
+        }
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(res.data.map(|v| v.into()).unwrap_or_default())
+    }
+
+    /// Returns the languages of a repository, as percentages of the code.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-repo-languages
+    /// Example: https://api.atomgit.com/repos/jia-hao-li/atomgit_evaluation/languages
+    async fn languages(&self, repo: &str) -> Result<HashMap<String, f64>, SCMError> {
+        let path = ATOMGIT_PATH_LANGUAGES.replace("{repo}", repo);
+        let res = self
+            .client
+            .get::<AtomGitLanguages>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+        utils::check_status(path, res.status)?;
+
+        Ok(utils::percentages(res.data.unwrap_or_default()))
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -88,3 +132,30 @@ impl From<AtomGitRepository> for Repository {
 impl Endpoint for AtomGitRepository {
     type Output = AtomGitRepository;
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitPermission {
+    pub permission: String,
+}
+
+impl From<AtomGitPermission> for Permission {
+    fn from(val: AtomGitPermission) -> Self {
+        match val.permission.as_str() {
+            "admin" => Permission::Admin,
+            "write" | "push" => Permission::Write,
+            "read" | "pull" => Permission::Read,
+            _ => Permission::None,
+        }
+    }
+}
+
+impl Endpoint for AtomGitPermission {
+    type Output = AtomGitPermission;
+}
+
+/// The bytes of code of each language of a repository, keyed by the language name.
+pub struct AtomGitLanguages;
+
+impl Endpoint for AtomGitLanguages {
+    type Output = HashMap<String, u64>;
+}
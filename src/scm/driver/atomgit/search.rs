@@ -0,0 +1,46 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::repo::Repository;
+use crate::scm::search::{CodeResult, SearchCapabilities, SearchOptions, SearchService};
+
+pub struct AtomGitSearchService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl SearchService for AtomGitSearchService {
+    fn capabilities(&self) -> SearchCapabilities {
+        SearchCapabilities::default()
+    }
+
+    /// AtomGit does not provide an API to search code.
+    async fn code(&self, _opts: &SearchOptions, _list: ListOptions) -> Result<Vec<CodeResult>, SCMError> {
+        Err(SCMError::Unsupported("AtomGit code search".to_string()))
+    }
+
+    /// AtomGit does not provide an API to search repositories.
+    async fn repositories(
+        &self,
+        _opts: &SearchOptions,
+        _list: ListOptions,
+    ) -> Result<Vec<Repository>, SCMError> {
+        Err(SCMError::Unsupported("AtomGit repository search".to_string()))
+    }
+}
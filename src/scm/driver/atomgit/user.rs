@@ -0,0 +1,129 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{ATOMGIT_PATH_USER, ATOMGIT_PATH_USERS, ATOMGIT_PATH_USER_EMAILS};
+use super::utils::convert_list_options;
+use crate::http::{endpoint::Endpoint, Client};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::user::{Email, User, UserService};
+
+pub struct AtomGitUserService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl UserService for AtomGitUserService {
+    /// Returns the authenticated user.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-user
+    /// Example: https://api.atomgit.com/user
+    async fn find(&self) -> Result<Option<User>, SCMError> {
+        let res = self
+            .client
+            .get::<AtomGitUser>(ATOMGIT_PATH_USER, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns a user by login.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-users
+    /// Example: https://api.atomgit.com/users/jia-hao-li
+    async fn find_login(&self, login: &str) -> Result<Option<User>, SCMError> {
+        let path = ATOMGIT_PATH_USERS.replace("{login}", login);
+        let res = self
+            .client
+            .get::<AtomGitUser>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the email addresses of the authenticated user.
+    ///
+    /// Docs: https://docs.atomgit.com/en/openAPI/api_versioned/get-user-emails
+    /// Example: https://api.atomgit.com/user/emails
+    async fn list_emails(&self, opts: ListOptions) -> Result<Vec<Email>, SCMError> {
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<AtomGitEmail>>(ATOMGIT_PATH_USER_EMAILS, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(emails) = res.data {
+            return Ok(emails.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitUser {
+    pub id: u64,
+    pub login: String,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub avatar_url: Option<String>,
+    pub html_url: Option<String>,
+}
+
+impl From<AtomGitUser> for User {
+    fn from(val: AtomGitUser) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val.name.unwrap_or_else(|| val.login.clone()),
+            login: val.login,
+            email: val.email.filter(|v| !v.is_empty()),
+            avatar: val.avatar_url.unwrap_or_default(),
+            link: val.html_url.unwrap_or_default(),
+        }
+    }
+}
+
+impl Endpoint for AtomGitUser {
+    type Output = AtomGitUser;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AtomGitEmail {
+    pub email: String,
+    pub primary: Option<bool>,
+    pub verified: Option<bool>,
+    pub state: Option<String>,
+}
+
+impl From<AtomGitEmail> for Email {
+    fn from(val: AtomGitEmail) -> Self {
+        Self {
+            value: val.email,
+            primary: val.primary.unwrap_or_default(),
+            verified: val
+                .verified
+                .unwrap_or_else(|| val.state.as_deref() == Some("confirmed")),
+        }
+    }
+}
+
+impl Endpoint for Vec<AtomGitEmail> {
+    type Output = Vec<AtomGitEmail>;
+}
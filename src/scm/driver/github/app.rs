@@ -0,0 +1,254 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use jiff::{SignedDuration, Timestamp};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, OnceCell};
+
+use super::constants::{
+    GITHUB_PATH_APP_ACCESS_TOKENS, GITHUB_PATH_ORG_INSTALLATION, GITHUB_PATH_REPO_INSTALLATION,
+};
+use crate::config::AppCredential;
+use crate::http::endpoint::Endpoint;
+use crate::http::{Auth, Client, HTTPError, TokenSource};
+
+/// The lifetime of the app JWT, GitHub accepts at most 10 minutes.
+const JWT_LIFETIME: u64 = 9 * 60;
+
+/// Installation tokens live for an hour, they are refreshed a bit before they
+/// expire so that a token never expires in the middle of a request.
+const TOKEN_REFRESH_MARGIN: SignedDuration = SignedDuration::from_mins(5);
+
+/// Specifies which installation of the app the tokens are minted for.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Installation {
+    Id(u64),
+    /// The installation on the repository, e.g. `octocat/Hello-World`.
+    Repository(String),
+    /// The installation on the organization.
+    Organization(String),
+}
+
+/// Authenticates as a GitHub App installation, minting installation access
+/// tokens from the private key of the app and caching them until they expire.
+///
+/// Docs: https://docs.github.com/en/apps/creating-github-apps/authenticating-with-a-github-app/authenticating-as-a-github-app-installation
+pub struct GithubApp {
+    client: Client,
+    app_id: String,
+    key: EncodingKey,
+    installation: Installation,
+    installation_id: OnceCell<u64>,
+    token: Mutex<Option<InstallationToken>>,
+}
+
+impl GithubApp {
+    /// Creates a new GitHub App token source, `endpoint` is the API address,
+    /// e.g. `https://api.github.com`. The tokens are minted with a default
+    /// client, see `client` to use the one of the driver.
+    pub fn new(
+        endpoint: &str,
+        app_id: &str,
+        private_key: &str,
+        installation: Installation,
+    ) -> Result<Self, HTTPError> {
+        let key = EncodingKey::from_rsa_pem(private_key.as_bytes())
+            .map_err(|e| HTTPError::Authentication(format!("invalid private key of app {app_id}: {e}")))?;
+
+        Ok(Self {
+            client: Client::new(endpoint, None)?,
+            app_id: app_id.to_string(),
+            key,
+            installation,
+            installation_id: OnceCell::new(),
+            token: Mutex::new(None),
+        })
+    }
+
+    /// Creates a new GitHub App token source from the credential, the installation
+    /// is chosen by id, then repository, then organization.
+    pub fn from_credential(endpoint: &str, credential: &AppCredential) -> Result<Self, HTTPError> {
+        let installation = match (
+            &credential.installation_id,
+            &credential.repository,
+            &credential.organization,
+        ) {
+            (Some(id), _, _) => Installation::Id(*id),
+            (None, Some(repo), _) => Installation::Repository(repo.clone()),
+            (None, None, Some(org)) => Installation::Organization(org.clone()),
+            (None, None, None) => {
+                return Err(HTTPError::Authentication(format!(
+                    "no installation configured for app {}",
+                    credential.app_id
+                )))
+            }
+        };
+
+        Self::new(
+            endpoint,
+            &credential.app_id,
+            &credential.private_key,
+            installation,
+        )
+    }
+
+    /// Mints the tokens with the client, e.g. the one of the driver, so that its
+    /// proxy, root certificates, identity, transport and middleware also apply
+    /// to these requests. The credentials of the client are not sent.
+    pub fn client(mut self, client: &Client) -> Self {
+        self.client = client.without_auth();
+        self
+    }
+
+    /// Returns a JWT signed with the private key, used to authenticate as the app.
+    pub fn jwt(&self) -> Result<String, HTTPError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| HTTPError::Authentication(e.to_string()))?
+            .as_secs();
+        // issued a minute in the past to allow for clock drift.
+        let claims = Claims {
+            iat: now - 60,
+            exp: now + JWT_LIFETIME,
+            iss: self.app_id.clone(),
+        };
+
+        jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.key)
+            .map_err(|e| HTTPError::Authentication(e.to_string()))
+    }
+
+    /// Mints a new installation access token.
+    ///
+    /// Docs: https://docs.github.com/en/rest/apps/apps?apiVersion=2022-11-28#create-an-installation-access-token-for-an-app
+    pub async fn create_token(&self) -> Result<InstallationToken, HTTPError> {
+        let client = self.client.clone().with_authentication(Auth::Bearer(self.jwt()?));
+        let id = self.installation_id(&client).await?;
+
+        let path = GITHUB_PATH_APP_ACCESS_TOKENS.replace("{id}", &id.to_string());
+        let res = client.post::<GithubInstallationToken, _>(&path, &()).await?;
+        match res.data {
+            Some(token) if res.status.is_success() => token.try_into(),
+            _ => Err(HTTPError::Authentication(format!(
+                "failed to create the installation token of app {}: {}",
+                self.app_id, res.status
+            ))),
+        }
+    }
+
+    /// Returns the id of the installation, looked up once.
+    ///
+    /// Docs: https://docs.github.com/en/rest/apps/apps?apiVersion=2022-11-28#get-a-repository-installation-for-the-authenticated-app
+    async fn installation_id(&self, client: &Client) -> Result<u64, HTTPError> {
+        self.installation_id
+            .get_or_try_init(|| async {
+                let path = match &self.installation {
+                    Installation::Id(id) => return Ok(*id),
+                    Installation::Repository(repo) => GITHUB_PATH_REPO_INSTALLATION.replace("{repo}", repo),
+                    Installation::Organization(org) => GITHUB_PATH_ORG_INSTALLATION.replace("{org}", org),
+                };
+                let res = client.get::<GithubInstallation>(&path, None).await?;
+                res.data.map(|v| v.id).ok_or_else(|| {
+                    HTTPError::Authentication(format!("app {} is not installed on {path}", self.app_id))
+                })
+            })
+            .await
+            .copied()
+    }
+}
+
+#[async_trait]
+impl TokenSource for GithubApp {
+    /// Returns the cached installation token, or mints a new one if it is
+    /// missing or about to expire.
+    async fn token(&self) -> Result<String, HTTPError> {
+        let mut cached = self.token.lock().await;
+        if let Some(token) = cached.as_ref().filter(|t| !t.expires_soon()) {
+            return Ok(token.token.clone());
+        }
+
+        let token = self.create_token().await?;
+        let value = token.token.clone();
+        *cached = Some(token);
+
+        Ok(value)
+    }
+
+    /// Drops the cached installation token if GitHub rejected it, e.g. because
+    /// it was revoked or the app was uninstalled.
+    async fn invalidate(&self, token: &str) {
+        let mut cached = self.token.lock().await;
+        if cached.as_ref().is_some_and(|t| t.token == token) {
+            *cached = None;
+        }
+    }
+}
+
+/// Represents an installation access token.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstallationToken {
+    pub token: String,
+    pub expires_at: Timestamp,
+}
+
+impl InstallationToken {
+    /// Returns true if the token expires within the refresh margin.
+    pub fn expires_soon(&self) -> bool {
+        self.expires_at - TOKEN_REFRESH_MARGIN <= Timestamp::now()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Claims {
+    iat: u64,
+    exp: u64,
+    iss: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubInstallation {
+    pub id: u64,
+}
+
+impl Endpoint for GithubInstallation {
+    type Output = GithubInstallation;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubInstallationToken {
+    pub token: String,
+    pub expires_at: String,
+}
+
+impl TryFrom<GithubInstallationToken> for InstallationToken {
+    type Error = HTTPError;
+
+    fn try_from(val: GithubInstallationToken) -> Result<Self, Self::Error> {
+        let expires_at = val.expires_at.parse().map_err(|e| {
+            HTTPError::Authentication(format!("invalid token expiry {}: {e}", val.expires_at))
+        })?;
+
+        Ok(Self {
+            token: val.token,
+            expires_at,
+        })
+    }
+}
+
+impl Endpoint for GithubInstallationToken {
+    type Output = GithubInstallationToken;
+}
@@ -14,10 +14,53 @@
 
 pub const GITHUB_ENDPOINT: &str = "https://api.github.com";
 
+// The hosts of github.com, besides the API, that are sent the credentials
+// (uploads of release assets and the Git LFS batch API)
+pub const GITHUB_API_HOST: &str = "api.github.com";
+pub const GITHUB_TRUSTED_HOSTS: [&str; 2] = ["uploads.github.com", "github.com"];
+
+// The token endpoint of OAuth apps and the user access tokens of GitHub Apps
+pub const GITHUB_OAUTH_TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
+
+// Media type for the raw file contents, supports files up to 100 MB
+pub const GITHUB_MEDIA_TYPE_RAW: &str = "application/vnd.github.raw+json";
+
 // REST API paths
 pub const GITHUB_PATH_CONTENTS: &str = "/repos/{repo}/contents/{file}";
 pub const GITHUB_PATH_BRANCHES: &str = "/repos/{repo}/branches";
+pub const GITHUB_PATH_BRANCH: &str = "/repos/{repo}/branches/{branch}";
 pub const GITHUB_PATH_TAGS: &str = "/repos/{repo}/tags";
 pub const GITHUB_PATH_COMMITS: &str = "/repos/{repo}/commits/{reference}";
 pub const GITHUB_PATH_REPOS: &str = "/repos/{repo}";
 pub const GITHUB_PATH_GIT_TREES: &str = "/repos/{repo}/git/trees/{tree_sha}";
+pub const GITHUB_PATH_ARCHIVE: &str = "/repos/{repo}/{format}/{reference}";
+pub const GITHUB_PATH_GIT_BLOBS: &str = "/repos/{repo}/git/blobs/{sha}";
+pub const GITHUB_PATH_GIT_REF: &str = "/repos/{repo}/git/ref/{ref}";
+pub const GITHUB_PATH_GIT_REFS: &str = "/repos/{repo}/git/refs";
+pub const GITHUB_PATH_GIT_REFS_DELETE: &str = "/repos/{repo}/git/refs/{ref}";
+pub const GITHUB_PATH_GIT_TAGS: &str = "/repos/{repo}/git/tags";
+pub const GITHUB_PATH_GIT_TAG: &str = "/repos/{repo}/git/tags/{sha}";
+pub const GITHUB_PATH_USER: &str = "/user";
+pub const GITHUB_PATH_USERS: &str = "/users/{login}";
+pub const GITHUB_PATH_USER_EMAILS: &str = "/user/emails";
+pub const GITHUB_PATH_USER_ORGS: &str = "/user/orgs";
+pub const GITHUB_PATH_ORGS: &str = "/orgs/{org}";
+pub const GITHUB_PATH_ORG_MEMBERS: &str = "/orgs/{org}/members";
+pub const GITHUB_PATH_ISSUES: &str = "/repos/{repo}/issues";
+pub const GITHUB_PATH_ISSUE: &str = "/repos/{repo}/issues/{number}";
+pub const GITHUB_PATH_ISSUE_COMMENTS: &str = "/repos/{repo}/issues/{number}/comments";
+pub const GITHUB_PATH_ISSUE_COMMENT: &str = "/repos/{repo}/issues/comments/{id}";
+pub const GITHUB_PATH_RELEASES: &str = "/repos/{repo}/releases";
+pub const GITHUB_PATH_RELEASE: &str = "/repos/{repo}/releases/{id}";
+pub const GITHUB_PATH_RELEASE_BY_TAG: &str = "/repos/{repo}/releases/tags/{tag}";
+pub const GITHUB_PATH_RELEASE_LATEST: &str = "/repos/{repo}/releases/latest";
+pub const GITHUB_PATH_RELEASE_ASSETS: &str = "/repos/{repo}/releases/{id}/assets";
+pub const GITHUB_PATH_KEYS: &str = "/repos/{repo}/keys";
+pub const GITHUB_PATH_KEY: &str = "/repos/{repo}/keys/{id}";
+pub const GITHUB_PATH_COLLABORATOR_PERMISSION: &str = "/repos/{repo}/collaborators/{user}/permission";
+pub const GITHUB_PATH_LANGUAGES: &str = "/repos/{repo}/languages";
+pub const GITHUB_PATH_SEARCH_CODE: &str = "/search/code";
+pub const GITHUB_PATH_SEARCH_REPOSITORIES: &str = "/search/repositories";
+pub const GITHUB_PATH_APP_ACCESS_TOKENS: &str = "/app/installations/{id}/access_tokens";
+pub const GITHUB_PATH_REPO_INSTALLATION: &str = "/repos/{repo}/installation";
+pub const GITHUB_PATH_ORG_INSTALLATION: &str = "/orgs/{org}/installation";
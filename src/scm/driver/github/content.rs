@@ -16,12 +16,16 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use data_encoding::BASE64_MIME as BASE64;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT};
 use serde::{Deserialize, Serialize};
 
-use super::constants::GITHUB_PATH_CONTENTS;
-use crate::http::{endpoint::Endpoint, Client};
+use super::constants::{GITHUB_MEDIA_TYPE_RAW, GITHUB_PATH_CONTENTS, GITHUB_PATH_GIT_BLOBS};
+use super::repo::GithubRepoService;
+use crate::http::{endpoint::Endpoint, ByteStream, Client};
 use crate::scm::content::{Content, ContentService, File};
 use crate::scm::errors::SCMError;
+use crate::scm::lfs;
+use crate::scm::utils;
 
 pub struct GithubContentService {
     pub client: Client,
@@ -44,13 +48,41 @@ impl ContentService for GithubContentService {
             .await
             .map_err(SCMError::ClientError)?;
 
-        if let Some(content) = res.data {
-            Ok(content.try_into().map_err(SCMError::DecodeError)?)
-        } else {
-            Err(SCMError::NotFound(path))
+        match res.data {
+            // files between 1 MB and 100 MB are returned without content,
+            // which is fetched through the blob API instead.
+            Some(content) if content.encoding.as_deref() == Some("none") => {
+                self.find_blob(repo, content).await
+            }
+            Some(content) => Ok(content.try_into().map_err(SCMError::DecodeError)?),
+            None => Err(SCMError::NotFound(path)),
         }
     }
 
+    /// Gets the raw contents of a file in a repository, up to 100 MB.
+    ///
+    /// Docs: https://docs.github.com/en/rest/repos/contents?apiVersion=2022-11-28#get-repository-content
+    /// Example: https://api.github.com/repos/octocat/Hello-World/contents/README
+    async fn raw(&self, repo: &str, file: &str, reference: &str) -> Result<ByteStream, SCMError> {
+        let path = GITHUB_PATH_CONTENTS
+            .replace("{repo}", repo)
+            .replace("{file}", file);
+        let options = HashMap::from([("ref".to_string(), reference.to_string())]);
+        let headers = HeaderMap::from_iter([(ACCEPT, HeaderValue::from_static(GITHUB_MEDIA_TYPE_RAW))]);
+        let res = self
+            .client
+            .get_stream(&path, Some(options), Some(headers))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        let repositories = GithubRepoService {
+            client: self.client.clone(),
+        };
+        lfs::resolve(&self.client, res.stream, &repositories, repo).await
+    }
+
     /// Gets the file list of a directory in a repository.
     ///
     /// Docs: https://docs.github.com/en/rest/repos/contents?apiVersion=2022-11-28#get-repository-content
@@ -74,12 +106,44 @@ impl ContentService for GithubContentService {
     }
 }
 
+impl GithubContentService {
+    /// Gets the contents of a file through the blob API, which supports files up to 100 MB.
+    ///
+    /// Docs: https://docs.github.com/en/rest/git/blobs?apiVersion=2022-11-28#get-a-blob
+    /// Example: https://api.github.com/repos/octocat/Hello-World/git/blobs/980a0d5f19a64b4b30a87d4206aade58726b60e3
+    async fn find_blob(&self, repo: &str, content: GithubContent) -> Result<Content, SCMError> {
+        let path = GITHUB_PATH_GIT_BLOBS
+            .replace("{repo}", repo)
+            .replace("{sha}", &content.sha);
+        let res = self
+            .client
+            .get::<GithubBlob>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+        utils::check_status(path.clone(), res.status)?;
+
+        if let Some(blob) = res.data {
+            Ok(Content {
+                path: content.path,
+                data: BASE64
+                    .decode(blob.content.as_bytes())
+                    .map_err(SCMError::DecodeError)?,
+                sha: blob.sha.clone(),
+                blob_id: blob.sha,
+            })
+        } else {
+            Err(SCMError::NotFound(path))
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GithubContent {
     pub name: String,
     pub path: String,
     pub sha: String,
     pub content: String,
+    pub encoding: Option<String>,
     #[serde(rename = "type")]
     pub kind: String,
 }
@@ -102,6 +166,18 @@ impl Endpoint for GithubContent {
     type Output = GithubContent;
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubBlob {
+    pub sha: String,
+    pub size: u64,
+    pub content: String,
+    pub encoding: String,
+}
+
+impl Endpoint for GithubBlob {
+    type Output = GithubBlob;
+}
+
 /// represents a file in a repository.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GithubFile {
@@ -14,12 +14,25 @@
 
 use super::content::GithubContentService;
 use super::git::GithubGitService;
+use super::issue::GithubIssueService;
+use super::key::GithubDeployKeyService;
+use super::org::GithubOrganizationService;
+use super::release::GithubReleaseService;
 use super::repo::GithubRepoService;
+use super::search::GithubSearchService;
+use super::user::GithubUserService;
 use crate::http::Client;
+use crate::scm::capabilities::Capabilities;
 use crate::scm::content::ContentService;
 use crate::scm::driver::DriverTrait;
 use crate::scm::git::GitService;
+use crate::scm::issue::IssueService;
+use crate::scm::key::DeployKeyService;
+use crate::scm::org::OrganizationService;
+use crate::scm::release::ReleaseService;
 use crate::scm::repo::RepositoryService;
+use crate::scm::search::SearchService;
+use crate::scm::user::UserService;
 
 pub struct GithubDriver {
     pub client: Client,
@@ -43,6 +56,61 @@ impl DriverTrait for GithubDriver {
             client: self.client.clone(),
         })
     }
+
+    fn users(&self) -> Box<dyn UserService> {
+        Box::new(GithubUserService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn organizations(&self) -> Box<dyn OrganizationService> {
+        Box::new(GithubOrganizationService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn issues(&self) -> Box<dyn IssueService> {
+        Box::new(GithubIssueService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn releases(&self) -> Box<dyn ReleaseService> {
+        Box::new(GithubReleaseService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn deploy_keys(&self) -> Box<dyn DeployKeyService> {
+        Box::new(GithubDeployKeyService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn search(&self) -> Box<dyn SearchService> {
+        Box::new(GithubSearchService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            contents: true,
+            git: true,
+            git_write: true,
+            repositories: true,
+            users: true,
+            organizations: true,
+            issues: true,
+            releases: true,
+            release_assets: true,
+            release_delete: true,
+            deploy_keys: true,
+            search: self.search().capabilities(),
+            recursive_tree: true,
+            archive_path: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -16,13 +16,19 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::constants::{GITHUB_PATH_BRANCHES, GITHUB_PATH_COMMITS, GITHUB_PATH_GIT_TREES, GITHUB_PATH_TAGS};
+use super::constants::{
+    GITHUB_PATH_ARCHIVE, GITHUB_PATH_BRANCH, GITHUB_PATH_BRANCHES, GITHUB_PATH_COMMITS, GITHUB_PATH_GIT_REF,
+    GITHUB_PATH_GIT_REFS, GITHUB_PATH_GIT_REFS_DELETE, GITHUB_PATH_GIT_TAG, GITHUB_PATH_GIT_TAGS,
+    GITHUB_PATH_GIT_TREES, GITHUB_PATH_TAGS,
+};
 use super::utils::convert_list_options;
 use super::GithubFile;
-use crate::http::{endpoint::Endpoint, Client};
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{ByteStream, Client};
+use crate::scm::archive::ArchiveFormat;
 use crate::scm::client::ListOptions;
 use crate::scm::errors::SCMError;
-use crate::scm::git::{Commit, GitService, Reference, Signature, Tree, TreeEntry};
+use crate::scm::git::{Commit, GitService, Reference, ReferenceInput, Signature, TagInput, Tree, TreeEntry};
 use crate::scm::utils;
 
 pub struct GithubGitService {
@@ -71,6 +77,120 @@ impl GitService for GithubGitService {
         Ok(vec![])
     }
 
+    /// Returns a single branch by name.
+    ///
+    /// Docs: https://docs.github.com/en/rest/branches/branches?apiVersion=2022-11-28#get-a-branch
+    /// Example: https://api.github.com/repos/octocat/Hello-World/branches/master
+    async fn find_branch(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        let path = GITHUB_PATH_BRANCH
+            .replace("{repo}", repo)
+            .replace("{branch}", name);
+        let res = self
+            .client
+            .get::<GithubBranch>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| (&v).into()))
+    }
+
+    /// Returns a single tag by name, annotated tags are peeled to the tagged commit.
+    ///
+    /// Docs: https://docs.github.com/en/rest/git/refs?apiVersion=2022-11-28#get-a-reference
+    /// Example: https://api.github.com/repos/octocat/Hello-World/git/ref/tags/v1.0.0
+    async fn find_tag(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        let reference = utils::expand_ref(name, "refs/tags/");
+        let path = GITHUB_PATH_GIT_REF
+            .replace("{repo}", repo)
+            .replace("{ref}", reference.trim_start_matches("refs/"));
+        let res = self
+            .client
+            .get::<GithubRef>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        let Some(mut tag) = res.data else {
+            return Ok(None);
+        };
+        if tag.object.kind == "tag" {
+            let path = GITHUB_PATH_GIT_TAG
+                .replace("{repo}", repo)
+                .replace("{sha}", &tag.object.sha);
+            let res = self
+                .client
+                .get::<GithubTag>(&path, None)
+                .await
+                .map_err(SCMError::ClientError)?;
+            if let Some(annotated) = res.data {
+                tag.object = annotated.object;
+            }
+        }
+
+        Ok(Some(tag.into()))
+    }
+
+    /// Creates a branch pointing to the commit.
+    ///
+    /// Docs: https://docs.github.com/en/rest/git/refs?apiVersion=2022-11-28#create-a-reference
+    /// Example: https://api.github.com/repos/octocat/Hello-World/git/refs
+    async fn create_branch(&self, repo: &str, input: &ReferenceInput) -> Result<Reference, SCMError> {
+        let reference = utils::expand_ref(&input.name, "refs/heads/");
+        self.create_ref(repo, &reference, &input.sha).await
+    }
+
+    /// Deletes a branch by name.
+    ///
+    /// Docs: https://docs.github.com/en/rest/git/refs?apiVersion=2022-11-28#delete-a-reference
+    /// Example: https://api.github.com/repos/octocat/Hello-World/git/refs/heads/feature
+    async fn delete_branch(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        self.delete_ref(repo, &utils::expand_ref(name, "refs/heads/"))
+            .await
+    }
+
+    /// Creates a tag pointing to the commit, an annotated tag object is
+    /// created first when the input has a message.
+    ///
+    /// Docs: https://docs.github.com/en/rest/git/tags?apiVersion=2022-11-28#create-a-tag-object
+    /// Example: https://api.github.com/repos/octocat/Hello-World/git/tags
+    async fn create_tag(&self, repo: &str, input: &TagInput) -> Result<Reference, SCMError> {
+        let reference = utils::expand_ref(&input.name, "refs/tags/");
+        let Some(message) = &input.message else {
+            return self.create_ref(repo, &reference, &input.sha).await;
+        };
+
+        let path = GITHUB_PATH_GIT_TAGS.replace("{repo}", repo);
+        let body = GithubTagInput {
+            tag: utils::trim_ref(&input.name),
+            message: message.clone(),
+            object: input.sha.clone(),
+            kind: "commit".to_string(),
+        };
+        let res = self
+            .client
+            .post::<GithubTag, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+        let tag = match res.data {
+            Some(tag) if res.status.is_success() => tag,
+            _ => return Err(SCMError::UnexpectedStatus(path, res.status)),
+        };
+
+        let created = self.create_ref(repo, &reference, &tag.sha).await?;
+        Ok(Reference {
+            sha: input.sha.clone(),
+            ..created
+        })
+    }
+
+    /// Deletes a tag by name.
+    ///
+    /// Docs: https://docs.github.com/en/rest/git/refs?apiVersion=2022-11-28#delete-a-reference
+    /// Example: https://api.github.com/repos/octocat/Hello-World/git/refs/tags/v1.0.0
+    async fn delete_tag(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        self.delete_ref(repo, &utils::expand_ref(name, "refs/tags/"))
+            .await
+    }
+
     /// Returns the contents of a single commit reference.
     ///
     /// Docs: https://docs.github.com/en/rest/commits/commits?apiVersion=2022-11-28#get-a-commit
@@ -112,6 +232,75 @@ impl GitService for GithubGitService {
 
         Ok(res.data.map(|v| v.into()))
     }
+
+    /// Downloads a tarball or zipball archive of a repository at the reference.
+    ///
+    /// Docs: https://docs.github.com/en/rest/repos/contents?apiVersion=2022-11-28#download-a-repository-archive-tar
+    /// Example: https://api.github.com/repos/octocat/Hello-World/tarball/master
+    async fn archive(
+        &self,
+        repo: &str,
+        reference: &str,
+        format: ArchiveFormat,
+        _path: Option<&str>,
+    ) -> Result<ByteStream, SCMError> {
+        let format = match format {
+            ArchiveFormat::TarGz => "tarball",
+            ArchiveFormat::Zip => "zipball",
+        };
+        let path = GITHUB_PATH_ARCHIVE
+            .replace("{repo}", repo)
+            .replace("{format}", format)
+            .replace("{reference}", reference);
+        let res = self
+            .client
+            .get_stream(&path, None, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        Ok(res.stream)
+    }
+}
+
+impl GithubGitService {
+    /// Creates a fully qualified reference pointing to the SHA.
+    async fn create_ref(&self, repo: &str, reference: &str, sha: &str) -> Result<Reference, SCMError> {
+        let path = GITHUB_PATH_GIT_REFS.replace("{repo}", repo);
+        let body = GithubRefInput {
+            reference: reference.to_string(),
+            sha: sha.to_string(),
+        };
+        let res = self
+            .client
+            .post::<GithubRef, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(created) if res.status.is_success() => Ok(created.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a fully qualified reference.
+    async fn delete_ref(&self, repo: &str, reference: &str) -> Result<(), SCMError> {
+        let path = GITHUB_PATH_GIT_REFS_DELETE
+            .replace("{repo}", repo)
+            .replace("{ref}", reference.trim_start_matches("refs/"));
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -195,6 +384,65 @@ impl Endpoint for Vec<GithubBranch> {
     type Output = Vec<GithubBranch>;
 }
 
+impl Endpoint for GithubBranch {
+    type Output = GithubBranch;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubRef {
+    #[serde(rename = "ref")]
+    pub reference: String,
+    pub object: GithubObject,
+}
+
+impl From<GithubRef> for Reference {
+    fn from(val: GithubRef) -> Self {
+        Self {
+            name: utils::trim_ref(&val.reference),
+            path: val.reference,
+            sha: val.object.sha,
+        }
+    }
+}
+
+impl Endpoint for GithubRef {
+    type Output = GithubRef;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubObject {
+    pub sha: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GithubRefInput {
+    #[serde(rename = "ref")]
+    pub reference: String,
+    pub sha: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubTag {
+    pub sha: String,
+    pub tag: String,
+    pub object: GithubObject,
+}
+
+impl Endpoint for GithubTag {
+    type Output = GithubTag;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GithubTagInput {
+    pub tag: String,
+    pub message: String,
+    pub object: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
 impl Endpoint for GithubCommit {
     type Output = GithubCommit;
 }
@@ -0,0 +1,317 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    GITHUB_PATH_ISSUE, GITHUB_PATH_ISSUES, GITHUB_PATH_ISSUE_COMMENT, GITHUB_PATH_ISSUE_COMMENTS,
+};
+use super::user::GithubUser;
+use super::utils::convert_list_options;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::issue::{Comment, CommentInput, Issue, IssueInput, IssueService, Thread};
+
+pub struct GithubIssueService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl IssueService for GithubIssueService {
+    /// Returns an issue by number.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/issues?apiVersion=2022-11-28#get-an-issue
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues/1347
+    async fn find(&self, repo: &str, number: u64) -> Result<Option<Issue>, SCMError> {
+        let path = GITHUB_PATH_ISSUE
+            .replace("{repo}", repo)
+            .replace("{number}", &number.to_string());
+        let res = self
+            .client
+            .get::<GithubIssue>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns a list of the open issues, GitHub lists pull requests as issues
+    /// so they are filtered out.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/issues?apiVersion=2022-11-28#list-repository-issues
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Issue>, SCMError> {
+        let path = GITHUB_PATH_ISSUES.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GithubIssue>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(issues) = res.data {
+            return Ok(issues
+                .into_iter()
+                .filter(|v| v.pull_request.is_none())
+                .map(|v| v.into())
+                .collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates an issue.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/issues?apiVersion=2022-11-28#create-an-issue
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues
+    async fn create(&self, repo: &str, input: &IssueInput) -> Result<Issue, SCMError> {
+        let path = GITHUB_PATH_ISSUES.replace("{repo}", repo);
+        let body = GithubIssueInput {
+            title: input.title.clone(),
+            body: input.body.clone(),
+            labels: input.labels.clone(),
+        };
+        let res = self
+            .client
+            .post::<GithubIssue, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(issue) if res.status.is_success() => Ok(issue.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Closes an issue by number.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/issues?apiVersion=2022-11-28#update-an-issue
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues/1347
+    async fn close(&self, repo: &str, number: u64) -> Result<(), SCMError> {
+        let path = GITHUB_PATH_ISSUE
+            .replace("{repo}", repo)
+            .replace("{number}", &number.to_string());
+        let body = GithubIssueState {
+            state: "closed".to_string(),
+        };
+        let res = self
+            .client
+            .patch::<Empty, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a list of comments, pull requests share the comments API of issues.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/comments?apiVersion=2022-11-28#list-issue-comments
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues/1347/comments
+    async fn list_comments(
+        &self,
+        repo: &str,
+        thread: Thread,
+        opts: ListOptions,
+    ) -> Result<Vec<Comment>, SCMError> {
+        let path = GITHUB_PATH_ISSUE_COMMENTS
+            .replace("{repo}", repo)
+            .replace("{number}", &thread.number().to_string());
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GithubComment>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(comments) = res.data {
+            return Ok(comments.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates a comment on the issue or pull request.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/comments?apiVersion=2022-11-28#create-an-issue-comment
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues/1347/comments
+    async fn create_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = GITHUB_PATH_ISSUE_COMMENTS
+            .replace("{repo}", repo)
+            .replace("{number}", &thread.number().to_string());
+        let body = GithubCommentInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .post::<GithubComment, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(comment) if res.status.is_success() => Ok(comment.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Updates a comment, the comments are addressed by id only.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/comments?apiVersion=2022-11-28#update-an-issue-comment
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues/comments/1
+    async fn update_comment(
+        &self,
+        repo: &str,
+        _thread: Thread,
+        id: u64,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = GITHUB_PATH_ISSUE_COMMENT
+            .replace("{repo}", repo)
+            .replace("{id}", &id.to_string());
+        let body = GithubCommentInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .patch::<GithubComment, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(comment) if res.status.is_success() => Ok(comment.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a comment, the comments are addressed by id only.
+    ///
+    /// Docs: https://docs.github.com/en/rest/issues/comments?apiVersion=2022-11-28#delete-an-issue-comment
+    /// Example: https://api.github.com/repos/octocat/Hello-World/issues/comments/1
+    async fn delete_comment(&self, repo: &str, _thread: Thread, id: u64) -> Result<(), SCMError> {
+        let path = GITHUB_PATH_ISSUE_COMMENT
+            .replace("{repo}", repo)
+            .replace("{id}", &id.to_string());
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String,
+    pub labels: Vec<GithubLabel>,
+    pub user: GithubUser,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub pull_request: Option<serde_json::Value>,
+}
+
+impl From<GithubIssue> for Issue {
+    fn from(val: GithubIssue) -> Self {
+        Self {
+            number: val.number,
+            title: val.title,
+            body: val.body.unwrap_or_default(),
+            labels: val.labels.into_iter().map(|v| v.name).collect(),
+            closed: val.state == "closed",
+            author: val.user.into(),
+            link: val.html_url,
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for GithubIssue {
+    type Output = GithubIssue;
+}
+
+impl Endpoint for Vec<GithubIssue> {
+    type Output = Vec<GithubIssue>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GithubIssueInput {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GithubIssueState {
+    pub state: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubComment {
+    pub id: u64,
+    pub body: Option<String>,
+    pub user: GithubUser,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<GithubComment> for Comment {
+    fn from(val: GithubComment) -> Self {
+        Self {
+            id: val.id,
+            body: val.body.unwrap_or_default(),
+            author: val.user.into(),
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for GithubComment {
+    type Output = GithubComment;
+}
+
+impl Endpoint for Vec<GithubComment> {
+    type Output = Vec<GithubComment>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GithubCommentInput {
+    pub body: String,
+}
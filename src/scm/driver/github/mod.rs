@@ -12,19 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod app;
 pub mod constants;
 pub mod content;
 pub mod driver;
 pub mod git;
+pub mod issue;
+pub mod key;
+pub mod org;
 pub mod pr;
+pub mod release;
 pub mod repo;
+pub mod search;
+pub mod user;
 mod utils;
 
-use self::constants::GITHUB_ENDPOINT;
+use std::sync::Arc;
+
+use self::app::GithubApp;
+use self::constants::{GITHUB_API_HOST, GITHUB_ENDPOINT, GITHUB_TRUSTED_HOSTS};
 use self::driver::GithubDriver;
 use self::pr::GithubFile;
 use super::Driver;
-use crate::http::Client;
+use crate::config::{AppCredential, Credential};
+use crate::http::{Auth, Client};
 use crate::scm::errors::SCMError;
 
 /// Returns a new GitHub driver using the default api.github.com address.
@@ -36,11 +47,48 @@ pub fn default() -> Result<Driver, SCMError> {
 /// Returns a new GitHub driver.
 #[inline]
 pub fn new(url: &str, token: Option<String>) -> Result<Driver, SCMError> {
-    from(Client::new(url, token).map_err(SCMError::ClientError)?)
+    from(Client::with_auth(url, token.map(Auth::Bearer).unwrap_or_default()).map_err(SCMError::ClientError)?)
+}
+
+/// Returns a new GitHub driver authenticated with the credential.
+pub fn with_credential(url: &str, credential: &impl Credential) -> Result<Driver, SCMError> {
+    from(Client::with_auth(url, auth(credential)).map_err(SCMError::ClientError)?)
+}
+
+/// Returns a new GitHub driver authenticated as the installation of a GitHub App.
+pub fn with_app(url: &str, credential: &AppCredential) -> Result<Driver, SCMError> {
+    from_app(Client::new(url, None).map_err(SCMError::ClientError)?, credential)
+}
+
+/// Returns a new GitHub driver using the given client, authenticated as the
+/// installation of a GitHub App. The installation tokens are minted with the
+/// same client, e.g. through its proxy or with its root certificates.
+pub fn from_app(client: Client, credential: &AppCredential) -> Result<Driver, SCMError> {
+    let endpoint = client.url("").map_err(SCMError::ClientError)?;
+    let app = GithubApp::from_credential(endpoint.as_str(), credential)
+        .map_err(SCMError::ClientError)?
+        .client(&client);
+    from(client.with_authentication(Auth::Source(Arc::new(app))))
+}
+
+/// Returns the authentication of the credential, GitHub accepts access tokens
+/// as bearer tokens, and personal access tokens as the password of basic
+/// authentication.
+pub fn auth(credential: &impl Credential) -> Auth {
+    super::auth(credential, Auth::Bearer)
 }
 
-/// Returns a new GitHub driver using the given client.
+/// Returns a new GitHub driver using the given client, the credentials of a
+/// client for api.github.com are also sent to the other hosts of github.com
+/// the API links to.
 pub fn from(client: Client) -> Result<Driver, SCMError> {
+    let client = match client
+        .url("")
+        .is_ok_and(|url| url.host_str() == Some(GITHUB_API_HOST))
+    {
+        true => client.with_trusted_hosts(GITHUB_TRUSTED_HOSTS),
+        false => client,
+    };
     Ok(Driver::Github(GithubDriver { client }))
 }
 
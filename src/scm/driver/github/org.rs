@@ -0,0 +1,212 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GITHUB_PATH_ORGS, GITHUB_PATH_ORG_MEMBERS, GITHUB_PATH_USER_ORGS};
+use super::utils::convert_list_options;
+use crate::http::{endpoint::Endpoint, Client};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::org::{Member, Organization, OrganizationService, Role};
+
+/// The default and the maximum page size of the GitHub API.
+const GITHUB_DEFAULT_PAGE_SIZE: i32 = 30;
+const GITHUB_MAX_PAGE_SIZE: i32 = 100;
+
+pub struct GithubOrganizationService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl OrganizationService for GithubOrganizationService {
+    /// Returns the organizations of the authenticated user.
+    ///
+    /// Docs: https://docs.github.com/en/rest/orgs/orgs?apiVersion=2022-11-28#list-organizations-for-the-authenticated-user
+    /// Example: https://api.github.com/user/orgs
+    async fn list(&self, opts: ListOptions) -> Result<Vec<Organization>, SCMError> {
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GithubOrganization>>(GITHUB_PATH_USER_ORGS, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(orgs) = res.data {
+            return Ok(orgs.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Returns an organization by name.
+    ///
+    /// Docs: https://docs.github.com/en/rest/orgs/orgs?apiVersion=2022-11-28#get-an-organization
+    /// Example: https://api.github.com/orgs/github
+    async fn find(&self, name: &str) -> Result<Option<Organization>, SCMError> {
+        let path = GITHUB_PATH_ORGS.replace("{org}", name);
+        let res = self
+            .client
+            .get::<GithubOrganization>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the members of an organization, the admins first. The members
+    /// API does not return the role, so the admins and the other members are
+    /// listed separately with the `role` filter, and the page is cut from them.
+    ///
+    /// Docs: https://docs.github.com/en/rest/orgs/members?apiVersion=2022-11-28#list-organization-members
+    /// Example: https://api.github.com/orgs/github/members?role=admin
+    async fn list_members(&self, name: &str, opts: ListOptions) -> Result<Vec<Member>, SCMError> {
+        let path = GITHUB_PATH_ORG_MEMBERS.replace("{org}", name);
+        let page = opts.page.max(1);
+        let size = match opts.size {
+            1.. => opts.size,
+            _ => GITHUB_DEFAULT_PAGE_SIZE,
+        };
+
+        let admins = self.list_by_role(&path, "admin", page, size).await?;
+        let found = admins.len() as i32;
+        let mut members: Vec<Member> = admins.into_iter().map(|v| v.into_member(Role::Admin)).collect();
+        if found == size {
+            return Ok(members);
+        }
+
+        // the admins end on this page, unless it is past them and they have to be counted.
+        let total = match found > 0 || page == 1 {
+            true => (page - 1) * size + found,
+            false => self.count_admins(&path).await?,
+        };
+        let offset = (page - 1) * size + found - total;
+        let wanted = (size - found) as usize;
+
+        // the wanted members span at most two pages of the same size.
+        let skip = (offset % size) as usize;
+        let mut others = vec![];
+        let mut page = offset / size + 1;
+        while others.len() < skip + wanted {
+            let chunk = self.list_by_role(&path, "member", page, size).await?;
+            let last = chunk.len() < size as usize;
+            others.extend(chunk);
+            if last {
+                break;
+            }
+            page += 1;
+        }
+
+        members.extend(
+            others
+                .into_iter()
+                .skip(skip)
+                .take(wanted)
+                .map(|v| v.into_member(Role::Member)),
+        );
+        Ok(members)
+    }
+}
+
+impl GithubOrganizationService {
+    /// Returns a page of the members of the organization with the role.
+    async fn list_by_role(
+        &self,
+        path: &str,
+        role: &str,
+        page: i32,
+        size: i32,
+    ) -> Result<Vec<GithubMember>, SCMError> {
+        let mut options = convert_list_options(ListOptions {
+            page,
+            size,
+            ..Default::default()
+        });
+        options.insert("role".to_string(), role.to_string());
+        let res = self
+            .client
+            .get::<Vec<GithubMember>>(path, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.unwrap_or_default())
+    }
+
+    /// Returns the number of admins of the organization.
+    async fn count_admins(&self, path: &str) -> Result<i32, SCMError> {
+        let mut count = 0;
+        let mut page = 1;
+
+        loop {
+            let admins = self
+                .list_by_role(path, "admin", page, GITHUB_MAX_PAGE_SIZE)
+                .await?;
+            count += admins.len() as i32;
+            if admins.len() < GITHUB_MAX_PAGE_SIZE as usize {
+                return Ok(count);
+            }
+            page += 1;
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubOrganization {
+    pub id: u64,
+    pub login: String,
+    pub name: Option<String>,
+    pub avatar_url: String,
+    pub html_url: Option<String>,
+}
+
+impl From<GithubOrganization> for Organization {
+    fn from(val: GithubOrganization) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val.name.unwrap_or_else(|| val.login.clone()),
+            link: val.html_url.unwrap_or_default(),
+            login: val.login,
+            avatar: val.avatar_url,
+        }
+    }
+}
+
+impl Endpoint for GithubOrganization {
+    type Output = GithubOrganization;
+}
+
+impl Endpoint for Vec<GithubOrganization> {
+    type Output = Vec<GithubOrganization>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubMember {
+    pub login: String,
+    pub avatar_url: String,
+}
+
+impl GithubMember {
+    fn into_member(self, role: Role) -> Member {
+        Member {
+            login: self.login,
+            avatar: self.avatar_url,
+            role,
+        }
+    }
+}
+
+impl Endpoint for Vec<GithubMember> {
+    type Output = Vec<GithubMember>;
+}
@@ -0,0 +1,300 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    GITHUB_PATH_RELEASE, GITHUB_PATH_RELEASES, GITHUB_PATH_RELEASE_ASSETS, GITHUB_PATH_RELEASE_BY_TAG,
+    GITHUB_PATH_RELEASE_LATEST,
+};
+use super::utils::convert_list_options;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{Client, HTTPError, Payload};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::release::{Asset, Release, ReleaseInput, ReleaseService};
+
+pub struct GithubReleaseService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl ReleaseService for GithubReleaseService {
+    /// Returns a list of releases, including drafts if the token can push.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/releases?apiVersion=2022-11-28#list-releases
+    /// Example: https://api.github.com/repos/octocat/Hello-World/releases
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Release>, SCMError> {
+        let path = GITHUB_PATH_RELEASES.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GithubRelease>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(releases) = res.data {
+            return Ok(releases.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Finds a release by tag.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/releases?apiVersion=2022-11-28#get-a-release-by-tag-name
+    /// Example: https://api.github.com/repos/octocat/Hello-World/releases/tags/v1.0.0
+    async fn find_by_tag(&self, repo: &str, tag: &str) -> Result<Option<Release>, SCMError> {
+        let path = GITHUB_PATH_RELEASE_BY_TAG
+            .replace("{repo}", repo)
+            .replace("{tag}", tag);
+        let res = self
+            .client
+            .get::<GithubRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the latest published full release, drafts and prereleases are skipped.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/releases?apiVersion=2022-11-28#get-the-latest-release
+    /// Example: https://api.github.com/repos/octocat/Hello-World/releases/latest
+    async fn latest(&self, repo: &str) -> Result<Option<Release>, SCMError> {
+        let path = GITHUB_PATH_RELEASE_LATEST.replace("{repo}", repo);
+        let res = self
+            .client
+            .get::<GithubRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Creates a release, the tag is created from the target if it does not exist.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/releases?apiVersion=2022-11-28#create-a-release
+    /// Example: https://api.github.com/repos/octocat/Hello-World/releases
+    async fn create(&self, repo: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        let path = GITHUB_PATH_RELEASES.replace("{repo}", repo);
+        let res = self
+            .client
+            .post::<GithubRelease, _>(&path, &GithubReleaseInput::from(input))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Updates a release by id.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/releases?apiVersion=2022-11-28#update-a-release
+    /// Example: https://api.github.com/repos/octocat/Hello-World/releases/1
+    async fn update(&self, repo: &str, id: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        let path = GITHUB_PATH_RELEASE.replace("{repo}", repo).replace("{id}", id);
+        let res = self
+            .client
+            .patch::<GithubRelease, _>(&path, &GithubReleaseInput::from(input))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a release by id.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/releases?apiVersion=2022-11-28#delete-a-release
+    /// Example: https://api.github.com/repos/octocat/Hello-World/releases/1
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError> {
+        let path = GITHUB_PATH_RELEASE.replace("{repo}", repo).replace("{id}", id);
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a list of the assets of a release.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/assets?apiVersion=2022-11-28#list-release-assets
+    /// Example: https://api.github.com/repos/octocat/Hello-World/releases/1/assets
+    async fn list_assets(&self, repo: &str, id: &str) -> Result<Vec<Asset>, SCMError> {
+        let path = GITHUB_PATH_RELEASE_ASSETS
+            .replace("{repo}", repo)
+            .replace("{id}", id);
+        let res = self
+            .client
+            .get::<Vec<GithubAsset>>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(assets) = res.data {
+            return Ok(assets.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Uploads an asset to a release, assets are uploaded to the separate upload
+    /// host given by the `upload_url` of the release.
+    ///
+    /// Docs: https://docs.github.com/en/rest/releases/assets?apiVersion=2022-11-28#upload-a-release-asset
+    /// Example: https://uploads.github.com/repos/octocat/Hello-World/releases/1/assets?name=foo.zip
+    async fn upload_asset(
+        &self,
+        repo: &str,
+        id: &str,
+        name: &str,
+        content_type: &str,
+        data: Payload,
+    ) -> Result<Asset, SCMError> {
+        let path = GITHUB_PATH_RELEASE.replace("{repo}", repo).replace("{id}", id);
+        let release = self
+            .client
+            .get::<GithubRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?
+            .data
+            .ok_or_else(|| SCMError::NotFound(path))?;
+
+        // the upload url is a hypermedia template, e.g. `.../assets{?name,label}`
+        let url = release
+            .upload_url
+            .split('{')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let options = Some([("name".to_string(), name.to_string())].into());
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_str(content_type)
+                .map_err(|e| SCMError::ClientError(HTTPError::InvalidHeaderValue(e)))?,
+        );
+        let res = self
+            .client
+            .post_stream::<GithubAsset>(&url, options, data, headers)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(asset) if res.status.is_success() => Ok(asset.into()),
+            _ => Err(SCMError::UnexpectedStatus(url, res.status)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubRelease {
+    pub id: u64,
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub html_url: String,
+    pub upload_url: String,
+    pub created_at: String,
+    pub published_at: Option<String>,
+}
+
+impl From<GithubRelease> for Release {
+    fn from(val: GithubRelease) -> Self {
+        Self {
+            id: val.id.to_string(),
+            tag: val.tag_name,
+            title: val.name.unwrap_or_default(),
+            description: val.body.unwrap_or_default(),
+            draft: val.draft,
+            prerelease: val.prerelease,
+            link: val.html_url,
+            created: val.created_at,
+            published: val.published_at,
+        }
+    }
+}
+
+impl Endpoint for GithubRelease {
+    type Output = GithubRelease;
+}
+
+impl Endpoint for Vec<GithubRelease> {
+    type Output = Vec<GithubRelease>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GithubReleaseInput {
+    pub tag_name: String,
+    pub name: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+impl From<&ReleaseInput> for GithubReleaseInput {
+    fn from(val: &ReleaseInput) -> Self {
+        Self {
+            tag_name: val.tag.clone(),
+            name: val.title.clone(),
+            body: val.description.clone(),
+            target_commitish: val.target.clone(),
+            draft: val.draft,
+            prerelease: val.prerelease,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubAsset {
+    pub id: u64,
+    pub name: String,
+    pub size: u64,
+    pub browser_download_url: String,
+}
+
+impl From<GithubAsset> for Asset {
+    fn from(val: GithubAsset) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val.name,
+            size: Some(val.size),
+            link: val.browser_download_url,
+        }
+    }
+}
+
+impl Endpoint for GithubAsset {
+    type Output = GithubAsset;
+}
+
+impl Endpoint for Vec<GithubAsset> {
+    type Output = Vec<GithubAsset>;
+}
@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::http::{endpoint::Endpoint, Client};
 use crate::scm::errors::SCMError;
-use crate::scm::repo::{Repository, RepositoryService};
+use crate::scm::repo::{Permission, Repository, RepositoryService};
+use crate::scm::utils;
 
-use super::constants::GITHUB_PATH_REPOS;
+use super::constants::{GITHUB_PATH_COLLABORATOR_PERMISSION, GITHUB_PATH_LANGUAGES, GITHUB_PATH_REPOS};
 
 pub struct GithubRepoService {
     pub client: Client,
@@ -41,6 +45,47 @@ impl RepositoryService for GithubRepoService {
 
         Ok(res.data.map(|v| v.into()))
     }
+
+    /// Returns the permission of a user, the `maintain` and `triage` roles
+    /// are mapped to write and read respectively.
+    ///
+    /// Docs: https://docs.github.com/en/rest/collaborators/collaborators?apiVersion=2022-11-28#get-repository-permissions-for-a-user
+    /// Example: https://api.github.com/repos/octocat/Hello-World/collaborators/octocat/permission
+    async fn permission(&self, repo: &str, user: &str) -> Result<Permission, SCMError> {
+        let path = GITHUB_PATH_COLLABORATOR_PERMISSION
+            .replace("{repo}", repo)
+            .replace("{user}", user);
+        let res = self
+            .client
+            .get::<GithubPermission>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if res.status == StatusCode::NOT_FOUND {
+            return Ok(Permission::None);
+        }
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(res.data.map(|v| v.into()).unwrap_or_default())
+    }
+
+    /// Returns the languages of a repository, as percentages of the code.
+    ///
+    /// Docs: https://docs.github.com/en/rest/repos/repos?apiVersion=2022-11-28#list-repository-languages
+    /// Example: https://api.github.com/repos/octocat/Hello-World/languages
+    async fn languages(&self, repo: &str) -> Result<HashMap<String, f64>, SCMError> {
+        let path = GITHUB_PATH_LANGUAGES.replace("{repo}", repo);
+        let res = self
+            .client
+            .get::<GithubLanguages>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+        utils::check_status(path, res.status)?;
+
+        Ok(utils::percentages(res.data.unwrap_or_default()))
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -88,3 +133,30 @@ impl From<GithubRepository> for Repository {
 impl Endpoint for GithubRepository {
     type Output = GithubRepository;
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubPermission {
+    pub permission: String,
+}
+
+impl From<GithubPermission> for Permission {
+    fn from(val: GithubPermission) -> Self {
+        match val.permission.as_str() {
+            "admin" => Permission::Admin,
+            "maintain" | "write" => Permission::Write,
+            "triage" | "read" => Permission::Read,
+            _ => Permission::None,
+        }
+    }
+}
+
+impl Endpoint for GithubPermission {
+    type Output = GithubPermission;
+}
+
+/// The bytes of code of each language of a repository, keyed by the language name.
+pub struct GithubLanguages;
+
+impl Endpoint for GithubLanguages {
+    type Output = HashMap<String, u64>;
+}
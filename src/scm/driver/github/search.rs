@@ -0,0 +1,163 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GITHUB_PATH_SEARCH_CODE, GITHUB_PATH_SEARCH_REPOSITORIES};
+use super::repo::GithubRepository;
+use super::utils::convert_list_options;
+use crate::http::endpoint::Endpoint;
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::repo::Repository;
+use crate::scm::search::{CodeResult, SearchCapabilities, SearchOptions, SearchScope, SearchService};
+
+pub struct GithubSearchService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl SearchService for GithubSearchService {
+    fn capabilities(&self) -> SearchCapabilities {
+        SearchCapabilities {
+            code: true,
+            repositories: true,
+        }
+    }
+
+    /// Searches for files, only the default branch of each repository is indexed.
+    ///
+    /// Docs: https://docs.github.com/en/rest/search/search?apiVersion=2022-11-28#search-code
+    /// Example: https://api.github.com/search/code?q=filename:.amp.toml+org:amphitheatre-app
+    async fn code(&self, opts: &SearchOptions, list: ListOptions) -> Result<Vec<CodeResult>, SCMError> {
+        let mut options = convert_list_options(list);
+        options.insert("q".to_string(), query(opts, true));
+        let res = self
+            .client
+            .get::<GithubSearch<GithubCodeResult>>(GITHUB_PATH_SEARCH_CODE, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(search) = res.data {
+            return Ok(search.items.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Searches for repositories.
+    ///
+    /// Docs: https://docs.github.com/en/rest/search/search?apiVersion=2022-11-28#search-repositories
+    /// Example: https://api.github.com/search/repositories?q=amp+org:amphitheatre-app
+    async fn repositories(
+        &self,
+        opts: &SearchOptions,
+        list: ListOptions,
+    ) -> Result<Vec<Repository>, SCMError> {
+        let mut options = convert_list_options(list);
+        options.insert("q".to_string(), query(opts, false));
+        let res = self
+            .client
+            .get::<GithubSearch<GithubRepository>>(GITHUB_PATH_SEARCH_REPOSITORIES, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(search) = res.data {
+            return Ok(search.items.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+}
+
+/// Builds the search query, the scope and path are expressed as qualifiers.
+fn query(opts: &SearchOptions, code: bool) -> String {
+    let mut terms = vec![];
+    if !opts.query.is_empty() {
+        terms.push(opts.query.clone());
+    }
+    match &opts.scope {
+        SearchScope::All => {}
+        SearchScope::Repository(repo) => terms.push(format!("repo:{repo}")),
+        SearchScope::Organization(org) => terms.push(format!("org:{org}")),
+    }
+    if let Some(path) = opts.path.as_ref().filter(|_| code) {
+        terms.push(format!("path:{path}"));
+    }
+
+    terms.join(" ")
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubSearch<T> {
+    pub total_count: u64,
+    pub incomplete_results: bool,
+    pub items: Vec<T>,
+}
+
+impl<T> Endpoint for GithubSearch<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    type Output = GithubSearch<T>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubCodeResult {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub html_url: String,
+    pub repository: GithubCodeRepository,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubCodeRepository {
+    pub full_name: String,
+}
+
+impl From<GithubCodeResult> for CodeResult {
+    fn from(val: GithubCodeResult) -> Self {
+        Self {
+            repo: val.repository.full_name,
+            name: val.name,
+            path: val.path,
+            sha: val.sha,
+            link: val.html_url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::query;
+    use crate::scm::search::{SearchOptions, SearchScope};
+
+    #[test]
+    fn test_query() {
+        let opts = SearchOptions {
+            query: "filename:.amp.toml".to_string(),
+            scope: SearchScope::Organization("amphitheatre-app".to_string()),
+            path: Some("services".to_string()),
+        };
+
+        assert_eq!(
+            query(&opts, true),
+            "filename:.amp.toml org:amphitheatre-app path:services"
+        );
+        assert_eq!(query(&opts, false), "filename:.amp.toml org:amphitheatre-app");
+    }
+}
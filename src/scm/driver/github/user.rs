@@ -0,0 +1,126 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GITHUB_PATH_USER, GITHUB_PATH_USERS, GITHUB_PATH_USER_EMAILS};
+use super::utils::convert_list_options;
+use crate::http::{endpoint::Endpoint, Client};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::user::{Email, User, UserService};
+
+pub struct GithubUserService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl UserService for GithubUserService {
+    /// Returns the authenticated user.
+    ///
+    /// Docs: https://docs.github.com/en/rest/users/users?apiVersion=2022-11-28#get-the-authenticated-user
+    /// Example: https://api.github.com/user
+    async fn find(&self) -> Result<Option<User>, SCMError> {
+        let res = self
+            .client
+            .get::<GithubUser>(GITHUB_PATH_USER, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns a user by login.
+    ///
+    /// Docs: https://docs.github.com/en/rest/users/users?apiVersion=2022-11-28#get-a-user
+    /// Example: https://api.github.com/users/octocat
+    async fn find_login(&self, login: &str) -> Result<Option<User>, SCMError> {
+        let path = GITHUB_PATH_USERS.replace("{login}", login);
+        let res = self
+            .client
+            .get::<GithubUser>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the email addresses of the authenticated user.
+    ///
+    /// Docs: https://docs.github.com/en/rest/users/emails?apiVersion=2022-11-28#list-email-addresses-for-the-authenticated-user
+    /// Example: https://api.github.com/user/emails
+    async fn list_emails(&self, opts: ListOptions) -> Result<Vec<Email>, SCMError> {
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GithubEmail>>(GITHUB_PATH_USER_EMAILS, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(emails) = res.data {
+            return Ok(emails.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubUser {
+    pub id: u64,
+    pub login: String,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub avatar_url: String,
+    pub html_url: String,
+}
+
+impl From<GithubUser> for User {
+    fn from(val: GithubUser) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val.name.unwrap_or_else(|| val.login.clone()),
+            login: val.login,
+            email: val.email,
+            avatar: val.avatar_url,
+            link: val.html_url,
+        }
+    }
+}
+
+impl Endpoint for GithubUser {
+    type Output = GithubUser;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GithubEmail {
+    pub email: String,
+    pub primary: bool,
+    pub verified: bool,
+}
+
+impl From<GithubEmail> for Email {
+    fn from(val: GithubEmail) -> Self {
+        Self {
+            value: val.email,
+            primary: val.primary,
+            verified: val.verified,
+        }
+    }
+}
+
+impl Endpoint for Vec<GithubEmail> {
+    type Output = Vec<GithubEmail>;
+}
@@ -14,9 +14,39 @@
 
 pub const GITLAB_ENDPOINT: &str = "https://gitlab.com";
 
+// The token endpoint of OAuth applications, relative to the server address
+pub const GITLAB_PATH_OAUTH_TOKEN: &str = "/oauth/token";
+
 // REST API paths
 pub const GITLAB_PATH_CONTENTS: &str = "/api/v4/projects/{repo}/repository/files/{file}";
 pub const GITLAB_PATH_BRANCHES: &str = "/api/v4/projects/{repo}/repository/branches";
+pub const GITLAB_PATH_BRANCH: &str = "/api/v4/projects/{repo}/repository/branches/{branch}";
 pub const GITLAB_PATH_TAGS: &str = "/api/v4/projects/{repo}/repository/tags";
+pub const GITLAB_PATH_TAG: &str = "/api/v4/projects/{repo}/repository/tags/{tag}";
 pub const GITLAB_PATH_COMMITS: &str = "/api/v4/projects/{repo}/repository/commits/{reference}";
 pub const GITLAB_PATH_REPOS: &str = "/api/v4/projects/{repo}";
+pub const GITLAB_PATH_TREE: &str = "/api/v4/projects/{repo}/repository/tree";
+pub const GITLAB_PATH_ARCHIVE: &str = "/api/v4/projects/{repo}/repository/archive.{format}";
+pub const GITLAB_PATH_CONTENTS_RAW: &str = "/api/v4/projects/{repo}/repository/files/{file}/raw";
+pub const GITLAB_PATH_USER: &str = "/api/v4/user";
+pub const GITLAB_PATH_USERS: &str = "/api/v4/users";
+pub const GITLAB_PATH_USER_EMAILS: &str = "/api/v4/user/emails";
+pub const GITLAB_PATH_GROUPS: &str = "/api/v4/groups";
+pub const GITLAB_PATH_GROUP: &str = "/api/v4/groups/{group}";
+pub const GITLAB_PATH_GROUP_MEMBERS: &str = "/api/v4/groups/{group}/members";
+pub const GITLAB_PATH_ISSUES: &str = "/api/v4/projects/{repo}/issues";
+pub const GITLAB_PATH_ISSUE: &str = "/api/v4/projects/{repo}/issues/{number}";
+pub const GITLAB_PATH_ISSUE_NOTES: &str = "/api/v4/projects/{repo}/issues/{number}/notes";
+pub const GITLAB_PATH_MERGE_REQUEST_NOTES: &str = "/api/v4/projects/{repo}/merge_requests/{number}/notes";
+pub const GITLAB_PATH_RELEASES: &str = "/api/v4/projects/{repo}/releases";
+pub const GITLAB_PATH_RELEASE: &str = "/api/v4/projects/{repo}/releases/{tag}";
+pub const GITLAB_PATH_RELEASE_LATEST: &str = "/api/v4/projects/{repo}/releases/permalink/latest";
+pub const GITLAB_PATH_RELEASE_LINKS: &str = "/api/v4/projects/{repo}/releases/{tag}/assets/links";
+pub const GITLAB_PATH_UPLOADS: &str = "/api/v4/projects/{repo}/uploads";
+pub const GITLAB_PATH_DEPLOY_KEYS: &str = "/api/v4/projects/{repo}/deploy_keys";
+pub const GITLAB_PATH_DEPLOY_KEY: &str = "/api/v4/projects/{repo}/deploy_keys/{id}";
+pub const GITLAB_PATH_MEMBERS_ALL: &str = "/api/v4/projects/{repo}/members/all";
+pub const GITLAB_PATH_LANGUAGES: &str = "/api/v4/projects/{repo}/languages";
+pub const GITLAB_PATH_SEARCH: &str = "/api/v4/search";
+pub const GITLAB_PATH_GROUP_SEARCH: &str = "/api/v4/groups/{group}/search";
+pub const GITLAB_PATH_PROJECT_SEARCH: &str = "/api/v4/projects/{repo}/search";
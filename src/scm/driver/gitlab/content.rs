@@ -18,11 +18,13 @@ use async_trait::async_trait;
 use data_encoding::BASE64_MIME as BASE64;
 use serde::{Deserialize, Serialize};
 
-use super::constants::GITLAB_PATH_CONTENTS;
+use super::constants::{GITLAB_PATH_CONTENTS, GITLAB_PATH_CONTENTS_RAW};
+use super::git::{GitlabGitService, GitlabTreeEntry};
 use super::utils::{encode, encode_path};
-use crate::http::{endpoint::Endpoint, Client};
+use crate::http::{endpoint::Endpoint, ByteStream, Client};
 use crate::scm::content::{Content, ContentService, File};
 use crate::scm::errors::SCMError;
+use crate::scm::utils;
 
 pub struct GitlabContentService {
     pub client: Client,
@@ -52,9 +54,41 @@ impl ContentService for GitlabContentService {
         }
     }
 
-    /// @TODO: Get file list from repository.
-    async fn list(&self, _repo: &str, _path: &str, _reference: &str) -> Result<Vec<File>, SCMError> {
-        todo!()
+    /// Get raw file from repository, LFS pointers are resolved by the server.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/repository_files.html#get-raw-file-from-repository
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/files/VERSION/raw?ref=master
+    async fn raw(&self, repo: &str, file: &str, reference: &str) -> Result<ByteStream, SCMError> {
+        let path = GITLAB_PATH_CONTENTS_RAW
+            .replace("{repo}", &encode(repo))
+            .replace("{file}", &encode_path(file));
+        let options = HashMap::from([
+            ("ref".to_string(), reference.to_string()),
+            ("lfs".to_string(), "true".to_string()),
+        ]);
+        let res = self
+            .client
+            .get_stream(&path, Some(options), None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        Ok(res.stream)
+    }
+
+    /// Get file list from repository, through the repository tree.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/repositories.html#list-repository-tree
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/tree?ref=master&path=files
+    async fn list(&self, repo: &str, path: &str, reference: &str) -> Result<Vec<File>, SCMError> {
+        let git = GitlabGitService {
+            client: self.client.clone(),
+        };
+        let path = Some(path).filter(|p| !p.is_empty());
+        let entries = git.list_tree(repo, reference, path, false).await?;
+
+        Ok(entries.unwrap_or_default().iter().map(|v| v.into()).collect())
     }
 }
 
@@ -88,3 +122,20 @@ impl TryFrom<GitlabContent> for Content {
 impl Endpoint for GitlabContent {
     type Output = GitlabContent;
 }
+
+impl From<&GitlabTreeEntry> for File {
+    fn from(val: &GitlabTreeEntry) -> Self {
+        Self {
+            name: val.name.clone(),
+            path: val.path.clone(),
+            sha: val.id.clone(),
+            blob_id: val.id.clone(),
+            // normalize to the kinds of the contents API of other providers.
+            kind: match val.kind.as_str() {
+                "tree" => "dir".to_string(),
+                "blob" => "file".to_string(),
+                kind => kind.to_string(),
+            },
+        }
+    }
+}
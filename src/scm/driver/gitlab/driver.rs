@@ -14,12 +14,25 @@
 
 use super::content::GitlabContentService;
 use super::git::GitlabGitService;
+use super::issue::GitlabIssueService;
+use super::key::GitlabDeployKeyService;
+use super::org::GitlabOrganizationService;
+use super::release::GitlabReleaseService;
 use super::repo::GitlabRepoService;
+use super::search::GitlabSearchService;
+use super::user::GitlabUserService;
 use crate::http::Client;
+use crate::scm::capabilities::Capabilities;
 use crate::scm::content::ContentService;
 use crate::scm::driver::DriverTrait;
 use crate::scm::git::GitService;
+use crate::scm::issue::IssueService;
+use crate::scm::key::DeployKeyService;
+use crate::scm::org::OrganizationService;
+use crate::scm::release::ReleaseService;
 use crate::scm::repo::RepositoryService;
+use crate::scm::search::SearchService;
+use crate::scm::user::UserService;
 
 pub struct GitlabDriver {
     pub client: Client,
@@ -43,6 +56,61 @@ impl DriverTrait for GitlabDriver {
             client: self.client.clone(),
         })
     }
+
+    fn users(&self) -> Box<dyn UserService> {
+        Box::new(GitlabUserService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn organizations(&self) -> Box<dyn OrganizationService> {
+        Box::new(GitlabOrganizationService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn issues(&self) -> Box<dyn IssueService> {
+        Box::new(GitlabIssueService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn releases(&self) -> Box<dyn ReleaseService> {
+        Box::new(GitlabReleaseService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn deploy_keys(&self) -> Box<dyn DeployKeyService> {
+        Box::new(GitlabDeployKeyService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn search(&self) -> Box<dyn SearchService> {
+        Box::new(GitlabSearchService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            contents: true,
+            git: true,
+            git_write: true,
+            repositories: true,
+            users: true,
+            organizations: true,
+            issues: true,
+            releases: true,
+            release_assets: true,
+            release_delete: true,
+            deploy_keys: true,
+            search: self.search().capabilities(),
+            recursive_tree: true,
+            archive_path: true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -12,15 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
-use super::constants::{GITLAB_PATH_BRANCHES, GITLAB_PATH_COMMITS, GITLAB_PATH_TAGS};
+use super::constants::{
+    GITLAB_PATH_ARCHIVE, GITLAB_PATH_BRANCH, GITLAB_PATH_BRANCHES, GITLAB_PATH_COMMITS, GITLAB_PATH_TAG,
+    GITLAB_PATH_TAGS, GITLAB_PATH_TREE,
+};
 use super::utils::{convert_list_options, encode};
-use crate::http::{endpoint::Endpoint, Client};
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{ByteStream, Client};
+use crate::scm::archive::ArchiveFormat;
 use crate::scm::client::ListOptions;
 use crate::scm::errors::SCMError;
-use crate::scm::git::{Commit, GitService, Reference, Signature, Tree};
+use crate::scm::git::{Commit, GitService, Reference, ReferenceInput, Signature, TagInput, Tree, TreeEntry};
 use crate::scm::utils;
 
 pub struct GitlabGitService {
@@ -69,6 +77,107 @@ impl GitService for GitlabGitService {
         Ok(vec![])
     }
 
+    /// Get a single repository branch.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/branches.html#get-single-repository-branch
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/branches/master
+    async fn find_branch(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        let path = GITLAB_PATH_BRANCH
+            .replace("{repo}", &encode(repo))
+            .replace("{branch}", &encode(name));
+        let res = self
+            .client
+            .get::<GitlabBranch>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| (&v).into()))
+    }
+
+    /// Get a single repository tag.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/tags.html#get-a-single-repository-tag
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/tags/v1.0.0
+    async fn find_tag(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        let path = GITLAB_PATH_TAG
+            .replace("{repo}", &encode(repo))
+            .replace("{tag}", &encode(name));
+        let res = self
+            .client
+            .get::<GitlabTag>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| (&v).into()))
+    }
+
+    /// Create repository branch.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/branches.html#create-repository-branch
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/branches
+    async fn create_branch(&self, repo: &str, input: &ReferenceInput) -> Result<Reference, SCMError> {
+        let path = GITLAB_PATH_BRANCHES.replace("{repo}", &encode(repo));
+        let body = GitlabBranchInput {
+            branch: input.name.clone(),
+            reference: input.sha.clone(),
+        };
+        let res = self
+            .client
+            .post::<GitlabBranch, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(branch) if res.status.is_success() => Ok((&branch).into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Delete repository branch.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/branches.html#delete-repository-branch
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/branches/feature
+    async fn delete_branch(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        let path = GITLAB_PATH_BRANCH
+            .replace("{repo}", &encode(repo))
+            .replace("{branch}", &encode(name));
+        self.delete(&path).await
+    }
+
+    /// Create a new tag, the tag is annotated when a message is given.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/tags.html#create-a-new-tag
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/tags
+    async fn create_tag(&self, repo: &str, input: &TagInput) -> Result<Reference, SCMError> {
+        let path = GITLAB_PATH_TAGS.replace("{repo}", &encode(repo));
+        let body = GitlabTagInput {
+            tag_name: input.name.clone(),
+            reference: input.sha.clone(),
+            message: input.message.clone(),
+        };
+        let res = self
+            .client
+            .post::<GitlabTag, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(tag) if res.status.is_success() => Ok((&tag).into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Delete a tag.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/tags.html#delete-a-tag
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/tags/v1.0.0
+    async fn delete_tag(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        let path = GITLAB_PATH_TAG
+            .replace("{repo}", &encode(repo))
+            .replace("{tag}", &encode(name));
+        self.delete(&path).await
+    }
+
     /// Get a single commit.
     ///
     /// Docs: https://docs.gitlab.com/ee/api/commits.html#get-a-single-commit
@@ -86,14 +195,122 @@ impl GitService for GitlabGitService {
         Ok(res.data.map(|v| v.into()))
     }
 
-    /// @TODO: Get a tree of a project.
+    /// List repository tree, the `tree_sha` is the name of a branch or tag, or a commit SHA.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/repositories.html#list-repository-tree
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/tree?ref=master
     async fn get_tree(
         &self,
-        _repo: &str,
-        _tree_sha: &str,
-        _recursive: Option<bool>,
+        repo: &str,
+        tree_sha: &str,
+        recursive: Option<bool>,
     ) -> Result<Option<Tree>, SCMError> {
-        todo!()
+        let entries = self
+            .list_tree(repo, tree_sha, None, recursive.unwrap_or_default())
+            .await?;
+
+        Ok(entries.map(|entries| Tree {
+            sha: tree_sha.to_string(),
+            tree: entries.iter().map(|v| v.into()).collect(),
+            truncated: false,
+        }))
+    }
+
+    /// Get file archive, optionally scoped to a subdirectory of the repository.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/repositories.html#get-file-archive
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/repository/archive.tar.gz?sha=master
+    async fn archive(
+        &self,
+        repo: &str,
+        reference: &str,
+        format: ArchiveFormat,
+        path: Option<&str>,
+    ) -> Result<ByteStream, SCMError> {
+        let mut options = HashMap::from([("sha".to_string(), reference.to_string())]);
+        if let Some(path) = path {
+            options.insert("path".to_string(), path.to_string());
+        }
+        let path = GITLAB_PATH_ARCHIVE
+            .replace("{repo}", &encode(repo))
+            .replace("{format}", format.extension());
+        let res = self
+            .client
+            .get_stream(&path, Some(options), None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        Ok(res.stream)
+    }
+}
+
+/// The maximum page size of the GitLab API.
+const GITLAB_MAX_PAGE_SIZE: i32 = 100;
+
+impl GitlabGitService {
+    /// Deletes the resource at the path, e.g. a branch or a tag.
+    async fn delete(&self, path: &str) -> Result<(), SCMError> {
+        let res = self
+            .client
+            .delete::<Empty>(path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path.to_string(), res.status));
+        }
+
+        Ok(())
+    }
+
+    /// Returns all the entries of the repository tree, following the pagination.
+    /// Returns `None` if the repository or reference does not exist, and fails
+    /// if any page can not be listed rather than returning a part of the tree.
+    pub(crate) async fn list_tree(
+        &self,
+        repo: &str,
+        reference: &str,
+        path: Option<&str>,
+        recursive: bool,
+    ) -> Result<Option<Vec<GitlabTreeEntry>>, SCMError> {
+        let url = GITLAB_PATH_TREE.replace("{repo}", &encode(repo));
+        let mut entries = vec![];
+        let mut page = 1;
+
+        loop {
+            let mut options = convert_list_options(ListOptions {
+                page,
+                size: GITLAB_MAX_PAGE_SIZE,
+                ..Default::default()
+            });
+            options.insert("ref".to_string(), reference.to_string());
+            options.insert("recursive".to_string(), recursive.to_string());
+            if let Some(path) = path {
+                options.insert("path".to_string(), path.to_string());
+            }
+
+            let res = self
+                .client
+                .get::<Vec<GitlabTreeEntry>>(&url, Some(options))
+                .await
+                .map_err(SCMError::ClientError)?;
+
+            if res.status == StatusCode::NOT_FOUND && page == 1 {
+                return Ok(None);
+            }
+            if !res.status.is_success() {
+                return Err(SCMError::UnexpectedStatus(url, res.status));
+            }
+            let data = res.data.unwrap_or_default();
+            let count = data.len() as i32;
+            entries.extend(data);
+            if count < GITLAB_MAX_PAGE_SIZE {
+                return Ok(Some(entries));
+            }
+            page += 1;
+        }
     }
 }
 
@@ -117,6 +334,47 @@ impl Endpoint for Vec<GitlabBranch> {
     type Output = Vec<GitlabBranch>;
 }
 
+impl Endpoint for GitlabBranch {
+    type Output = GitlabBranch;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabBranchInput {
+    pub branch: String,
+    #[serde(rename = "ref")]
+    pub reference: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabTag {
+    pub name: String,
+    pub message: Option<String>,
+    pub commit: GitlabCommit,
+}
+
+impl From<&GitlabTag> for Reference {
+    fn from(val: &GitlabTag) -> Self {
+        Self {
+            name: utils::trim_ref(&val.name),
+            path: utils::expand_ref(&val.name, "refs/tags/"),
+            sha: val.commit.id.clone(),
+        }
+    }
+}
+
+impl Endpoint for GitlabTag {
+    type Output = GitlabTag;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabTagInput {
+    pub tag_name: String,
+    #[serde(rename = "ref")]
+    pub reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GitlabCommit {
     pub id: String,
@@ -159,3 +417,29 @@ impl From<GitlabCommit> for Commit {
 impl Endpoint for GitlabCommit {
     type Output = GitlabCommit;
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabTreeEntry {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub path: String,
+    pub mode: String,
+}
+
+impl From<&GitlabTreeEntry> for TreeEntry {
+    fn from(val: &GitlabTreeEntry) -> Self {
+        Self {
+            mode: val.mode.clone(),
+            path: val.path.clone(),
+            sha: val.id.clone(),
+            kind: val.kind.clone(),
+            size: None,
+        }
+    }
+}
+
+impl Endpoint for Vec<GitlabTreeEntry> {
+    type Output = Vec<GitlabTreeEntry>;
+}
@@ -0,0 +1,314 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    GITLAB_PATH_ISSUE, GITLAB_PATH_ISSUES, GITLAB_PATH_ISSUE_NOTES, GITLAB_PATH_MERGE_REQUEST_NOTES,
+};
+use super::user::GitlabUser;
+use super::utils::{convert_list_options, encode};
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::issue::{Comment, CommentInput, Issue, IssueInput, IssueService, Thread};
+
+pub struct GitlabIssueService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl IssueService for GitlabIssueService {
+    /// Single project issue, the number is the internal id of the issue.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/issues.html#single-project-issue
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues/1
+    async fn find(&self, repo: &str, number: u64) -> Result<Option<Issue>, SCMError> {
+        let path = GITLAB_PATH_ISSUE
+            .replace("{repo}", &encode(repo))
+            .replace("{number}", &number.to_string());
+        let res = self
+            .client
+            .get::<GitlabIssue>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// List the open project issues.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/issues.html#list-project-issues
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues?state=opened
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Issue>, SCMError> {
+        let path = GITLAB_PATH_ISSUES.replace("{repo}", &encode(repo));
+        let mut options = convert_list_options(opts);
+        options.insert("state".to_string(), "opened".to_string());
+        let res = self
+            .client
+            .get::<Vec<GitlabIssue>>(&path, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(issues) = res.data {
+            return Ok(issues.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// New issue.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/issues.html#new-issue
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues
+    async fn create(&self, repo: &str, input: &IssueInput) -> Result<Issue, SCMError> {
+        let path = GITLAB_PATH_ISSUES.replace("{repo}", &encode(repo));
+        let body = GitlabIssueInput {
+            title: input.title.clone(),
+            description: input.body.clone(),
+            labels: input.labels.join(","),
+        };
+        let res = self
+            .client
+            .post::<GitlabIssue, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(issue) if res.status.is_success() => Ok(issue.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Edit an issue to close it.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/issues.html#edit-an-issue
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues/1?state_event=close
+    async fn close(&self, repo: &str, number: u64) -> Result<(), SCMError> {
+        let path = GITLAB_PATH_ISSUE
+            .replace("{repo}", &encode(repo))
+            .replace("{number}", &number.to_string());
+        let body = GitlabIssueState {
+            state_event: "close".to_string(),
+        };
+        let res = self
+            .client
+            .put::<Empty, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+
+    /// List the notes of an issue or a merge request, system notes are filtered out.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/notes.html#list-project-issue-notes
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues/1/notes
+    async fn list_comments(
+        &self,
+        repo: &str,
+        thread: Thread,
+        opts: ListOptions,
+    ) -> Result<Vec<Comment>, SCMError> {
+        let path = notes_path(repo, thread);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GitlabNote>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(notes) = res.data {
+            return Ok(notes
+                .into_iter()
+                .filter(|v| !v.system)
+                .map(|v| v.into())
+                .collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Create a new note on an issue or a merge request.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/notes.html#create-new-issue-note
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues/1/notes
+    async fn create_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = notes_path(repo, thread);
+        let body = GitlabNoteInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .post::<GitlabNote, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(note) if res.status.is_success() => Ok(note.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Modify an existing note of an issue or a merge request.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/notes.html#modify-existing-issue-note
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues/1/notes/302
+    async fn update_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        id: u64,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = format!("{}/{id}", notes_path(repo, thread));
+        let body = GitlabNoteInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .put::<GitlabNote, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(note) if res.status.is_success() => Ok(note.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Delete an existing note of an issue or a merge request.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/notes.html#delete-an-issue-note
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/issues/1/notes/302
+    async fn delete_comment(&self, repo: &str, thread: Thread, id: u64) -> Result<(), SCMError> {
+        let path = format!("{}/{id}", notes_path(repo, thread));
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the path of the notes, merge requests are numbered apart from issues.
+fn notes_path(repo: &str, thread: Thread) -> String {
+    let path = match thread {
+        Thread::Issue(_) => GITLAB_PATH_ISSUE_NOTES,
+        Thread::PullRequest(_) => GITLAB_PATH_MERGE_REQUEST_NOTES,
+    };
+    path.replace("{repo}", &encode(repo))
+        .replace("{number}", &thread.number().to_string())
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabIssue {
+    pub iid: u64,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: String,
+    pub labels: Vec<String>,
+    pub author: GitlabUser,
+    pub web_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<GitlabIssue> for Issue {
+    fn from(val: GitlabIssue) -> Self {
+        Self {
+            number: val.iid,
+            title: val.title,
+            body: val.description.unwrap_or_default(),
+            labels: val.labels,
+            closed: val.state == "closed",
+            author: val.author.into(),
+            link: val.web_url,
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for GitlabIssue {
+    type Output = GitlabIssue;
+}
+
+impl Endpoint for Vec<GitlabIssue> {
+    type Output = Vec<GitlabIssue>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabIssueInput {
+    pub title: String,
+    pub description: String,
+    pub labels: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabIssueState {
+    pub state_event: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabNote {
+    pub id: u64,
+    pub body: String,
+    pub author: GitlabUser,
+    pub system: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<GitlabNote> for Comment {
+    fn from(val: GitlabNote) -> Self {
+        Self {
+            id: val.id,
+            body: val.body,
+            author: val.author.into(),
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for GitlabNote {
+    type Output = GitlabNote;
+}
+
+impl Endpoint for Vec<GitlabNote> {
+    type Output = Vec<GitlabNote>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabNoteInput {
+    pub body: String,
+}
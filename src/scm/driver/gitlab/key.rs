@@ -0,0 +1,131 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GITLAB_PATH_DEPLOY_KEY, GITLAB_PATH_DEPLOY_KEYS};
+use super::utils::{convert_list_options, encode};
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::key::{DeployKey, DeployKeyInput, DeployKeyService};
+
+pub struct GitlabDeployKeyService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl DeployKeyService for GitlabDeployKeyService {
+    /// List deploy keys for project.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/deploy_keys.html#list-deploy-keys-for-project
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/deploy_keys
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<DeployKey>, SCMError> {
+        let path = GITLAB_PATH_DEPLOY_KEYS.replace("{repo}", &encode(repo));
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GitlabDeployKey>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(keys) = res.data {
+            return Ok(keys.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Add deploy key, the key is read-only unless it can push.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/deploy_keys.html#add-deploy-key-for-a-project
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/deploy_keys
+    async fn create(&self, repo: &str, input: &DeployKeyInput) -> Result<DeployKey, SCMError> {
+        let path = GITLAB_PATH_DEPLOY_KEYS.replace("{repo}", &encode(repo));
+        let body = GitlabDeployKeyInput {
+            title: input.title.clone(),
+            key: input.key.clone(),
+            can_push: !input.read_only,
+        };
+        let res = self
+            .client
+            .post::<GitlabDeployKey, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(key) if res.status.is_success() => Ok(key.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Delete deploy key, the key is only removed from the project.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/deploy_keys.html#delete-deploy-key
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/deploy_keys/1
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError> {
+        let path = GITLAB_PATH_DEPLOY_KEY
+            .replace("{repo}", &encode(repo))
+            .replace("{id}", id);
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabDeployKey {
+    pub id: u64,
+    pub title: String,
+    pub key: String,
+    pub can_push: bool,
+    pub created_at: String,
+}
+
+impl From<GitlabDeployKey> for DeployKey {
+    fn from(val: GitlabDeployKey) -> Self {
+        Self {
+            id: val.id.to_string(),
+            title: val.title,
+            key: val.key,
+            read_only: !val.can_push,
+            created: val.created_at,
+        }
+    }
+}
+
+impl Endpoint for GitlabDeployKey {
+    type Output = GitlabDeployKey;
+}
+
+impl Endpoint for Vec<GitlabDeployKey> {
+    type Output = Vec<GitlabDeployKey>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabDeployKeyInput {
+    pub title: String,
+    pub key: String,
+    pub can_push: bool,
+}
@@ -15,14 +15,21 @@
 use self::constants::GITLAB_ENDPOINT;
 use self::driver::GitlabDriver;
 use super::Driver;
-use crate::http::Client;
+use crate::config::{Credential, Scheme};
+use crate::http::{Auth, Client};
 use crate::scm::errors::SCMError;
 
 pub mod constants;
 pub mod content;
 pub mod driver;
 pub mod git;
+pub mod issue;
+pub mod key;
+pub mod org;
+pub mod release;
 pub mod repo;
+pub mod search;
+pub mod user;
 pub mod utils;
 
 /// Returns a new Gitlab driver using the default gitlab.com address.
@@ -30,11 +37,33 @@ pub fn default() -> Result<Driver, SCMError> {
     from(Client::new(GITLAB_ENDPOINT, None).map_err(SCMError::ClientError)?)
 }
 
-/// Returns a new Gitlab driver.
+/// Returns a new Gitlab driver authenticated with an OAuth access token.
 pub fn new(url: &str, token: Option<String>) -> Result<Driver, SCMError> {
     from(Client::new(url, token).map_err(SCMError::ClientError)?)
 }
 
+/// Returns a new Gitlab driver authenticated with a personal, project or group
+/// access token, which is sent in the `PRIVATE-TOKEN` header.
+pub fn with_private_token(url: &str, token: &str) -> Result<Driver, SCMError> {
+    from(Client::with_auth(url, Auth::PrivateToken(token.to_string())).map_err(SCMError::ClientError)?)
+}
+
+/// Returns a new Gitlab driver authenticated with the credential.
+pub fn with_credential(url: &str, credential: &impl Credential) -> Result<Driver, SCMError> {
+    from(Client::with_auth(url, auth(credential)).map_err(SCMError::ClientError)?)
+}
+
+/// Returns the authentication of the credential, a token is sent as an OAuth
+/// bearer token. The API does not support basic authentication, so a password
+/// is taken for a personal access token and sent in the `PRIVATE-TOKEN` header.
+pub fn auth(credential: &impl Credential) -> Auth {
+    match credential.scheme() {
+        Scheme::Bearer => Auth::Bearer(credential.token_any()),
+        Scheme::Basic => Auth::PrivateToken(credential.password_any()),
+        Scheme::Unknown => Auth::None,
+    }
+}
+
 /// Returns a new Gitlab driver using the given client.
 pub fn from(client: Client) -> Result<Driver, SCMError> {
     Ok(Driver::Gitlab(GitlabDriver { client }))
@@ -42,7 +71,9 @@ pub fn from(client: Client) -> Result<Driver, SCMError> {
 
 #[cfg(test)]
 mod test {
-    use crate::{http::Client, scm::driver::gitlab};
+    use crate::config::RepositoryCredential;
+    use crate::http::{Auth, Client};
+    use crate::scm::driver::gitlab;
 
     use super::constants::GITLAB_ENDPOINT;
 
@@ -60,4 +91,23 @@ mod test {
     fn create_gitlab_selhost_driver() {
         let _driver = gitlab::new("https://gitlab.company.com", None);
     }
+
+    #[test]
+    fn select_auth_from_credential() {
+        let credential = RepositoryCredential {
+            token: Some("secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(gitlab::auth(&credential), Auth::Bearer("secret".to_string()));
+
+        let credential = RepositoryCredential {
+            username: Some("root".to_string()),
+            password: Some("glpat-secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            gitlab::auth(&credential),
+            Auth::PrivateToken("glpat-secret".to_string())
+        );
+    }
 }
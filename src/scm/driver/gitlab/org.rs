@@ -0,0 +1,143 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GITLAB_PATH_GROUP, GITLAB_PATH_GROUPS, GITLAB_PATH_GROUP_MEMBERS};
+use super::utils::{convert_list_options, encode};
+use crate::http::{endpoint::Endpoint, Client};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::org::{Member, Organization, OrganizationService, Role};
+
+/// The access level of the group owners.
+///
+/// Docs: https://docs.gitlab.com/ee/api/members.html#roles
+const GITLAB_ACCESS_LEVEL_OWNER: u32 = 50;
+
+pub struct GitlabOrganizationService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl OrganizationService for GitlabOrganizationService {
+    /// List groups the current user is a member of.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/groups.html#list-groups
+    /// Example: https://gitlab.com/api/v4/groups?min_access_level=10
+    async fn list(&self, opts: ListOptions) -> Result<Vec<Organization>, SCMError> {
+        let mut options = convert_list_options(opts);
+        options.insert("min_access_level".to_string(), "10".to_string());
+        let res = self
+            .client
+            .get::<Vec<GitlabGroup>>(GITLAB_PATH_GROUPS, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(groups) = res.data {
+            return Ok(groups.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Get a group by its full path.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/groups.html#details-of-a-group
+    /// Example: https://gitlab.com/api/v4/groups/gitlab-org
+    async fn find(&self, name: &str) -> Result<Option<Organization>, SCMError> {
+        let path = GITLAB_PATH_GROUP.replace("{group}", &encode(name));
+        let res = self
+            .client
+            .get::<GitlabGroup>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// List the direct members of a group, owners are reported as admins.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/members.html#list-all-members-of-a-group-or-project
+    /// Example: https://gitlab.com/api/v4/groups/gitlab-org/members
+    async fn list_members(&self, name: &str, opts: ListOptions) -> Result<Vec<Member>, SCMError> {
+        let path = GITLAB_PATH_GROUP_MEMBERS.replace("{group}", &encode(name));
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GitlabMember>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(members) = res.data {
+            return Ok(members.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabGroup {
+    pub id: u64,
+    pub name: String,
+    pub full_path: String,
+    pub avatar_url: Option<String>,
+    pub web_url: String,
+}
+
+impl From<GitlabGroup> for Organization {
+    fn from(val: GitlabGroup) -> Self {
+        Self {
+            id: val.id.to_string(),
+            login: val.full_path,
+            name: val.name,
+            avatar: val.avatar_url.unwrap_or_default(),
+            link: val.web_url,
+        }
+    }
+}
+
+impl Endpoint for GitlabGroup {
+    type Output = GitlabGroup;
+}
+
+impl Endpoint for Vec<GitlabGroup> {
+    type Output = Vec<GitlabGroup>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabMember {
+    pub username: String,
+    pub avatar_url: Option<String>,
+    pub access_level: u32,
+}
+
+impl From<GitlabMember> for Member {
+    fn from(val: GitlabMember) -> Self {
+        Self {
+            login: val.username,
+            avatar: val.avatar_url.unwrap_or_default(),
+            role: match val.access_level >= GITLAB_ACCESS_LEVEL_OWNER {
+                true => Role::Admin,
+                false => Role::Member,
+            },
+        }
+    }
+}
+
+impl Endpoint for Vec<GitlabMember> {
+    type Output = Vec<GitlabMember>;
+}
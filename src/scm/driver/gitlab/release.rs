@@ -0,0 +1,330 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use reqwest::multipart::Form;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    GITLAB_PATH_RELEASE, GITLAB_PATH_RELEASES, GITLAB_PATH_RELEASE_LATEST, GITLAB_PATH_RELEASE_LINKS,
+    GITLAB_PATH_UPLOADS,
+};
+use super::utils::{convert_list_options, encode};
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{Client, HTTPError, Payload};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::release::{Asset, Release, ReleaseInput, ReleaseService};
+
+/// GitLab identifies releases by their tag, so the tag is used as the release id.
+pub struct GitlabReleaseService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl ReleaseService for GitlabReleaseService {
+    /// List releases, sorted by the release date.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/releases/#list-releases
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/releases
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Release>, SCMError> {
+        let path = GITLAB_PATH_RELEASES.replace("{repo}", &encode(repo));
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GitlabRelease>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(releases) = res.data {
+            return Ok(releases.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Get a release by a tag name.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/releases/#get-a-release-by-a-tag-name
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/releases/v1.0.0
+    async fn find_by_tag(&self, repo: &str, tag: &str) -> Result<Option<Release>, SCMError> {
+        let path = GITLAB_PATH_RELEASE
+            .replace("{repo}", &encode(repo))
+            .replace("{tag}", &encode(tag));
+        let res = self
+            .client
+            .get::<GitlabRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Get the latest release, upcoming releases are skipped.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/releases/#get-the-latest-release
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/releases/permalink/latest
+    async fn latest(&self, repo: &str) -> Result<Option<Release>, SCMError> {
+        let path = GITLAB_PATH_RELEASE_LATEST.replace("{repo}", &encode(repo));
+        let res = self
+            .client
+            .get::<GitlabRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Create a release, GitLab has no drafts or prereleases so those flags are ignored.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/releases/#create-a-release
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/releases
+    async fn create(&self, repo: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        let path = GITLAB_PATH_RELEASES.replace("{repo}", &encode(repo));
+        let body = GitlabReleaseInput {
+            tag_name: Some(input.tag.clone()),
+            name: input.title.clone(),
+            description: input.description.clone(),
+            reference: input.target.clone(),
+        };
+        let res = self
+            .client
+            .post::<GitlabRelease, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Update a release, the tag of a release cannot be changed.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/releases/#update-a-release
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/releases/v1.0.0
+    async fn update(&self, repo: &str, id: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        let path = GITLAB_PATH_RELEASE
+            .replace("{repo}", &encode(repo))
+            .replace("{tag}", &encode(id));
+        let body = GitlabReleaseInput {
+            tag_name: None,
+            name: input.title.clone(),
+            description: input.description.clone(),
+            reference: None,
+        };
+        let res = self
+            .client
+            .put::<GitlabRelease, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Delete a release, the tag is kept.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/releases/#delete-a-release
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/releases/v1.0.0
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError> {
+        let path = GITLAB_PATH_RELEASE
+            .replace("{repo}", &encode(repo))
+            .replace("{tag}", &encode(id));
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+
+    /// List the links of a release, GitLab release assets are links to files.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/releases/links.html#list-links-of-a-release
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/releases/v1.0.0/assets/links
+    async fn list_assets(&self, repo: &str, id: &str) -> Result<Vec<Asset>, SCMError> {
+        let path = GITLAB_PATH_RELEASE_LINKS
+            .replace("{repo}", &encode(repo))
+            .replace("{tag}", &encode(id));
+        let res = self
+            .client
+            .get::<Vec<GitlabReleaseLink>>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(links) = res.data {
+            return Ok(links.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Uploads the file to the project, then links it to the release.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/project_markdown_uploads.html#upload-a-file
+    /// Docs: https://docs.gitlab.com/ee/api/releases/links.html#create-a-release-link
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/uploads
+    async fn upload_asset(
+        &self,
+        repo: &str,
+        id: &str,
+        name: &str,
+        content_type: &str,
+        data: Payload,
+    ) -> Result<Asset, SCMError> {
+        let path = GITLAB_PATH_UPLOADS.replace("{repo}", &encode(repo));
+        let part = data
+            .into_part()
+            .file_name(name.to_string())
+            .mime_str(content_type)
+            .map_err(|e| SCMError::ClientError(HTTPError::ReqwestError(e)))?;
+        let res = self
+            .client
+            .post_multipart::<GitlabUpload>(&path, Form::new().part("file", part))
+            .await
+            .map_err(SCMError::ClientError)?;
+        let upload = match res.data {
+            Some(upload) if res.status.is_success() => upload,
+            _ => return Err(SCMError::UnexpectedStatus(path, res.status)),
+        };
+
+        let path = GITLAB_PATH_RELEASE_LINKS
+            .replace("{repo}", &encode(repo))
+            .replace("{tag}", &encode(id));
+        let body = GitlabReleaseLinkInput {
+            name: name.to_string(),
+            url: self
+                .client
+                .url(&upload.full_path)
+                .map_err(SCMError::ClientError)?
+                .to_string(),
+            link_type: "package".to_string(),
+        };
+        let res = self
+            .client
+            .post::<GitlabReleaseLink, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(link) if res.status.is_success() => Ok(link.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabRelease {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub upcoming_release: bool,
+    pub created_at: String,
+    pub released_at: Option<String>,
+    #[serde(rename = "_links")]
+    pub links: Option<GitlabReleaseLinks>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabReleaseLinks {
+    #[serde(rename = "self")]
+    pub web: Option<String>,
+}
+
+impl From<GitlabRelease> for Release {
+    fn from(val: GitlabRelease) -> Self {
+        Self {
+            id: val.tag_name.clone(),
+            tag: val.tag_name,
+            title: val.name.unwrap_or_default(),
+            description: val.description.unwrap_or_default(),
+            draft: false,
+            prerelease: val.upcoming_release,
+            link: val.links.and_then(|v| v.web).unwrap_or_default(),
+            created: val.created_at,
+            published: val.released_at,
+        }
+    }
+}
+
+impl Endpoint for GitlabRelease {
+    type Output = GitlabRelease;
+}
+
+impl Endpoint for Vec<GitlabRelease> {
+    type Output = Vec<GitlabRelease>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabReleaseInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_name: Option<String>,
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabReleaseLink {
+    pub id: u64,
+    pub name: String,
+    pub url: String,
+    pub direct_asset_url: Option<String>,
+}
+
+impl From<GitlabReleaseLink> for Asset {
+    fn from(val: GitlabReleaseLink) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val.name,
+            size: None,
+            link: val.direct_asset_url.unwrap_or(val.url),
+        }
+    }
+}
+
+impl Endpoint for GitlabReleaseLink {
+    type Output = GitlabReleaseLink;
+}
+
+impl Endpoint for Vec<GitlabReleaseLink> {
+    type Output = Vec<GitlabReleaseLink>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GitlabReleaseLinkInput {
+    pub name: String,
+    pub url: String,
+    pub link_type: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabUpload {
+    pub url: String,
+    pub full_path: String,
+}
+
+impl Endpoint for GitlabUpload {
+    type Output = GitlabUpload;
+}
@@ -12,15 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
+use super::org::GitlabMember;
 use super::utils::encode;
 use crate::http::{endpoint::Endpoint, Client};
 use crate::scm::constants::Visibility;
-use crate::scm::driver::gitlab::constants::GITLAB_PATH_REPOS;
+use crate::scm::driver::gitlab::constants::{
+    GITLAB_PATH_LANGUAGES, GITLAB_PATH_MEMBERS_ALL, GITLAB_PATH_REPOS,
+};
 use crate::scm::errors::SCMError;
-use crate::scm::repo::{Repository, RepositoryService};
+use crate::scm::repo::{Permission, Repository, RepositoryService};
+use crate::scm::utils;
+
+// The access levels of project members.
+const GITLAB_ACCESS_LEVEL_REPORTER: u32 = 20;
+const GITLAB_ACCESS_LEVEL_DEVELOPER: u32 = 30;
+const GITLAB_ACCESS_LEVEL_MAINTAINER: u32 = 40;
 
 pub struct GitlabRepoService {
     pub client: Client,
@@ -42,6 +54,61 @@ impl RepositoryService for GitlabRepoService {
 
         Ok(res.data.map(|v| v.into()))
     }
+
+    /// Returns the permission of a user from the project members, including the
+    /// members inherited from the groups. Guests cannot read the code of private
+    /// projects, so only reporters and above are given read access.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/members.html#list-all-members-of-a-group-or-project-including-inherited-and-invited-members
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/members/all?query=root
+    async fn permission(&self, repo: &str, user: &str) -> Result<Permission, SCMError> {
+        let path = GITLAB_PATH_MEMBERS_ALL.replace("{repo}", &encode(repo));
+        let options = Some([("query".to_string(), user.to_string())].into());
+        let res = self
+            .client
+            .get::<Vec<GitlabMember>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if res.status == StatusCode::NOT_FOUND {
+            return Ok(Permission::None);
+        }
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        let level = res
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|v| v.username == user)
+            .map(|v| v.access_level)
+            .max()
+            .unwrap_or_default();
+
+        Ok(match level {
+            l if l >= GITLAB_ACCESS_LEVEL_MAINTAINER => Permission::Admin,
+            l if l >= GITLAB_ACCESS_LEVEL_DEVELOPER => Permission::Write,
+            l if l >= GITLAB_ACCESS_LEVEL_REPORTER => Permission::Read,
+            _ => Permission::None,
+        })
+    }
+
+    /// Returns the languages of a repository, as percentages of the code.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/projects.html#languages
+    /// Example: https://gitlab.com/api/v4/projects/gitlab-org%2Fgitlab-test/languages
+    async fn languages(&self, repo: &str) -> Result<HashMap<String, f64>, SCMError> {
+        let path = GITLAB_PATH_LANGUAGES.replace("{repo}", &encode(repo));
+        let res = self
+            .client
+            .get::<GitlabLanguages>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+        utils::check_status(path, res.status)?;
+
+        Ok(res.data.unwrap_or_default())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -105,3 +172,14 @@ impl From<GitlabRepository> for Repository {
 impl Endpoint for GitlabRepository {
     type Output = GitlabRepository;
 }
+
+impl Endpoint for Vec<GitlabRepository> {
+    type Output = Vec<GitlabRepository>;
+}
+
+/// The percentage of the code of each language of a repository, keyed by the language name.
+pub struct GitlabLanguages;
+
+impl Endpoint for GitlabLanguages {
+    type Output = HashMap<String, f64>;
+}
@@ -0,0 +1,141 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GITLAB_PATH_GROUP_SEARCH, GITLAB_PATH_PROJECT_SEARCH, GITLAB_PATH_SEARCH};
+use super::repo::GitlabRepository;
+use super::utils::{convert_list_options, encode};
+use crate::http::endpoint::Endpoint;
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::repo::Repository;
+use crate::scm::search::{CodeResult, SearchCapabilities, SearchOptions, SearchScope, SearchService};
+
+pub struct GitlabSearchService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl SearchService for GitlabSearchService {
+    fn capabilities(&self) -> SearchCapabilities {
+        SearchCapabilities {
+            code: true,
+            repositories: true,
+        }
+    }
+
+    /// Search for blobs, searching across projects or groups requires the
+    /// advanced search to be enabled on the instance, it is unsupported otherwise.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/search.html#scope-blobs
+    /// Example: https://gitlab.com/api/v4/groups/gitlab-org/search?scope=blobs&search=filename:.amp.toml
+    async fn code(&self, opts: &SearchOptions, list: ListOptions) -> Result<Vec<CodeResult>, SCMError> {
+        let mut search = opts.query.clone();
+        if let Some(path) = &opts.path {
+            search = format!("{search} path:{path}").trim().to_string();
+        }
+        let mut options = convert_list_options(list);
+        options.insert("scope".to_string(), "blobs".to_string());
+        options.insert("search".to_string(), search);
+        let path = path(&opts.scope);
+        let res = self
+            .client
+            .get::<Vec<GitlabBlob>>(&path, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        // GitLab rejects the scope with `400 Bad Request` without advanced search.
+        if res.status == StatusCode::BAD_REQUEST && !matches!(opts.scope, SearchScope::Repository(_)) {
+            return Err(SCMError::Unsupported(
+                "GitLab code search across projects without advanced search".to_string(),
+            ));
+        }
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        if let Some(blobs) = res.data {
+            let repo = match &opts.scope {
+                SearchScope::Repository(repo) => Some(repo.clone()),
+                _ => None,
+            };
+            return Ok(blobs
+                .into_iter()
+                .map(|v| CodeResult {
+                    repo: repo.clone().unwrap_or_else(|| v.project_id.to_string()),
+                    name: v.filename,
+                    path: v.path,
+                    sha: v.id.unwrap_or_default(),
+                    link: String::new(),
+                })
+                .collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Search for projects.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/search.html#scope-projects
+    /// Example: https://gitlab.com/api/v4/groups/gitlab-org/search?scope=projects&search=gitlab
+    async fn repositories(
+        &self,
+        opts: &SearchOptions,
+        list: ListOptions,
+    ) -> Result<Vec<Repository>, SCMError> {
+        let mut options = convert_list_options(list);
+        options.insert("scope".to_string(), "projects".to_string());
+        options.insert("search".to_string(), opts.query.clone());
+        let path = path(&opts.scope);
+        let res = self
+            .client
+            .get::<Vec<GitlabRepository>>(&path, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        if let Some(repos) = res.data {
+            return Ok(repos.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+}
+
+/// Returns the search endpoint of the scope.
+fn path(scope: &SearchScope) -> String {
+    match scope {
+        SearchScope::All => GITLAB_PATH_SEARCH.to_string(),
+        SearchScope::Repository(repo) => GITLAB_PATH_PROJECT_SEARCH.replace("{repo}", &encode(repo)),
+        SearchScope::Organization(group) => GITLAB_PATH_GROUP_SEARCH.replace("{group}", &encode(group)),
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabBlob {
+    pub filename: String,
+    pub path: String,
+    pub id: Option<String>,
+    pub project_id: u64,
+}
+
+impl Endpoint for Vec<GitlabBlob> {
+    type Output = Vec<GitlabBlob>;
+}
@@ -0,0 +1,144 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GITLAB_PATH_USER, GITLAB_PATH_USERS, GITLAB_PATH_USER_EMAILS};
+use super::utils::convert_list_options;
+use crate::http::{endpoint::Endpoint, Client};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::user::{Email, User, UserService};
+
+pub struct GitlabUserService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl UserService for GitlabUserService {
+    /// Get the current user.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/users.html#for-non-administrator-users
+    /// Example: https://gitlab.com/api/v4/user
+    async fn find(&self) -> Result<Option<User>, SCMError> {
+        let res = self
+            .client
+            .get::<GitlabUser>(GITLAB_PATH_USER, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// List users by username, the username is unique so at most one user is returned.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/users.html#for-non-administrator-users-1
+    /// Example: https://gitlab.com/api/v4/users?username=gitlab-bot
+    async fn find_login(&self, login: &str) -> Result<Option<User>, SCMError> {
+        let options = HashMap::from([("username".to_string(), login.to_string())]);
+        let res = self
+            .client
+            .get::<Vec<GitlabUser>>(GITLAB_PATH_USERS, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.and_then(|v| v.into_iter().next()).map(|v| v.into()))
+    }
+
+    /// List emails of the current user, the list does not include the primary
+    /// email, so it is prepended from the current user.
+    ///
+    /// Docs: https://docs.gitlab.com/ee/api/users.html#list-emails
+    /// Example: https://gitlab.com/api/v4/user/emails
+    async fn list_emails(&self, opts: ListOptions) -> Result<Vec<Email>, SCMError> {
+        let first = opts.page <= 1;
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GitlabEmail>>(GITLAB_PATH_USER_EMAILS, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        let mut emails = vec![];
+        if first {
+            if let Some(email) = self.find().await?.and_then(|v| v.email) {
+                emails.push(Email {
+                    value: email,
+                    primary: true,
+                    verified: true,
+                });
+            }
+        }
+        if let Some(others) = res.data {
+            emails.extend(others.into_iter().map(|v| v.into()));
+        }
+
+        Ok(emails)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabUser {
+    pub id: u64,
+    pub username: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub public_email: Option<String>,
+    pub avatar_url: Option<String>,
+    pub web_url: String,
+}
+
+impl From<GitlabUser> for User {
+    fn from(val: GitlabUser) -> Self {
+        Self {
+            id: val.id.to_string(),
+            login: val.username,
+            name: val.name,
+            email: val.email.or(val.public_email).filter(|v| !v.is_empty()),
+            avatar: val.avatar_url.unwrap_or_default(),
+            link: val.web_url,
+        }
+    }
+}
+
+impl Endpoint for GitlabUser {
+    type Output = GitlabUser;
+}
+
+impl Endpoint for Vec<GitlabUser> {
+    type Output = Vec<GitlabUser>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitlabEmail {
+    pub email: String,
+    pub confirmed_at: Option<String>,
+}
+
+impl From<GitlabEmail> for Email {
+    fn from(val: GitlabEmail) -> Self {
+        Self {
+            value: val.email,
+            primary: false,
+            verified: val.confirmed_at.is_some(),
+        }
+    }
+}
+
+impl Endpoint for Vec<GitlabEmail> {
+    type Output = Vec<GitlabEmail>;
+}
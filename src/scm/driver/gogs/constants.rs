@@ -13,11 +13,39 @@
 // limitations under the License.
 
 pub const GOGS_ENDPOINT: &str = "https://gogs.io";
+pub const GITEA_ENDPOINT: &str = "https://gitea.com";
+
+// The token endpoint of Gitea OAuth applications, relative to the server address
+pub const GOGS_PATH_OAUTH_TOKEN: &str = "/login/oauth/access_token";
 
 // REST API paths (see https://gogs.io/docs/api)
 pub const GOGS_PATH_CONTENTS: &str = "/api/v1/repos/{repo}/contents/{file}";
 pub const GOGS_PATH_BRANCHES: &str = "/api/v1/repos/{repo}/branches";
+pub const GOGS_PATH_BRANCH: &str = "/api/v1/repos/{repo}/branches/{branch}";
 pub const GOGS_PATH_TAGS: &str = "/api/v1/repos/{repo}/tags";
+pub const GOGS_PATH_TAG: &str = "/api/v1/repos/{repo}/tags/{tag}";
 pub const GOGS_PATH_COMMITS: &str = "/api/v1/repos/{repo}/git/commits/{reference}";
 pub const GOGS_PATH_GIT_TREES: &str = "/api/v1/repos/{repo}/git/trees/{tree_sha}";
 pub const GOGS_PATH_REPOS: &str = "/api/v1/repos/{repo}";
+pub const GOGS_PATH_ARCHIVE: &str = "/api/v1/repos/{repo}/archive/{reference}.{format}";
+pub const GOGS_PATH_RAW: &str = "/api/v1/repos/{repo}/raw/{reference}/{file}";
+pub const GOGS_PATH_USER: &str = "/api/v1/user";
+pub const GOGS_PATH_USERS: &str = "/api/v1/users/{login}";
+pub const GOGS_PATH_USER_EMAILS: &str = "/api/v1/user/emails";
+pub const GOGS_PATH_USER_ORGS: &str = "/api/v1/user/orgs";
+pub const GOGS_PATH_ORGS: &str = "/api/v1/orgs/{org}";
+pub const GOGS_PATH_ORG_MEMBERS: &str = "/api/v1/orgs/{org}/members";
+pub const GOGS_PATH_ISSUES: &str = "/api/v1/repos/{repo}/issues";
+pub const GOGS_PATH_ISSUE: &str = "/api/v1/repos/{repo}/issues/{number}";
+pub const GOGS_PATH_ISSUE_COMMENTS: &str = "/api/v1/repos/{repo}/issues/{number}/comments";
+pub const GOGS_PATH_ISSUE_COMMENT: &str = "/api/v1/repos/{repo}/issues/{number}/comments/{id}";
+pub const GOGS_PATH_RELEASES: &str = "/api/v1/repos/{repo}/releases";
+pub const GOGS_PATH_RELEASE: &str = "/api/v1/repos/{repo}/releases/{id}";
+pub const GOGS_PATH_RELEASE_BY_TAG: &str = "/api/v1/repos/{repo}/releases/tags/{tag}";
+pub const GOGS_PATH_RELEASE_LATEST: &str = "/api/v1/repos/{repo}/releases/latest";
+pub const GOGS_PATH_RELEASE_ASSETS: &str = "/api/v1/repos/{repo}/releases/{id}/assets";
+pub const GOGS_PATH_KEYS: &str = "/api/v1/repos/{repo}/keys";
+pub const GOGS_PATH_KEY: &str = "/api/v1/repos/{repo}/keys/{id}";
+pub const GOGS_PATH_COLLABORATOR_PERMISSION: &str = "/api/v1/repos/{repo}/collaborators/{user}/permission";
+pub const GOGS_PATH_LANGUAGES: &str = "/api/v1/repos/{repo}/languages";
+pub const GOGS_PATH_REPOS_SEARCH: &str = "/api/v1/repos/search";
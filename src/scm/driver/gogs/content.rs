@@ -18,10 +18,13 @@ use async_trait::async_trait;
 use data_encoding::BASE64_MIME as BASE64;
 use serde::{Deserialize, Serialize};
 
-use super::constants::GOGS_PATH_CONTENTS;
-use crate::http::{endpoint::Endpoint, Client};
+use super::constants::{GOGS_PATH_CONTENTS, GOGS_PATH_RAW};
+use super::repo::GogsRepoService;
+use crate::http::{endpoint::Endpoint, ByteStream, Client};
 use crate::scm::content::{Content, ContentService, File};
 use crate::scm::errors::SCMError;
+use crate::scm::lfs;
+use crate::scm::utils;
 
 pub struct GogsContentService {
     pub client: Client,
@@ -49,6 +52,30 @@ impl ContentService for GogsContentService {
         }
     }
 
+    /// Gets the raw contents of a file in a repository.
+    ///
+    /// Docs: https://gogs.io/docs/api
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/raw/main/README.md
+    async fn raw(&self, repo: &str, file: &str, reference: &str) -> Result<ByteStream, SCMError> {
+        let path = GOGS_PATH_RAW
+            .replace("{repo}", repo)
+            .replace("{reference}", reference)
+            .replace("{file}", file);
+        let options = None;
+        let res = self
+            .client
+            .get_stream(&path, options, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        let repositories = GogsRepoService {
+            client: self.client.clone(),
+        };
+        lfs::resolve(&self.client, res.stream, &repositories, repo).await
+    }
+
     /// Gets the file list of a directory in a repository.
     ///
     /// Docs: https://gogs.io/docs/api
@@ -14,15 +14,50 @@
 
 use super::content::GogsContentService;
 use super::git::GogsService;
+use super::issue::GogsIssueService;
+use super::key::GogsDeployKeyService;
+use super::org::GogsOrganizationService;
+use super::release::GogsReleaseService;
 use super::repo::GogsRepoService;
+use super::search::GogsSearchService;
+use super::user::GogsUserService;
 use crate::http::Client;
+use crate::scm::capabilities::Capabilities;
 use crate::scm::content::ContentService;
 use crate::scm::driver::DriverTrait;
+use crate::scm::errors::SCMError;
 use crate::scm::git::GitService;
+use crate::scm::issue::IssueService;
+use crate::scm::key::DeployKeyService;
+use crate::scm::org::OrganizationService;
+use crate::scm::release::ReleaseService;
 use crate::scm::repo::RepositoryService;
+use crate::scm::search::SearchService;
+use crate::scm::user::UserService;
+
+/// The server behind a `GogsDriver`, Gitea extends the API of Gogs it was
+/// forked from.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Flavour {
+    #[default]
+    Gogs,
+    Gitea,
+}
+
+impl Flavour {
+    /// Returns `SCMError::Unsupported` unless the server is Gitea, for the
+    /// operations that only Gitea has an API for.
+    pub fn require_gitea(self, operation: &str) -> Result<(), SCMError> {
+        match self {
+            Flavour::Gitea => Ok(()),
+            Flavour::Gogs => Err(SCMError::Unsupported(format!("Gogs {operation}"))),
+        }
+    }
+}
 
 pub struct GogsDriver {
     pub client: Client,
+    pub flavour: Flavour,
 }
 
 impl DriverTrait for GogsDriver {
@@ -35,6 +70,7 @@ impl DriverTrait for GogsDriver {
     fn git(&self) -> Box<dyn GitService> {
         Box::new(GogsService {
             client: self.client.clone(),
+            flavour: self.flavour,
         })
     }
 
@@ -43,6 +79,65 @@ impl DriverTrait for GogsDriver {
             client: self.client.clone(),
         })
     }
+
+    fn users(&self) -> Box<dyn UserService> {
+        Box::new(GogsUserService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn organizations(&self) -> Box<dyn OrganizationService> {
+        Box::new(GogsOrganizationService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn issues(&self) -> Box<dyn IssueService> {
+        Box::new(GogsIssueService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn releases(&self) -> Box<dyn ReleaseService> {
+        Box::new(GogsReleaseService {
+            client: self.client.clone(),
+            flavour: self.flavour,
+        })
+    }
+
+    fn deploy_keys(&self) -> Box<dyn DeployKeyService> {
+        Box::new(GogsDeployKeyService {
+            client: self.client.clone(),
+        })
+    }
+
+    fn search(&self) -> Box<dyn SearchService> {
+        Box::new(GogsSearchService {
+            client: self.client.clone(),
+        })
+    }
+
+    /// Gogs can only list releases and has no API to create and delete branches
+    /// and tags, Gitea supports all of them.
+    fn capabilities(&self) -> Capabilities {
+        let gitea = self.flavour == Flavour::Gitea;
+        Capabilities {
+            contents: true,
+            git: true,
+            git_write: gitea,
+            repositories: true,
+            users: true,
+            organizations: true,
+            issues: true,
+            releases: gitea,
+            release_assets: gitea,
+            release_delete: gitea,
+            deploy_keys: true,
+            search: self.search().capabilities(),
+            recursive_tree: false,
+            archive_path: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -58,4 +153,22 @@ mod test {
     fn return_repo_service() {
         gogs::default().unwrap().repositories();
     }
+
+    #[test]
+    fn return_capabilities() {
+        let capabilities = gogs::default().unwrap().capabilities();
+        assert!(!capabilities.recursive_tree);
+        assert!(!capabilities.git_write);
+        assert!(!capabilities.releases);
+        assert!(!capabilities.release_assets);
+        assert!(capabilities.deploy_keys);
+        assert!(!capabilities.search.code);
+        assert!(capabilities.search.repositories);
+
+        let capabilities = gogs::gitea("https://gitea.com", None).unwrap().capabilities();
+        assert!(capabilities.git_write);
+        assert!(capabilities.releases);
+        assert!(capabilities.release_assets);
+        assert!(capabilities.release_delete);
+    }
 }
@@ -16,17 +16,24 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::constants::{GOGS_PATH_BRANCHES, GOGS_PATH_COMMITS, GOGS_PATH_GIT_TREES, GOGS_PATH_TAGS};
+use super::constants::{
+    GOGS_PATH_ARCHIVE, GOGS_PATH_BRANCH, GOGS_PATH_BRANCHES, GOGS_PATH_COMMITS, GOGS_PATH_GIT_TREES,
+    GOGS_PATH_TAG, GOGS_PATH_TAGS,
+};
+use super::driver::Flavour;
 use super::utils::convert_list_options;
 use super::GogsFile;
-use crate::http::{endpoint::Endpoint, Client};
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{ByteStream, Client};
+use crate::scm::archive::ArchiveFormat;
 use crate::scm::client::ListOptions;
 use crate::scm::errors::SCMError;
-use crate::scm::git::{Commit, GitService, Reference, Signature, Tree, TreeEntry};
+use crate::scm::git::{Commit, GitService, Reference, ReferenceInput, Signature, TagInput, Tree, TreeEntry};
 use crate::scm::utils;
 
 pub struct GogsService {
     pub client: Client,
+    pub flavour: Flavour,
 }
 
 #[async_trait]
@@ -60,7 +67,7 @@ impl GitService for GogsService {
         let options = Some(convert_list_options(opts));
         let res = self
             .client
-            .get::<Vec<GogsBranch>>(&path, options)
+            .get::<Vec<GogsTag>>(&path, options)
             .await
             .map_err(SCMError::ClientError)?;
 
@@ -71,6 +78,110 @@ impl GitService for GogsService {
         Ok(vec![])
     }
 
+    /// Returns a single branch by name.
+    ///
+    /// Docs: https://gogs.io/docs/api
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/branches/main
+    async fn find_branch(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        let path = GOGS_PATH_BRANCH.replace("{repo}", repo).replace("{branch}", name);
+        let res = self
+            .client
+            .get::<GogsBranch>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| (&v).into()))
+    }
+
+    /// Returns a single tag by name, Gogs has no API for it so the tag is looked
+    /// up in the list of tags.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoGetTag
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/tags/v0.9.0
+    async fn find_tag(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError> {
+        if self.flavour == Flavour::Gogs {
+            let tags = self.list_tags(repo, ListOptions::default()).await?;
+            return Ok(tags.into_iter().find(|tag| tag.name == name));
+        }
+
+        let path = GOGS_PATH_TAG.replace("{repo}", repo).replace("{tag}", name);
+        let res = self
+            .client
+            .get::<GogsTag>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| (&v).into()))
+    }
+
+    /// Creates a branch pointing to the commit, only supported by Gitea.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoCreateBranch
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/branches
+    async fn create_branch(&self, repo: &str, input: &ReferenceInput) -> Result<Reference, SCMError> {
+        self.flavour.require_gitea("branch creation")?;
+        let path = GOGS_PATH_BRANCHES.replace("{repo}", repo);
+        let body = GogsBranchInput {
+            new_branch_name: input.name.clone(),
+            old_ref_name: input.sha.clone(),
+        };
+        let res = self
+            .client
+            .post::<GogsBranch, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(branch) if res.status.is_success() => Ok((&branch).into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a branch by name, only supported by Gitea.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoDeleteBranch
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/branches/feature
+    async fn delete_branch(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        self.flavour.require_gitea("branch deletion")?;
+        let path = GOGS_PATH_BRANCH.replace("{repo}", repo).replace("{branch}", name);
+        self.delete(&path).await
+    }
+
+    /// Creates a tag pointing to the commit, only supported by Gitea. The tag is
+    /// annotated when a message is given.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoCreateTag
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/tags
+    async fn create_tag(&self, repo: &str, input: &TagInput) -> Result<Reference, SCMError> {
+        self.flavour.require_gitea("tag creation")?;
+        let path = GOGS_PATH_TAGS.replace("{repo}", repo);
+        let body = GogsTagInput {
+            tag_name: input.name.clone(),
+            target: input.sha.clone(),
+            message: input.message.clone(),
+        };
+        let res = self
+            .client
+            .post::<GogsTag, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(tag) if res.status.is_success() => Ok((&tag).into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a tag by name, only supported by Gitea.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoDeleteTag
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/tags/v0.9.0
+    async fn delete_tag(&self, repo: &str, name: &str) -> Result<(), SCMError> {
+        self.flavour.require_gitea("tag deletion")?;
+        let path = GOGS_PATH_TAG.replace("{repo}", repo).replace("{tag}", name);
+        self.delete(&path).await
+    }
+
     /// Returns the contents of a single commit reference.
     ///
     /// Docs: https://gogs.io/docs/api
@@ -112,6 +223,49 @@ impl GitService for GogsService {
 
         Ok(res.data.map(|v| v.into()))
     }
+
+    /// Downloads an archive of a repository at the reference.
+    ///
+    /// Docs: https://gogs.io/docs/api
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/archive/master.tar.gz
+    async fn archive(
+        &self,
+        repo: &str,
+        reference: &str,
+        format: ArchiveFormat,
+        _path: Option<&str>,
+    ) -> Result<ByteStream, SCMError> {
+        let path = GOGS_PATH_ARCHIVE
+            .replace("{repo}", repo)
+            .replace("{format}", format.extension())
+            .replace("{reference}", reference);
+        let res = self
+            .client
+            .get_stream(&path, None, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        utils::check_status(path, res.status)?;
+
+        Ok(res.stream)
+    }
+}
+
+impl GogsService {
+    /// Deletes the resource at the path, e.g. a branch or a tag.
+    async fn delete(&self, path: &str) -> Result<(), SCMError> {
+        let res = self
+            .client
+            .delete::<Empty>(path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path.to_string(), res.status));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -198,6 +352,49 @@ impl Endpoint for Vec<GogsBranch> {
     type Output = Vec<GogsBranch>;
 }
 
+impl Endpoint for GogsBranch {
+    type Output = GogsBranch;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GogsBranchInput {
+    pub new_branch_name: String,
+    pub old_ref_name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsTag {
+    pub name: String,
+    pub message: Option<String>,
+    pub commit: GogsSimpleCommit,
+}
+
+impl From<&GogsTag> for Reference {
+    fn from(val: &GogsTag) -> Self {
+        Self {
+            name: utils::trim_ref(&val.name),
+            path: utils::expand_ref(&val.name, "refs/tags/"),
+            sha: val.commit.sha.clone(),
+        }
+    }
+}
+
+impl Endpoint for GogsTag {
+    type Output = GogsTag;
+}
+
+impl Endpoint for Vec<GogsTag> {
+    type Output = Vec<GogsTag>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GogsTagInput {
+    pub tag_name: String,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
 impl Endpoint for GogsCommit {
     type Output = GogsCommit;
 }
@@ -0,0 +1,316 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    GOGS_PATH_ISSUE, GOGS_PATH_ISSUES, GOGS_PATH_ISSUE_COMMENT, GOGS_PATH_ISSUE_COMMENTS,
+};
+use super::user::GogsUser;
+use super::utils::convert_list_options;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::issue::{Comment, CommentInput, Issue, IssueInput, IssueService, Thread};
+
+pub struct GogsIssueService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl IssueService for GogsIssueService {
+    /// Returns an issue by number.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues#get-a-single-issue
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues/1347
+    async fn find(&self, repo: &str, number: u64) -> Result<Option<Issue>, SCMError> {
+        let path = GOGS_PATH_ISSUE
+            .replace("{repo}", repo)
+            .replace("{number}", &number.to_string());
+        let res = self
+            .client
+            .get::<GogsIssue>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns a list of the open issues, pull requests listed as issues are filtered out.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues#list-issues-for-a-repository
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Issue>, SCMError> {
+        let path = GOGS_PATH_ISSUES.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GogsIssue>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(issues) = res.data {
+            return Ok(issues
+                .into_iter()
+                .filter(|v| v.pull_request.is_none())
+                .map(|v| v.into())
+                .collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates an issue, the labels are ignored since the API takes label ids.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues#create-an-issue
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues
+    async fn create(&self, repo: &str, input: &IssueInput) -> Result<Issue, SCMError> {
+        let path = GOGS_PATH_ISSUES.replace("{repo}", repo);
+        let body = GogsIssueInput {
+            title: input.title.clone(),
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .post::<GogsIssue, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(issue) if res.status.is_success() => Ok(issue.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Closes an issue by number.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues#edit-an-issue
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues/1347
+    async fn close(&self, repo: &str, number: u64) -> Result<(), SCMError> {
+        let path = GOGS_PATH_ISSUE
+            .replace("{repo}", repo)
+            .replace("{number}", &number.to_string());
+        let body = GogsIssueState {
+            state: "closed".to_string(),
+        };
+        let res = self
+            .client
+            .patch::<Empty, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a list of comments, pull requests share the comments API of issues.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues/Comments#list-comments-on-an-issue
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues/1347/comments
+    async fn list_comments(
+        &self,
+        repo: &str,
+        thread: Thread,
+        opts: ListOptions,
+    ) -> Result<Vec<Comment>, SCMError> {
+        let path = GOGS_PATH_ISSUE_COMMENTS
+            .replace("{repo}", repo)
+            .replace("{number}", &thread.number().to_string());
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GogsComment>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(comments) = res.data {
+            return Ok(comments.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates a comment on the issue or pull request.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues/Comments#create-a-comment
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues/1347/comments
+    async fn create_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = GOGS_PATH_ISSUE_COMMENTS
+            .replace("{repo}", repo)
+            .replace("{number}", &thread.number().to_string());
+        let body = GogsCommentInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .post::<GogsComment, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(comment) if res.status.is_success() => Ok(comment.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Updates a comment on the issue or pull request.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues/Comments#edit-a-comment
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues/1347/comments/1
+    async fn update_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        id: u64,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError> {
+        let path = GOGS_PATH_ISSUE_COMMENT
+            .replace("{repo}", repo)
+            .replace("{number}", &thread.number().to_string())
+            .replace("{id}", &id.to_string());
+        let body = GogsCommentInput {
+            body: input.body.clone(),
+        };
+        let res = self
+            .client
+            .patch::<GogsComment, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(comment) if res.status.is_success() => Ok(comment.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a comment on the issue or pull request.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Issues/Comments#delete-a-comment
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/issues/1347/comments/1
+    async fn delete_comment(&self, repo: &str, thread: Thread, id: u64) -> Result<(), SCMError> {
+        let path = GOGS_PATH_ISSUE_COMMENT
+            .replace("{repo}", repo)
+            .replace("{number}", &thread.number().to_string())
+            .replace("{id}", &id.to_string());
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String,
+    pub labels: Vec<GogsLabel>,
+    pub user: GogsUser,
+    pub html_url: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub pull_request: Option<serde_json::Value>,
+}
+
+impl From<GogsIssue> for Issue {
+    fn from(val: GogsIssue) -> Self {
+        Self {
+            number: val.number,
+            title: val.title,
+            body: val.body.unwrap_or_default(),
+            labels: val.labels.into_iter().map(|v| v.name).collect(),
+            closed: val.state == "closed",
+            author: val.user.into(),
+            link: val.html_url.unwrap_or_default(),
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for GogsIssue {
+    type Output = GogsIssue;
+}
+
+impl Endpoint for Vec<GogsIssue> {
+    type Output = Vec<GogsIssue>;
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GogsIssueInput {
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GogsIssueState {
+    pub state: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsComment {
+    pub id: u64,
+    pub body: Option<String>,
+    pub user: GogsUser,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<GogsComment> for Comment {
+    fn from(val: GogsComment) -> Self {
+        Self {
+            id: val.id,
+            body: val.body.unwrap_or_default(),
+            author: val.user.into(),
+            created: val.created_at,
+            updated: val.updated_at,
+        }
+    }
+}
+
+impl Endpoint for GogsComment {
+    type Output = GogsComment;
+}
+
+impl Endpoint for Vec<GogsComment> {
+    type Output = Vec<GogsComment>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GogsCommentInput {
+    pub body: String,
+}
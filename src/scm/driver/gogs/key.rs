@@ -0,0 +1,129 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GOGS_PATH_KEY, GOGS_PATH_KEYS};
+use super::utils::convert_list_options;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::key::{DeployKey, DeployKeyInput, DeployKeyService};
+
+pub struct GogsDeployKeyService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl DeployKeyService for GogsDeployKeyService {
+    /// Returns a list of deploy keys, Gogs deploy keys are always read-only.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/blob/master/Repositories/Deploy%20Keys.md#list-deploy-keys
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/keys
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<DeployKey>, SCMError> {
+        let path = GOGS_PATH_KEYS.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GogsDeployKey>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(keys) = res.data {
+            return Ok(keys.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Creates a deploy key.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/blob/master/Repositories/Deploy%20Keys.md#add-a-new-deploy-key
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/keys
+    async fn create(&self, repo: &str, input: &DeployKeyInput) -> Result<DeployKey, SCMError> {
+        let path = GOGS_PATH_KEYS.replace("{repo}", repo);
+        let body = GogsDeployKeyInput {
+            title: input.title.clone(),
+            key: input.key.clone(),
+            read_only: input.read_only,
+        };
+        let res = self
+            .client
+            .post::<GogsDeployKey, _>(&path, &body)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(key) if res.status.is_success() => Ok(key.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a deploy key by id.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/blob/master/Repositories/Deploy%20Keys.md#remove-a-deploy-key
+    /// Example: https://gogs.io/api/v1/repos/gogs/gogs/keys/1
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError> {
+        let path = GOGS_PATH_KEY.replace("{repo}", repo).replace("{id}", id);
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsDeployKey {
+    pub id: u64,
+    pub title: String,
+    pub key: String,
+    pub read_only: Option<bool>,
+    pub created_at: String,
+}
+
+impl From<GogsDeployKey> for DeployKey {
+    fn from(val: GogsDeployKey) -> Self {
+        Self {
+            id: val.id.to_string(),
+            title: val.title,
+            key: val.key,
+            read_only: val.read_only.unwrap_or(true),
+            created: val.created_at,
+        }
+    }
+}
+
+impl Endpoint for GogsDeployKey {
+    type Output = GogsDeployKey;
+}
+
+impl Endpoint for Vec<GogsDeployKey> {
+    type Output = Vec<GogsDeployKey>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GogsDeployKeyInput {
+    pub title: String,
+    pub key: String,
+    pub read_only: bool,
+}
@@ -16,15 +16,22 @@ pub mod constants;
 pub mod content;
 pub mod driver;
 pub mod git;
+pub mod issue;
+pub mod key;
+pub mod org;
 pub mod pr;
+pub mod release;
 pub mod repo;
+pub mod search;
+pub mod user;
 mod utils;
 
 use self::constants::GOGS_ENDPOINT;
 use self::pr::GogsFile;
 use super::Driver;
-use crate::http::Client;
-use crate::scm::driver::gogs::driver::GogsDriver;
+use crate::config::Credential;
+use crate::http::{Auth, Client};
+use crate::scm::driver::gogs::driver::{Flavour, GogsDriver};
 use crate::scm::errors::SCMError;
 
 /// Returns a new Gogs driver using the default gogs.io address.
@@ -36,18 +43,55 @@ pub fn default() -> Result<Driver, SCMError> {
 /// Returns a new Gogs driver.
 #[inline]
 pub fn new(url: &str, token: Option<String>) -> Result<Driver, SCMError> {
-    from(Client::new(url, token).map_err(SCMError::ClientError)?)
+    from(Client::with_auth(url, token.map(Auth::Token).unwrap_or_default()).map_err(SCMError::ClientError)?)
+}
+
+/// Returns a new Gogs driver authenticated with the credential.
+pub fn with_credential(url: &str, credential: &impl Credential) -> Result<Driver, SCMError> {
+    from(Client::with_auth(url, auth(credential)).map_err(SCMError::ClientError)?)
+}
+
+/// Returns the authentication of the credential, Gogs and Gitea expect access
+/// tokens as `Authorization: token <token>`.
+pub fn auth(credential: &impl Credential) -> Auth {
+    super::auth(credential, Auth::Token)
+}
+
+/// Returns a new Gitea driver, the operations Gogs has no API for are supported.
+#[inline]
+pub fn gitea(url: &str, token: Option<String>) -> Result<Driver, SCMError> {
+    from_gitea(
+        Client::with_auth(url, token.map(Auth::Token).unwrap_or_default()).map_err(SCMError::ClientError)?,
+    )
+}
+
+/// Returns a new Gitea driver authenticated with the credential.
+pub fn with_gitea_credential(url: &str, credential: &impl Credential) -> Result<Driver, SCMError> {
+    from_gitea(Client::with_auth(url, auth(credential)).map_err(SCMError::ClientError)?)
 }
 
 /// Returns a new Gogs driver using the given client.
 pub fn from(client: Client) -> Result<Driver, SCMError> {
-    Ok(Driver::Gogs(GogsDriver { client }))
+    Ok(Driver::Gogs(GogsDriver {
+        client,
+        flavour: Flavour::Gogs,
+    }))
+}
+
+/// Returns a new Gitea driver using the given client.
+pub fn from_gitea(client: Client) -> Result<Driver, SCMError> {
+    Ok(Driver::Gogs(GogsDriver {
+        client,
+        flavour: Flavour::Gitea,
+    }))
 }
 
 #[cfg(test)]
 mod test {
     use super::constants::GOGS_ENDPOINT;
-    use crate::{http::Client, scm::driver::gogs};
+    use crate::config::RepositoryCredential;
+    use crate::http::{Auth, Client};
+    use crate::scm::driver::gogs;
 
     #[test]
     fn create_gogs_driver() {
@@ -63,4 +107,24 @@ mod test {
     fn create_gogs_enterprise_driver() {
         let _driver = gogs::new("https://gogs.company.com", None);
     }
+
+    #[test]
+    fn select_auth_from_credential() {
+        let mut credential = RepositoryCredential {
+            token: Some("secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(gogs::auth(&credential), Auth::Token("secret".to_string()));
+
+        credential.token = None;
+        credential.username = Some("gogs".to_string());
+        credential.password = Some("password".to_string());
+        assert_eq!(
+            gogs::auth(&credential),
+            Auth::Basic {
+                username: "gogs".to_string(),
+                password: Some("password".to_string()),
+            }
+        );
+    }
 }
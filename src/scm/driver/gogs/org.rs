@@ -0,0 +1,129 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GOGS_PATH_ORGS, GOGS_PATH_ORG_MEMBERS, GOGS_PATH_USER_ORGS};
+use super::user::GogsUser;
+use super::utils::convert_list_options;
+use crate::http::{endpoint::Endpoint, Client};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::org::{Member, Organization, OrganizationService, Role};
+
+pub struct GogsOrganizationService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl OrganizationService for GogsOrganizationService {
+    /// Returns the organizations of the authenticated user.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Organizations#list-your-organizations
+    /// Example: https://gogs.io/api/v1/user/orgs
+    async fn list(&self, opts: ListOptions) -> Result<Vec<Organization>, SCMError> {
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GogsOrganization>>(GOGS_PATH_USER_ORGS, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(orgs) = res.data {
+            return Ok(orgs.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Returns an organization by name.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Organizations#get-an-organization
+    /// Example: https://gogs.io/api/v1/orgs/gogs
+    async fn find(&self, name: &str) -> Result<Option<Organization>, SCMError> {
+        let path = GOGS_PATH_ORGS.replace("{org}", name);
+        let res = self
+            .client
+            .get::<GogsOrganization>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the members of an organization, this is part of the Gitea-compatible
+    /// API which does not tell the role of the members.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/organization/orgListMembers
+    /// Example: https://gitea.com/api/v1/orgs/gitea/members
+    async fn list_members(&self, name: &str, opts: ListOptions) -> Result<Vec<Member>, SCMError> {
+        let path = GOGS_PATH_ORG_MEMBERS.replace("{org}", name);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GogsUser>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(members) = res.data {
+            return Ok(members
+                .into_iter()
+                .map(|v| Member {
+                    login: v.login,
+                    avatar: v.avatar_url.unwrap_or_default(),
+                    role: Role::Undefined,
+                })
+                .collect());
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsOrganization {
+    pub id: u64,
+    pub username: String,
+    pub full_name: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+impl From<GogsOrganization> for Organization {
+    fn from(val: GogsOrganization) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val
+                .full_name
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| val.username.clone()),
+            login: val.username,
+            avatar: val.avatar_url.unwrap_or_default(),
+            // the API does not return the web address of organizations.
+            link: String::new(),
+        }
+    }
+}
+
+impl Endpoint for GogsOrganization {
+    type Output = GogsOrganization;
+}
+
+impl Endpoint for Vec<GogsOrganization> {
+    type Output = Vec<GogsOrganization>;
+}
+
+impl Endpoint for Vec<GogsUser> {
+    type Output = Vec<GogsUser>;
+}
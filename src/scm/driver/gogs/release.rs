@@ -0,0 +1,293 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use reqwest::multipart::Form;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{
+    GOGS_PATH_RELEASE, GOGS_PATH_RELEASES, GOGS_PATH_RELEASE_ASSETS, GOGS_PATH_RELEASE_BY_TAG,
+    GOGS_PATH_RELEASE_LATEST,
+};
+use super::driver::Flavour;
+use super::utils::convert_list_options;
+use crate::http::endpoint::{Empty, Endpoint};
+use crate::http::{Client, HTTPError, Payload};
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::release::{Asset, Release, ReleaseInput, ReleaseService};
+
+pub struct GogsReleaseService {
+    pub client: Client,
+    pub flavour: Flavour,
+}
+
+#[async_trait]
+impl ReleaseService for GogsReleaseService {
+    /// Returns a list of releases, Gogs only supports listing releases so the
+    /// other methods are only supported by Gitea.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoListReleases
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Release>, SCMError> {
+        let path = GOGS_PATH_RELEASES.replace("{repo}", repo);
+        let options = Some(convert_list_options(opts));
+        let res = self
+            .client
+            .get::<Vec<GogsRelease>>(&path, options)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(releases) = res.data {
+            return Ok(releases.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Finds a release by tag.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoGetReleaseByTag
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases/tags/v0.9.0
+    async fn find_by_tag(&self, repo: &str, tag: &str) -> Result<Option<Release>, SCMError> {
+        self.flavour.require_gitea("release lookup")?;
+        let path = GOGS_PATH_RELEASE_BY_TAG
+            .replace("{repo}", repo)
+            .replace("{tag}", tag);
+        let res = self
+            .client
+            .get::<GogsRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Returns the latest published full release, drafts and prereleases are skipped.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoGetLatestRelease
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases/latest
+    async fn latest(&self, repo: &str) -> Result<Option<Release>, SCMError> {
+        self.flavour.require_gitea("release lookup")?;
+        let path = GOGS_PATH_RELEASE_LATEST.replace("{repo}", repo);
+        let res = self
+            .client
+            .get::<GogsRelease>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        Ok(res.data.map(|v| v.into()))
+    }
+
+    /// Creates a release, the tag is created from the target if it does not exist.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoCreateRelease
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases
+    async fn create(&self, repo: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        self.flavour.require_gitea("release creation")?;
+        let path = GOGS_PATH_RELEASES.replace("{repo}", repo);
+        let res = self
+            .client
+            .post::<GogsRelease, _>(&path, &GogsReleaseInput::from(input))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Updates a release by id.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoEditRelease
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases/1
+    async fn update(&self, repo: &str, id: &str, input: &ReleaseInput) -> Result<Release, SCMError> {
+        self.flavour.require_gitea("release update")?;
+        let path = GOGS_PATH_RELEASE.replace("{repo}", repo).replace("{id}", id);
+        let res = self
+            .client
+            .patch::<GogsRelease, _>(&path, &GogsReleaseInput::from(input))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(release) if res.status.is_success() => Ok(release.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+
+    /// Deletes a release by id.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoDeleteRelease
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases/1
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError> {
+        self.flavour.require_gitea("release deletion")?;
+        let path = GOGS_PATH_RELEASE.replace("{repo}", repo).replace("{id}", id);
+        let res = self
+            .client
+            .delete::<Empty>(&path)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a list of the assets of a release.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoListReleaseAttachments
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases/1/assets
+    async fn list_assets(&self, repo: &str, id: &str) -> Result<Vec<Asset>, SCMError> {
+        self.flavour.require_gitea("release assets")?;
+        let path = GOGS_PATH_RELEASE_ASSETS
+            .replace("{repo}", repo)
+            .replace("{id}", id);
+        let res = self
+            .client
+            .get::<Vec<GogsAsset>>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if let Some(assets) = res.data {
+            return Ok(assets.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+
+    /// Uploads an attachment to a release.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoCreateReleaseAttachment
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/releases/1/assets?name=tea.zip
+    async fn upload_asset(
+        &self,
+        repo: &str,
+        id: &str,
+        name: &str,
+        content_type: &str,
+        data: Payload,
+    ) -> Result<Asset, SCMError> {
+        self.flavour.require_gitea("release assets")?;
+        let path = GOGS_PATH_RELEASE_ASSETS
+            .replace("{repo}", repo)
+            .replace("{id}", id);
+        let part = data
+            .into_part()
+            .file_name(name.to_string())
+            .mime_str(content_type)
+            .map_err(|e| SCMError::ClientError(HTTPError::ReqwestError(e)))?;
+        let res = self
+            .client
+            .post_multipart::<GogsAsset>(&path, Form::new().part("attachment", part))
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        match res.data {
+            Some(asset) if res.status.is_success() => Ok(asset.into()),
+            _ => Err(SCMError::UnexpectedStatus(path, res.status)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsRelease {
+    pub id: u64,
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub html_url: String,
+    pub created_at: String,
+    pub published_at: Option<String>,
+}
+
+impl From<GogsRelease> for Release {
+    fn from(val: GogsRelease) -> Self {
+        Self {
+            id: val.id.to_string(),
+            tag: val.tag_name,
+            title: val.name.unwrap_or_default(),
+            description: val.body.unwrap_or_default(),
+            draft: val.draft,
+            prerelease: val.prerelease,
+            link: val.html_url,
+            created: val.created_at,
+            published: val.published_at,
+        }
+    }
+}
+
+impl Endpoint for GogsRelease {
+    type Output = GogsRelease;
+}
+
+impl Endpoint for Vec<GogsRelease> {
+    type Output = Vec<GogsRelease>;
+}
+
+#[derive(Debug, Serialize)]
+pub struct GogsReleaseInput {
+    pub tag_name: String,
+    pub name: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+impl From<&ReleaseInput> for GogsReleaseInput {
+    fn from(val: &ReleaseInput) -> Self {
+        Self {
+            tag_name: val.tag.clone(),
+            name: val.title.clone(),
+            body: val.description.clone(),
+            target_commitish: val.target.clone(),
+            draft: val.draft,
+            prerelease: val.prerelease,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsAsset {
+    pub id: u64,
+    pub name: String,
+    pub size: u64,
+    pub browser_download_url: String,
+}
+
+impl From<GogsAsset> for Asset {
+    fn from(val: GogsAsset) -> Self {
+        Self {
+            id: val.id.to_string(),
+            name: val.name,
+            size: Some(val.size),
+            link: val.browser_download_url,
+        }
+    }
+}
+
+impl Endpoint for GogsAsset {
+    type Output = GogsAsset;
+}
+
+impl Endpoint for Vec<GogsAsset> {
+    type Output = Vec<GogsAsset>;
+}
@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::http::{endpoint::Endpoint, Client};
 use crate::scm::errors::SCMError;
-use crate::scm::repo::{Repository, RepositoryService};
+use crate::scm::repo::{Permission, Repository, RepositoryService};
+use crate::scm::utils;
 
-use super::constants::GOGS_PATH_REPOS;
+use super::constants::{GOGS_PATH_COLLABORATOR_PERMISSION, GOGS_PATH_LANGUAGES, GOGS_PATH_REPOS};
 
 pub struct GogsRepoService {
     pub client: Client,
@@ -41,6 +45,48 @@ impl RepositoryService for GogsRepoService {
 
         Ok(res.data.map(|v| v.into()))
     }
+
+    /// Returns the permission of a user, Gogs has no such API so the Gitea
+    /// compatible API is used.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoGetRepoPermissions
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/collaborators/lunny/permission
+    async fn permission(&self, repo: &str, user: &str) -> Result<Permission, SCMError> {
+        let path = GOGS_PATH_COLLABORATOR_PERMISSION
+            .replace("{repo}", repo)
+            .replace("{user}", user);
+        let res = self
+            .client
+            .get::<GogsPermission>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+
+        if res.status == StatusCode::NOT_FOUND {
+            return Ok(Permission::None);
+        }
+        if !res.status.is_success() {
+            return Err(SCMError::UnexpectedStatus(path, res.status));
+        }
+
+        Ok(res.data.map(|v| v.into()).unwrap_or_default())
+    }
+
+    /// Returns the languages of a repository as percentages of the code, Gogs
+    /// has no such API so the Gitea compatible API is used.
+    ///
+    /// Docs: https://gitea.com/api/swagger#/repository/repoGetLanguages
+    /// Example: https://gitea.com/api/v1/repos/gitea/tea/languages
+    async fn languages(&self, repo: &str) -> Result<HashMap<String, f64>, SCMError> {
+        let path = GOGS_PATH_LANGUAGES.replace("{repo}", repo);
+        let res = self
+            .client
+            .get::<GogsLanguages>(&path, None)
+            .await
+            .map_err(SCMError::ClientError)?;
+        utils::check_status(path, res.status)?;
+
+        Ok(utils::percentages(res.data.unwrap_or_default()))
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -88,3 +134,30 @@ impl From<GogsRepository> for Repository {
 impl Endpoint for GogsRepository {
     type Output = GogsRepository;
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsPermission {
+    pub permission: String,
+}
+
+impl From<GogsPermission> for Permission {
+    fn from(val: GogsPermission) -> Self {
+        match val.permission.as_str() {
+            "owner" | "admin" => Permission::Admin,
+            "write" => Permission::Write,
+            "read" => Permission::Read,
+            _ => Permission::None,
+        }
+    }
+}
+
+impl Endpoint for GogsPermission {
+    type Output = GogsPermission;
+}
+
+/// The bytes of code of each language of a repository, keyed by the language name.
+pub struct GogsLanguages;
+
+impl Endpoint for GogsLanguages {
+    type Output = HashMap<String, u64>;
+}
@@ -0,0 +1,100 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::constants::{GOGS_PATH_ORGS, GOGS_PATH_REPOS_SEARCH};
+use super::org::GogsOrganization;
+use super::repo::GogsRepository;
+use super::utils::convert_list_options;
+use crate::http::endpoint::Endpoint;
+use crate::http::Client;
+use crate::scm::client::ListOptions;
+use crate::scm::errors::SCMError;
+use crate::scm::repo::Repository;
+use crate::scm::search::{CodeResult, SearchCapabilities, SearchOptions, SearchScope, SearchService};
+use crate::scm::utils;
+
+pub struct GogsSearchService {
+    pub client: Client,
+}
+
+#[async_trait]
+impl SearchService for GogsSearchService {
+    fn capabilities(&self) -> SearchCapabilities {
+        SearchCapabilities {
+            code: false,
+            repositories: true,
+        }
+    }
+
+    /// Gogs and Gitea do not provide an API to search code.
+    async fn code(&self, _opts: &SearchOptions, _list: ListOptions) -> Result<Vec<CodeResult>, SCMError> {
+        Err(SCMError::Unsupported("Gogs code search".to_string()))
+    }
+
+    /// Searches for repositories by keyword, the organization scope is resolved
+    /// to the id of the organization, the repository scope is not supported and
+    /// treated as a search over everything.
+    ///
+    /// Docs: https://github.com/gogs/docs-api/tree/master/Repositories#search-repositories
+    /// Example: https://gogs.io/api/v1/repos/search?q=gogs
+    async fn repositories(
+        &self,
+        opts: &SearchOptions,
+        list: ListOptions,
+    ) -> Result<Vec<Repository>, SCMError> {
+        let mut options = convert_list_options(list);
+        if let Some(size) = options.remove("per_page") {
+            options.insert("limit".to_string(), size);
+        }
+        options.insert("q".to_string(), opts.query.clone());
+
+        if let SearchScope::Organization(org) = &opts.scope {
+            let path = GOGS_PATH_ORGS.replace("{org}", org);
+            let res = self
+                .client
+                .get::<GogsOrganization>(&path, None)
+                .await
+                .map_err(SCMError::ClientError)?;
+            utils::check_status(path.clone(), res.status)?;
+            let org = res.data.ok_or_else(|| SCMError::NotFound(path))?;
+            options.insert("uid".to_string(), org.id.to_string());
+        }
+
+        let res = self
+            .client
+            .get::<GogsSearch>(GOGS_PATH_REPOS_SEARCH, Some(options))
+            .await
+            .map_err(SCMError::ClientError)?;
+        utils::check_status(GOGS_PATH_REPOS_SEARCH.to_string(), res.status)?;
+
+        if let Some(search) = res.data {
+            return Ok(search.data.into_iter().map(|v| v.into()).collect());
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GogsSearch {
+    pub ok: bool,
+    pub data: Vec<GogsRepository>,
+}
+
+impl Endpoint for GogsSearch {
+    type Output = GogsSearch;
+}
@@ -17,11 +17,25 @@ pub mod github;
 pub mod gitlab;
 pub mod gogs;
 
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use self::github::constants::GITHUB_OAUTH_TOKEN_URL;
+use self::gitlab::constants::GITLAB_PATH_OAUTH_TOKEN;
+use self::gogs::constants::{GITEA_ENDPOINT, GOGS_PATH_OAUTH_TOKEN};
 use super::errors::SCMError;
-use crate::config::RepositoryCredential;
+use crate::config::{persist_token, Credential, RepositoryCredential, Scheme};
+use crate::http::{Auth, Client, OAuth2};
+use crate::scm::capabilities::Capabilities;
 use crate::scm::content::ContentService;
 use crate::scm::git::GitService;
+use crate::scm::issue::IssueService;
+use crate::scm::key::DeployKeyService;
+use crate::scm::org::OrganizationService;
+use crate::scm::release::ReleaseService;
 use crate::scm::repo::RepositoryService;
+use crate::scm::search::SearchService;
+use crate::scm::user::UserService;
 use crate::utils::http::host;
 
 /// Driver is a enum that represents the SCM driver.
@@ -37,6 +51,15 @@ pub trait DriverTrait {
     fn contents(&self) -> Box<dyn ContentService>;
     fn git(&self) -> Box<dyn GitService>;
     fn repositories(&self) -> Box<dyn RepositoryService>;
+    fn users(&self) -> Box<dyn UserService>;
+    fn organizations(&self) -> Box<dyn OrganizationService>;
+    fn issues(&self) -> Box<dyn IssueService>;
+    fn releases(&self) -> Box<dyn ReleaseService>;
+    fn deploy_keys(&self) -> Box<dyn DeployKeyService>;
+    fn search(&self) -> Box<dyn SearchService>;
+
+    /// Returns the services and features supported by the driver.
+    fn capabilities(&self) -> Capabilities;
 }
 
 impl DriverTrait for Driver {
@@ -66,17 +89,157 @@ impl DriverTrait for Driver {
             Driver::Gogs(driver) => driver.repositories(),
         }
     }
+
+    fn users(&self) -> Box<dyn UserService> {
+        match self {
+            Driver::Github(driver) => driver.users(),
+            Driver::Gitlab(driver) => driver.users(),
+            Driver::AtomGit(driver) => driver.users(),
+            Driver::Gogs(driver) => driver.users(),
+        }
+    }
+
+    fn organizations(&self) -> Box<dyn OrganizationService> {
+        match self {
+            Driver::Github(driver) => driver.organizations(),
+            Driver::Gitlab(driver) => driver.organizations(),
+            Driver::AtomGit(driver) => driver.organizations(),
+            Driver::Gogs(driver) => driver.organizations(),
+        }
+    }
+
+    fn issues(&self) -> Box<dyn IssueService> {
+        match self {
+            Driver::Github(driver) => driver.issues(),
+            Driver::Gitlab(driver) => driver.issues(),
+            Driver::AtomGit(driver) => driver.issues(),
+            Driver::Gogs(driver) => driver.issues(),
+        }
+    }
+
+    fn releases(&self) -> Box<dyn ReleaseService> {
+        match self {
+            Driver::Github(driver) => driver.releases(),
+            Driver::Gitlab(driver) => driver.releases(),
+            Driver::AtomGit(driver) => driver.releases(),
+            Driver::Gogs(driver) => driver.releases(),
+        }
+    }
+
+    fn deploy_keys(&self) -> Box<dyn DeployKeyService> {
+        match self {
+            Driver::Github(driver) => driver.deploy_keys(),
+            Driver::Gitlab(driver) => driver.deploy_keys(),
+            Driver::AtomGit(driver) => driver.deploy_keys(),
+            Driver::Gogs(driver) => driver.deploy_keys(),
+        }
+    }
+
+    fn search(&self) -> Box<dyn SearchService> {
+        match self {
+            Driver::Github(driver) => driver.search(),
+            Driver::Gitlab(driver) => driver.search(),
+            Driver::AtomGit(driver) => driver.search(),
+            Driver::Gogs(driver) => driver.search(),
+        }
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        match self {
+            Driver::Github(driver) => driver.capabilities(),
+            Driver::Gitlab(driver) => driver.capabilities(),
+            Driver::AtomGit(driver) => driver.capabilities(),
+            Driver::Gogs(driver) => driver.capabilities(),
+        }
+    }
+}
+
+/// Returns the authentication of the credential, the token is sent with the
+/// provider specific scheme and a username and password with basic authentication.
+fn auth(credential: &impl Credential, token: fn(String) -> Auth) -> Auth {
+    match credential.scheme() {
+        Scheme::Bearer => token(credential.token_any()),
+        Scheme::Basic => Auth::Basic {
+            username: credential.username_any(),
+            password: credential.password(),
+        },
+        Scheme::Unknown => Auth::None,
+    }
+}
+
+/// Returns the OAuth2 token source of the credential, if its token can be refreshed.
+fn oauth2(credential: &RepositoryCredential) -> Result<Option<OAuth2>, SCMError> {
+    let (Some(_), Some(oauth)) = (&credential.refresh_token, &credential.oauth) else {
+        return Ok(None);
+    };
+
+    let server = credential.server.trim_end_matches('/');
+    let token_url = match (&oauth.token_url, credential.driver.as_str()) {
+        (Some(url), _) => url.clone(),
+        (None, "github") => GITHUB_OAUTH_TOKEN_URL.to_string(),
+        (None, "gitlab") => format!("{server}{GITLAB_PATH_OAUTH_TOKEN}"),
+        (None, "gogs" | "gitea") => format!("{server}{GOGS_PATH_OAUTH_TOKEN}"),
+        (None, driver) => return Err(SCMError::Unsupported(format!("{driver} OAuth2 token refresh"))),
+    };
+
+    let source = OAuth2::new(
+        &token_url,
+        &oauth.client_id,
+        oauth.client_secret.clone(),
+        credential.oauth2_token(),
+    )
+    .map_err(SCMError::ClientError)?;
+    Ok(Some(source.redirect_uri(oauth.redirect_uri.clone())))
+}
+
+/// Returns a new driver authenticated with the refreshable OAuth2 token source.
+fn with_oauth2(credential: &RepositoryCredential, source: OAuth2) -> Result<Driver, SCMError> {
+    let client = Client::with_auth(&credential.server, Auth::Source(Arc::new(source)))
+        .map_err(SCMError::ClientError)?;
+    match credential.driver.as_str() {
+        "github" => github::from(client),
+        "gitlab" => gitlab::from(client),
+        "atomgit" => atomgit::from(client),
+        "gogs" => gogs::from(client),
+        "gitea" => gogs::from_gitea(client),
+        _ => Err(SCMError::UnknownDriver(credential.driver.to_string())),
+    }
+}
+
+impl Driver {
+    /// Returns a new driver from the credential, like `Driver::try_from`, and
+    /// saves refreshed OAuth2 tokens back to the configuration at the path.
+    pub fn with_configuration(
+        credential: &RepositoryCredential,
+        path: impl Into<PathBuf>,
+    ) -> Result<Driver, SCMError> {
+        match oauth2(credential)? {
+            Some(source) => {
+                let source = source.on_refresh(persist_token(path, credential));
+                with_oauth2(credential, source)
+            }
+            None => Driver::try_from(credential),
+        }
+    }
 }
 
 impl TryFrom<&RepositoryCredential> for Driver {
     type Error = SCMError;
 
     fn try_from(credential: &RepositoryCredential) -> Result<Self, Self::Error> {
+        if let Some(source) = oauth2(credential)? {
+            return with_oauth2(credential, source);
+        }
+
         match credential.driver.as_str() {
-            "github" => Ok(github::new(&credential.server, credential.token.clone())?),
-            "gitlab" => Ok(gitlab::new(&credential.server, credential.token.clone())?),
-            "atomgit" => Ok(atomgit::new(&credential.server, credential.token.clone())?),
-            "gogs" => Ok(gogs::new(&credential.server, credential.token.clone())?),
+            "github" => match &credential.app {
+                Some(app) => Ok(github::with_app(&credential.server, app)?),
+                None => Ok(github::with_credential(&credential.server, credential)?),
+            },
+            "gitlab" => Ok(gitlab::with_credential(&credential.server, credential)?),
+            "atomgit" => Ok(atomgit::with_credential(&credential.server, credential)?),
+            "gogs" => Ok(gogs::with_credential(&credential.server, credential)?),
+            "gitea" => Ok(gogs::with_gitea_credential(&credential.server, credential)?),
             _ => Err(SCMError::UnknownDriver(credential.driver.to_string())),
         }
     }
@@ -92,6 +255,7 @@ impl TryFrom<&str> for Driver {
             "gitlab.com" => Ok(gitlab::default()?),
             "atomgit.com" => Ok(atomgit::default()?),
             "gogs.io" => Ok(gogs::default()?),
+            "gitea.com" => Ok(gogs::gitea(GITEA_ENDPOINT, None)?),
             _ => Err(SCMError::UnknownDriver(url.to_string())),
         }
     }
@@ -21,4 +21,22 @@ pub enum SCMError {
 
     #[error("Not Found: {0}")]
     NotFound(String),
+
+    #[error("Unexpected Status {1}: {0}")]
+    UnexpectedStatus(String, reqwest::StatusCode),
+
+    #[error("IO Error: {0}")]
+    IoError(#[source] std::io::Error),
+
+    #[error("Unsafe Archive Entry: {0}")]
+    UnsafeArchiveEntry(String),
+
+    #[error("Invalid Pattern: {0}")]
+    InvalidPattern(#[source] globset::Error),
+
+    #[error("Invalid Manifest {0}: {1}")]
+    InvalidManifest(String, String),
+
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
 }
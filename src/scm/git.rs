@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{client::ListOptions, errors::SCMError};
+use super::{archive::ArchiveFormat, client::ListOptions, errors::SCMError};
+use crate::http::ByteStream;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -25,6 +26,27 @@ pub struct Reference {
     pub sha: String,
 }
 
+/// Represents the parameters to create a git branch.
+#[derive(Debug, Default, PartialEq)]
+pub struct ReferenceInput {
+    /// The short name of the reference, e.g. `feature/preview`.
+    pub name: String,
+    /// The commit SHA the reference points to.
+    pub sha: String,
+}
+
+/// Represents the parameters to create a git tag. The tag is annotated
+/// when a message is given, lightweight otherwise.
+#[derive(Debug, Default, PartialEq)]
+pub struct TagInput {
+    /// The short name of the tag, e.g. `v1.0.0`.
+    pub name: String,
+    /// The commit SHA the tag points to.
+    pub sha: String,
+    /// The message of an annotated tag.
+    pub message: Option<String>,
+}
+
 /// Represents a repository commit.
 #[derive(Debug, Default, PartialEq)]
 pub struct Commit {
@@ -72,6 +94,24 @@ pub trait GitService: Send + Sync {
     /// Returns a list of git tags.
     async fn list_tags(&self, repo: &str, opts: ListOptions) -> Result<Vec<Reference>, SCMError>;
 
+    /// Finds a git branch by name.
+    async fn find_branch(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError>;
+
+    /// Finds a git tag by name, the reference points to the tagged commit.
+    async fn find_tag(&self, repo: &str, name: &str) -> Result<Option<Reference>, SCMError>;
+
+    /// Creates a git branch pointing to the commit.
+    async fn create_branch(&self, repo: &str, input: &ReferenceInput) -> Result<Reference, SCMError>;
+
+    /// Deletes a git branch by name.
+    async fn delete_branch(&self, repo: &str, name: &str) -> Result<(), SCMError>;
+
+    /// Creates a lightweight or annotated git tag pointing to the commit.
+    async fn create_tag(&self, repo: &str, input: &TagInput) -> Result<Reference, SCMError>;
+
+    /// Deletes a git tag by name.
+    async fn delete_tag(&self, repo: &str, name: &str) -> Result<(), SCMError>;
+
     /// Finds a git commit by reference
     async fn find_commit(&self, repo: &str, reference: &str) -> Result<Option<Commit>, SCMError>;
 
@@ -82,4 +122,15 @@ pub trait GitService: Send + Sync {
         sha: &str,
         recursive: Option<bool>,
     ) -> Result<Option<Tree>, SCMError>;
+
+    /// Returns a stream of the repository archive at the reference. The `path` is
+    /// passed to providers that can scope the archive to a subdirectory, others
+    /// return the whole repository, see [`crate::scm::archive::extract`].
+    async fn archive(
+        &self,
+        repo: &str,
+        reference: &str,
+        format: ArchiveFormat,
+        path: Option<&str>,
+    ) -> Result<ByteStream, SCMError>;
 }
@@ -0,0 +1,117 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use super::{client::ListOptions, errors::SCMError, user::User};
+
+/// Represents an issue.
+#[derive(Debug, Default, PartialEq)]
+pub struct Issue {
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+    pub closed: bool,
+    pub author: User,
+    pub link: String,
+    pub created: String,
+    pub updated: String,
+}
+
+/// Represents a comment on an issue or a pull request.
+#[derive(Debug, Default, PartialEq)]
+pub struct Comment {
+    pub id: u64,
+    pub body: String,
+    pub author: User,
+    pub created: String,
+    pub updated: String,
+}
+
+/// Represents the parameters to create an issue.
+#[derive(Debug, Default, PartialEq)]
+pub struct IssueInput {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+}
+
+/// Represents the parameters to create or update a comment.
+#[derive(Debug, Default, PartialEq)]
+pub struct CommentInput {
+    pub body: String,
+}
+
+/// Identifies the issue or pull request a comment belongs to. Some providers
+/// number pull requests apart from issues (e.g. GitLab merge requests), so
+/// the kind is needed to address the comments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Thread {
+    Issue(u64),
+    PullRequest(u64),
+}
+
+impl Thread {
+    /// Returns the number of the issue or pull request.
+    pub fn number(&self) -> u64 {
+        match self {
+            Thread::Issue(number) | Thread::PullRequest(number) => *number,
+        }
+    }
+}
+
+/// Provides access to issues and comments.
+#[async_trait]
+pub trait IssueService: Send + Sync {
+    /// Returns an issue by number.
+    async fn find(&self, repo: &str, number: u64) -> Result<Option<Issue>, SCMError>;
+
+    /// Returns a list of the open issues, pull requests are not included.
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Issue>, SCMError>;
+
+    /// Creates an issue.
+    async fn create(&self, repo: &str, input: &IssueInput) -> Result<Issue, SCMError>;
+
+    /// Closes an issue by number.
+    async fn close(&self, repo: &str, number: u64) -> Result<(), SCMError>;
+
+    /// Returns a list of comments on the issue or pull request.
+    async fn list_comments(
+        &self,
+        repo: &str,
+        thread: Thread,
+        opts: ListOptions,
+    ) -> Result<Vec<Comment>, SCMError>;
+
+    /// Creates a comment on the issue or pull request.
+    async fn create_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError>;
+
+    /// Updates a comment on the issue or pull request.
+    async fn update_comment(
+        &self,
+        repo: &str,
+        thread: Thread,
+        id: u64,
+        input: &CommentInput,
+    ) -> Result<Comment, SCMError>;
+
+    /// Deletes a comment on the issue or pull request.
+    async fn delete_comment(&self, repo: &str, thread: Thread, id: u64) -> Result<(), SCMError>;
+}
@@ -0,0 +1,49 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use super::{client::ListOptions, errors::SCMError};
+
+/// Represents a SSH deploy key of a repository.
+#[derive(Debug, Default, PartialEq)]
+pub struct DeployKey {
+    pub id: String,
+    pub title: String,
+    pub key: String,
+    pub read_only: bool,
+    pub created: String,
+}
+
+/// Represents the parameters to add a deploy key.
+#[derive(Debug, Default, PartialEq)]
+pub struct DeployKeyInput {
+    pub title: String,
+    /// The public key, e.g. `ssh-ed25519 AAAA...`.
+    pub key: String,
+    pub read_only: bool,
+}
+
+/// Provides access to the deploy keys of a repository.
+#[async_trait]
+pub trait DeployKeyService: Send + Sync {
+    /// Returns a list of the deploy keys.
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<DeployKey>, SCMError>;
+
+    /// Adds a deploy key.
+    async fn create(&self, repo: &str, input: &DeployKeyInput) -> Result<DeployKey, SCMError>;
+
+    /// Removes a deploy key by id.
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError>;
+}
@@ -0,0 +1,266 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use futures::{stream, StreamExt};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+use super::errors::SCMError;
+use super::repo::RepositoryService;
+use super::utils;
+use crate::http::{endpoint::Endpoint, ByteStream, Client, HTTPError};
+
+const LFS_SPEC_PREFIX: &str = "version https://git-lfs.github.com/spec/";
+const LFS_MEDIA_TYPE: &str = "application/vnd.git-lfs+json";
+
+/// Pointer files are tiny text files, anything larger is real content.
+const LFS_POINTER_MAX_SIZE: usize = 1024;
+
+/// Represents a Git LFS pointer file, which stands in for the real content.
+///
+/// Spec: https://github.com/git-lfs/git-lfs/blob/main/docs/spec.md
+#[derive(Debug, PartialEq)]
+pub struct Pointer {
+    pub oid: String,
+    pub size: u64,
+}
+
+impl Pointer {
+    /// Parses the pointer from the file content, returns `None` if it is not a pointer.
+    pub fn parse(data: &[u8]) -> Option<Pointer> {
+        if data.len() > LFS_POINTER_MAX_SIZE {
+            return None;
+        }
+
+        let text = std::str::from_utf8(data).ok()?;
+        let mut lines = text.lines();
+        if !lines.next()?.starts_with(LFS_SPEC_PREFIX) {
+            return None;
+        }
+
+        let mut oid = None;
+        let mut size = None;
+        for line in lines {
+            if let Some(value) = line.strip_prefix("oid sha256:") {
+                oid = Some(value.trim().to_string());
+            } else if let Some(value) = line.strip_prefix("size ") {
+                size = value.trim().parse().ok();
+            }
+        }
+
+        Some(Pointer {
+            oid: oid?,
+            size: size?,
+        })
+    }
+}
+
+/// Reads just enough of the stream to tell whether it is a LFS pointer file.
+///
+/// Returns the pointer if it is one, along with a stream that yields the whole
+/// content again, including the bytes that have already been read.
+pub async fn peek(mut stream: ByteStream) -> Result<(Option<Pointer>, ByteStream), SCMError> {
+    let mut head: Vec<Bytes> = vec![];
+    let mut size = 0;
+
+    while size <= LFS_POINTER_MAX_SIZE {
+        match stream.next().await {
+            Some(chunk) => {
+                let chunk = chunk.map_err(SCMError::ClientError)?;
+                size += chunk.len();
+                head.push(chunk);
+            }
+            None => {
+                let data = head.concat();
+                let pointer = Pointer::parse(&data);
+                let stream = Box::pin(stream::once(async move { Ok(Bytes::from(data)) }));
+                return Ok((pointer, stream));
+            }
+        }
+    }
+
+    let head = stream::iter(head.into_iter().map(Ok::<Bytes, HTTPError>));
+    Ok((None, Box::pin(head.chain(stream))))
+}
+
+/// Resolves the stream to the real content if it is a LFS pointer file,
+/// otherwise the stream is returned unchanged.
+pub async fn resolve(
+    client: &Client,
+    stream: ByteStream,
+    repositories: &dyn RepositoryService,
+    repo: &str,
+) -> Result<ByteStream, SCMError> {
+    let (pointer, stream) = peek(stream).await?;
+    let Some(pointer) = pointer else {
+        return Ok(stream);
+    };
+
+    let repository = repositories
+        .find(repo)
+        .await?
+        .ok_or_else(|| SCMError::NotFound(repo.to_string()))?;
+    download(client, &repository.clone, &pointer).await
+}
+
+/// Downloads the real content of the pointer through the LFS batch API of the
+/// repository, `clone_url` is the HTTP(S) clone address of the repository.
+///
+/// Docs: https://github.com/git-lfs/git-lfs/blob/main/docs/api/batch.md
+pub async fn download(client: &Client, clone_url: &str, pointer: &Pointer) -> Result<ByteStream, SCMError> {
+    let mut endpoint = clone_url.trim_end_matches('/').to_string();
+    if !endpoint.ends_with(".git") {
+        endpoint.push_str(".git");
+    }
+    let path = format!("{endpoint}/info/lfs/objects/batch");
+
+    let request = LfsBatchRequest {
+        operation: "download".to_string(),
+        transfers: vec!["basic".to_string()],
+        objects: vec![LfsObject {
+            oid: pointer.oid.clone(),
+            size: pointer.size,
+        }],
+    };
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static(LFS_MEDIA_TYPE));
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(LFS_MEDIA_TYPE));
+
+    let res = client
+        .post_with_headers::<LfsBatchResponse, _>(&path, &request, headers)
+        .await
+        .map_err(SCMError::ClientError)?;
+    utils::check_status(path.clone(), res.status)?;
+
+    let action = res
+        .data
+        .and_then(|v| v.objects.into_iter().find(|o| o.oid == pointer.oid))
+        .and_then(|o| o.actions)
+        .and_then(|a| a.download)
+        .ok_or_else(|| SCMError::NotFound(format!("{path}#{}", pointer.oid)))?;
+
+    let mut headers = HeaderMap::new();
+    for (name, value) in action.header.unwrap_or_default() {
+        let name =
+            HeaderName::try_from(name).map_err(|e| SCMError::ClientError(HTTPError::InvalidHeaderName(e)))?;
+        let value = HeaderValue::try_from(value)
+            .map_err(|e| SCMError::ClientError(HTTPError::InvalidHeaderValue(e)))?;
+        headers.insert(name, value);
+    }
+
+    // the content is usually served by a storage service, which must not see the
+    // credentials of the provider and expects only the headers of the action.
+    let res = client
+        .without_auth()
+        .get_stream(&action.href, None, Some(headers))
+        .await
+        .map_err(SCMError::ClientError)?;
+    utils::check_status(action.href, res.status)?;
+
+    Ok(res.stream)
+}
+
+#[derive(Debug, Serialize)]
+struct LfsBatchRequest {
+    operation: String,
+    transfers: Vec<String>,
+    objects: Vec<LfsObject>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct LfsObject {
+    oid: String,
+    size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct LfsBatchResponse {
+    objects: Vec<LfsObjectResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LfsObjectResponse {
+    oid: String,
+    actions: Option<LfsActions>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LfsActions {
+    download: Option<LfsAction>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LfsAction {
+    href: String,
+    header: Option<HashMap<String, String>>,
+}
+
+impl Endpoint for LfsBatchResponse {
+    type Output = LfsBatchResponse;
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use futures::{stream, TryStreamExt};
+
+    use super::{peek, Pointer};
+    use crate::http::{ByteStream, HTTPError};
+
+    const POINTER: &str = "version https://git-lfs.github.com/spec/v1\n\
+        oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+        size 12345\n";
+
+    fn chunks(data: &[&'static str]) -> ByteStream {
+        Box::pin(stream::iter(
+            data.iter()
+                .map(|v| Ok::<Bytes, HTTPError>(Bytes::from_static(v.as_bytes())))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    #[test]
+    fn test_parse_pointer() {
+        let pointer = Pointer::parse(POINTER.as_bytes()).unwrap();
+        assert_eq!(
+            pointer.oid,
+            "4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393"
+        );
+        assert_eq!(pointer.size, 12345);
+
+        assert_eq!(Pointer::parse(b"Hello World!\n"), None);
+    }
+
+    #[tokio::test]
+    async fn test_peek_pointer() {
+        let (pointer, stream) = peek(chunks(&[&POINTER[..20], &POINTER[20..]])).await.unwrap();
+        assert!(pointer.is_some());
+
+        let data: Vec<u8> = stream.map_ok(|v| v.to_vec()).try_concat().await.unwrap();
+        assert_eq!(data, POINTER.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_peek_regular_content() {
+        let large = "x".repeat(2048).leak();
+        let (pointer, stream) = peek(chunks(&[large, "tail"])).await.unwrap();
+        assert!(pointer.is_none());
+
+        let data: Vec<u8> = stream.map_ok(|v| v.to_vec()).try_concat().await.unwrap();
+        assert_eq!(data.len(), 2048 + 4);
+    }
+}
@@ -12,11 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod archive;
+pub mod capabilities;
 pub mod client;
 pub mod constants;
 pub mod content;
+pub mod discovery;
 pub mod driver;
 pub mod errors;
 pub mod git;
+pub mod issue;
+pub mod key;
+pub mod lfs;
+pub mod org;
+pub mod release;
 pub mod repo;
+pub mod search;
+pub mod user;
 pub mod utils;
+pub mod walk;
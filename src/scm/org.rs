@@ -0,0 +1,58 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use super::{client::ListOptions, errors::SCMError};
+
+/// Represents an organization account, a group on GitLab.
+#[derive(Debug, Default, PartialEq)]
+pub struct Organization {
+    pub id: String,
+    pub login: String,
+    pub name: String,
+    pub avatar: String,
+    pub link: String,
+}
+
+/// Represents the role of a member in an organization.
+#[derive(Debug, Default, PartialEq)]
+pub enum Role {
+    /// The provider does not tell the role of the member.
+    #[default]
+    Undefined,
+    Member,
+    Admin,
+}
+
+/// Represents a member of an organization.
+#[derive(Debug, Default, PartialEq)]
+pub struct Member {
+    pub login: String,
+    pub avatar: String,
+    pub role: Role,
+}
+
+/// Provides access to organization accounts.
+#[async_trait]
+pub trait OrganizationService: Send + Sync {
+    /// Returns the organizations of the authenticated user.
+    async fn list(&self, opts: ListOptions) -> Result<Vec<Organization>, SCMError>;
+
+    /// Returns an organization by name.
+    async fn find(&self, name: &str) -> Result<Option<Organization>, SCMError>;
+
+    /// Returns the members of an organization, along with their role.
+    async fn list_members(&self, name: &str, opts: ListOptions) -> Result<Vec<Member>, SCMError>;
+}
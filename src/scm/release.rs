@@ -0,0 +1,121 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use super::{client::ListOptions, errors::SCMError};
+use crate::http::Payload;
+
+/// Represents a release.
+#[derive(Debug, Default, PartialEq)]
+pub struct Release {
+    /// The identifier of the release, used to address it in the service.
+    /// Providers that key releases by tag (e.g. GitLab) use the tag.
+    pub id: String,
+    pub tag: String,
+    pub title: String,
+    pub description: String,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub link: String,
+    pub created: String,
+    pub published: Option<String>,
+}
+
+impl Release {
+    /// Returns the version of the release, the tag without the `v` prefix
+    /// (e.g. `v1.2.0` is `1.2.0`), as used by `schema::Metadata::version`.
+    pub fn version(&self) -> String {
+        let tag = self.tag.trim_start_matches("refs/tags/");
+        match tag.strip_prefix('v') {
+            Some(version) if version.starts_with(|c: char| c.is_ascii_digit()) => version.to_string(),
+            _ => tag.to_string(),
+        }
+    }
+}
+
+/// Represents the parameters to create or update a release.
+#[derive(Debug, Default, PartialEq)]
+pub struct ReleaseInput {
+    pub tag: String,
+    pub title: String,
+    pub description: String,
+    /// The branch or commit the tag is created from, if the tag does not exist yet.
+    pub target: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+/// Represents a file attached to a release.
+#[derive(Debug, Default, PartialEq)]
+pub struct Asset {
+    pub id: String,
+    pub name: String,
+    pub size: Option<u64>,
+    pub link: String,
+}
+
+/// Provides access to releases.
+#[async_trait]
+pub trait ReleaseService: Send + Sync {
+    /// Returns a list of releases.
+    async fn list(&self, repo: &str, opts: ListOptions) -> Result<Vec<Release>, SCMError>;
+
+    /// Finds a release by tag.
+    async fn find_by_tag(&self, repo: &str, tag: &str) -> Result<Option<Release>, SCMError>;
+
+    /// Returns the latest published release.
+    async fn latest(&self, repo: &str) -> Result<Option<Release>, SCMError>;
+
+    /// Creates a release.
+    async fn create(&self, repo: &str, input: &ReleaseInput) -> Result<Release, SCMError>;
+
+    /// Updates a release by id.
+    async fn update(&self, repo: &str, id: &str, input: &ReleaseInput) -> Result<Release, SCMError>;
+
+    /// Deletes a release by id, the tag is kept.
+    async fn delete(&self, repo: &str, id: &str) -> Result<(), SCMError>;
+
+    /// Returns a list of the assets of a release.
+    async fn list_assets(&self, repo: &str, id: &str) -> Result<Vec<Asset>, SCMError>;
+
+    /// Uploads a file as an asset of a release, the data is streamed and
+    /// never buffered as a whole.
+    async fn upload_asset(
+        &self,
+        repo: &str,
+        id: &str,
+        name: &str,
+        content_type: &str,
+        data: Payload,
+    ) -> Result<Asset, SCMError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::Release;
+
+    #[test]
+    fn test_release_version() {
+        let release = |tag: &str| Release {
+            tag: tag.to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(release("v1.2.0").version(), "1.2.0");
+        assert_eq!(release("1.2.0").version(), "1.2.0");
+        assert_eq!(release("refs/tags/v0.1.0").version(), "0.1.0");
+        assert_eq!(release("victory").version(), "victory");
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
 use super::{constants::Visibility, errors::SCMError};
@@ -33,9 +35,29 @@ pub struct Repository {
     pub description: Option<String>,
 }
 
+/// Represents the permission of a user on a repository, ordered from the
+/// least to the most privileged so that levels can be compared.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Permission {
+    #[default]
+    None,
+    Read,
+    Write,
+    Admin,
+}
+
 /// Provides access to repository resources.
 #[async_trait]
 pub trait RepositoryService: Send + Sync {
     /// Returns a repository by name.
     async fn find(&self, repo: &str) -> Result<Option<Repository>, SCMError>;
+
+    /// Returns the permission of the user on the repository, `Permission::None`
+    /// if the user has no access to it.
+    async fn permission(&self, repo: &str, user: &str) -> Result<Permission, SCMError>;
+
+    /// Returns the percentage of the code written in each language. GitLab only
+    /// reports the percentages, so the bytes of code reported by the other
+    /// providers are converted for the values to be comparable across drivers.
+    async fn languages(&self, repo: &str) -> Result<HashMap<String, f64>, SCMError>;
 }
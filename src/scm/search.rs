@@ -0,0 +1,75 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use super::{client::ListOptions, errors::SCMError, repo::Repository};
+
+/// Specifies where to search.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum SearchScope {
+    /// Everything visible to the authenticated user.
+    #[default]
+    All,
+    /// A single repository, e.g. `octocat/Hello-World`.
+    Repository(String),
+    /// All repositories of an organization (or a group on GitLab).
+    Organization(String),
+}
+
+/// Represents the parameters of a search.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchOptions {
+    /// The search terms, provider specific qualifiers are passed through.
+    pub query: String,
+    pub scope: SearchScope,
+    /// Restricts code search to the files below the path, ignored by repository search.
+    pub path: Option<String>,
+}
+
+/// Represents a file matching a code search.
+#[derive(Debug, Default, PartialEq)]
+pub struct CodeResult {
+    /// The repository of the file, GitLab only reports the numeric project id
+    /// for searches across projects, which is accepted in place of the name.
+    pub repo: String,
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub link: String,
+}
+
+/// Describes the kinds of search supported by a provider.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SearchCapabilities {
+    pub code: bool,
+    pub repositories: bool,
+}
+
+/// Provides access to code and repository search.
+#[async_trait]
+pub trait SearchService: Send + Sync {
+    /// Returns the kinds of search supported by the provider.
+    fn capabilities(&self) -> SearchCapabilities;
+
+    /// Returns the files matching the search.
+    async fn code(&self, opts: &SearchOptions, list: ListOptions) -> Result<Vec<CodeResult>, SCMError>;
+
+    /// Returns the repositories matching the search.
+    async fn repositories(
+        &self,
+        opts: &SearchOptions,
+        list: ListOptions,
+    ) -> Result<Vec<Repository>, SCMError>;
+}
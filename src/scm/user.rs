@@ -0,0 +1,49 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use super::{client::ListOptions, errors::SCMError};
+
+/// Represents a user account.
+#[derive(Debug, Default, PartialEq)]
+pub struct User {
+    pub id: String,
+    pub login: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub avatar: String,
+    pub link: String,
+}
+
+/// Represents an email address of a user account.
+#[derive(Debug, Default, PartialEq)]
+pub struct Email {
+    pub value: String,
+    pub primary: bool,
+    pub verified: bool,
+}
+
+/// Provides access to user accounts.
+#[async_trait]
+pub trait UserService: Send + Sync {
+    /// Returns the authenticated user.
+    async fn find(&self) -> Result<Option<User>, SCMError>;
+
+    /// Returns a user by login.
+    async fn find_login(&self, login: &str) -> Result<Option<User>, SCMError>;
+
+    /// Returns the email addresses of the authenticated user.
+    async fn list_emails(&self, opts: ListOptions) -> Result<Vec<Email>, SCMError>;
+}
@@ -12,6 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use reqwest::StatusCode;
+
+use super::errors::SCMError;
+
+/// Returns `SCMError::NotFound` for `404 Not Found` and `SCMError::UnexpectedStatus`
+/// for the other unsuccessful statuses, so that callers can tell a missing resource
+/// from a request to retry or re-authenticate.
+pub fn check_status(path: String, status: StatusCode) -> Result<(), SCMError> {
+    match status {
+        StatusCode::NOT_FOUND => Err(SCMError::NotFound(path)),
+        status if !status.is_success() => Err(SCMError::UnexpectedStatus(path, status)),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the percentage of the code written in each language, given the bytes
+/// of code written in each language.
+pub fn percentages(bytes: HashMap<String, u64>) -> HashMap<String, f64> {
+    let total: u64 = bytes.values().sum();
+    bytes
+        .into_iter()
+        .map(|(name, size)| {
+            (
+                name,
+                if total == 0 {
+                    0.0
+                } else {
+                    size as f64 * 100.0 / total as f64
+                },
+            )
+        })
+        .collect()
+}
+
 /// Returns ref without the path prefix.
 pub fn trim_ref(reference: &str) -> String {
     let mut reference = String::from(reference);
@@ -0,0 +1,389 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::{stream, StreamExt, TryStreamExt};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use tracing::debug;
+
+use super::content::{ContentService, File};
+use super::errors::SCMError;
+use super::git::GitService;
+use crate::schema::Build;
+
+/// Specifies which files are returned by a walk, and how.
+#[derive(Clone, Debug)]
+pub struct WalkOptions {
+    /// Glob patterns of the files to include, relative to the walked path,
+    /// everything below a matching directory is included. All files are
+    /// included when empty.
+    pub include: Vec<String>,
+    /// Glob patterns of the files to exclude, relative to the walked path.
+    /// Takes precedence over `include`, and excludes everything below a
+    /// matching directory.
+    pub exclude: Vec<String>,
+    /// The maximum number of concurrent requests when listing directories.
+    pub concurrency: usize,
+}
+
+impl Default for WalkOptions {
+    fn default() -> Self {
+        Self {
+            include: vec![],
+            exclude: vec![],
+            concurrency: 8,
+        }
+    }
+}
+
+impl From<&Build> for WalkOptions {
+    fn from(build: &Build) -> Self {
+        Self {
+            include: build.include.clone().unwrap_or_default(),
+            exclude: build.exclude.clone().unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Matches relative paths against the include and exclude patterns.
+struct Filter {
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+}
+
+impl Filter {
+    fn new(options: &WalkOptions) -> Result<Self, SCMError> {
+        let include = match options.include.is_empty() {
+            true => None,
+            false => Some(build_glob_set(&options.include)?),
+        };
+
+        Ok(Self {
+            include,
+            exclude: build_glob_set(&options.exclude)?,
+        })
+    }
+
+    /// Returns true if the directory or any of its parents is excluded.
+    fn excludes(&self, path: &str) -> bool {
+        matches_any_prefix(&self.exclude, path)
+    }
+
+    /// Returns true if the file should be part of the result.
+    fn matches(&self, path: &str) -> bool {
+        if self.excludes(path) {
+            return false;
+        }
+
+        self.include
+            .as_ref()
+            .map(|set| matches_any_prefix(set, path))
+            .unwrap_or(true)
+    }
+}
+
+/// Returns true if the path or any of its parents matches the set.
+fn matches_any_prefix(set: &GlobSet, path: &str) -> bool {
+    let mut prefix = String::new();
+    for part in path.split('/') {
+        if !prefix.is_empty() {
+            prefix.push('/');
+        }
+        prefix.push_str(part);
+        if set.is_match(&prefix) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, SCMError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        builder.add(Glob::new(pattern).map_err(SCMError::InvalidPattern)?);
+    }
+
+    builder.build().map_err(SCMError::InvalidPattern)
+}
+
+/// Returns the path relative to the walk root, or `None` if it is outside of it.
+fn relative<'a>(root: &str, path: &'a str) -> Option<&'a str> {
+    if root.is_empty() {
+        return Some(path);
+    }
+
+    path.strip_prefix(root).and_then(|rest| rest.strip_prefix('/'))
+}
+
+/// Returns every file under the path of the repository at the reference,
+/// filtered by the include and exclude patterns.
+///
+/// A single recursive tree request is used when the `git` service is given and
+/// the tree is complete, otherwise (or if the tree request fails) the
+/// directories are listed level by level through the `contents` service, with
+/// at most `options.concurrency` requests in flight.
+pub async fn walk(
+    git: Option<&dyn GitService>,
+    contents: &dyn ContentService,
+    repo: &str,
+    path: &str,
+    reference: &str,
+    options: &WalkOptions,
+) -> Result<Vec<File>, SCMError> {
+    let filter = Filter::new(options)?;
+    let root = path.trim_matches('/');
+
+    if let Some(git) = git {
+        let tree = git
+            .get_tree(repo, reference, Some(true))
+            .await
+            .unwrap_or_else(|err| {
+                debug!("failed to get the tree of {repo} at {reference}, listing the contents: {err}");
+                None
+            });
+        if let Some(tree) = tree {
+            if !tree.truncated {
+                let mut files: Vec<File> = tree
+                    .tree
+                    .into_iter()
+                    .filter(|entry| entry.kind == "blob")
+                    .filter(|entry| relative(root, &entry.path).is_some_and(|p| filter.matches(p)))
+                    .map(|entry| File {
+                        name: entry.path.rsplit('/').next().unwrap_or_default().to_string(),
+                        path: entry.path,
+                        sha: entry.sha.clone(),
+                        blob_id: entry.sha,
+                        kind: "file".to_string(),
+                    })
+                    .collect();
+                files.sort_by(|a, b| a.path.cmp(&b.path));
+                return Ok(files);
+            }
+        }
+    }
+
+    let mut files = vec![];
+    let mut pending = vec![root.to_string()];
+    while !pending.is_empty() {
+        let listings: Vec<Vec<File>> = stream::iter(pending.drain(..))
+            .map(|dir| async move { contents.list(repo, &dir, reference).await })
+            .buffer_unordered(options.concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        for entry in listings.into_iter().flatten() {
+            let Some(relative) = relative(root, &entry.path) else {
+                continue;
+            };
+            match entry.kind.as_str() {
+                "dir" if !filter.excludes(relative) => pending.push(entry.path),
+                "file" if filter.matches(relative) => files.push(entry),
+                _ => {}
+            }
+        }
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use async_trait::async_trait;
+
+    use super::{walk, Filter, WalkOptions};
+    use crate::http::ByteStream;
+    use crate::scm::archive::ArchiveFormat;
+    use crate::scm::client::ListOptions;
+    use crate::scm::content::{Content, ContentService, File};
+    use crate::scm::errors::SCMError;
+    use crate::scm::git::{Commit, GitService, Reference, ReferenceInput, TagInput, Tree};
+
+    /// Serves directory listings from memory.
+    struct MemoryContentService {
+        dirs: HashMap<&'static str, Vec<(&'static str, &'static str)>>,
+    }
+
+    #[async_trait]
+    impl ContentService for MemoryContentService {
+        async fn find(&self, _repo: &str, _path: &str, _reference: &str) -> Result<Content, SCMError> {
+            unimplemented!()
+        }
+
+        async fn raw(&self, _repo: &str, _path: &str, _reference: &str) -> Result<ByteStream, SCMError> {
+            unimplemented!()
+        }
+
+        async fn list(&self, _repo: &str, path: &str, _reference: &str) -> Result<Vec<File>, SCMError> {
+            let entries = self.dirs.get(path).cloned().unwrap_or_default();
+            Ok(entries
+                .into_iter()
+                .map(|(path, kind)| File {
+                    name: path.rsplit('/').next().unwrap().to_string(),
+                    path: path.to_string(),
+                    sha: String::new(),
+                    blob_id: String::new(),
+                    kind: kind.to_string(),
+                })
+                .collect())
+        }
+    }
+
+    /// Fails every request, e.g. like a provider without a tree API.
+    struct FailingGitService;
+
+    #[async_trait]
+    impl GitService for FailingGitService {
+        async fn list_branches(&self, _repo: &str, _opts: ListOptions) -> Result<Vec<Reference>, SCMError> {
+            unimplemented!()
+        }
+
+        async fn list_tags(&self, _repo: &str, _opts: ListOptions) -> Result<Vec<Reference>, SCMError> {
+            unimplemented!()
+        }
+
+        async fn find_branch(&self, _repo: &str, _name: &str) -> Result<Option<Reference>, SCMError> {
+            unimplemented!()
+        }
+
+        async fn find_tag(&self, _repo: &str, _name: &str) -> Result<Option<Reference>, SCMError> {
+            unimplemented!()
+        }
+
+        async fn create_branch(&self, _repo: &str, _input: &ReferenceInput) -> Result<Reference, SCMError> {
+            unimplemented!()
+        }
+
+        async fn delete_branch(&self, _repo: &str, _name: &str) -> Result<(), SCMError> {
+            unimplemented!()
+        }
+
+        async fn create_tag(&self, _repo: &str, _input: &TagInput) -> Result<Reference, SCMError> {
+            unimplemented!()
+        }
+
+        async fn delete_tag(&self, _repo: &str, _name: &str) -> Result<(), SCMError> {
+            unimplemented!()
+        }
+
+        async fn find_commit(&self, _repo: &str, _reference: &str) -> Result<Option<Commit>, SCMError> {
+            unimplemented!()
+        }
+
+        async fn get_tree(
+            &self,
+            repo: &str,
+            _sha: &str,
+            _recursive: Option<bool>,
+        ) -> Result<Option<Tree>, SCMError> {
+            Err(SCMError::Unsupported(format!("tree of {repo}")))
+        }
+
+        async fn archive(
+            &self,
+            _repo: &str,
+            _reference: &str,
+            _format: ArchiveFormat,
+            _path: Option<&str>,
+        ) -> Result<ByteStream, SCMError> {
+            unimplemented!()
+        }
+    }
+
+    fn options(include: &[&str], exclude: &[&str]) -> WalkOptions {
+        WalkOptions {
+            include: include.iter().map(|v| v.to_string()).collect(),
+            exclude: exclude.iter().map(|v| v.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter::new(&options(
+            &["src/**", "Cargo.toml"],
+            &["src/generated", "**/*.bak"],
+        ))
+        .unwrap();
+
+        assert!(filter.matches("Cargo.toml"));
+        assert!(filter.matches("src/main.rs"));
+        assert!(!filter.matches("README.md"));
+        assert!(!filter.matches("src/generated/schema.rs"));
+        assert!(!filter.matches("src/main.rs.bak"));
+    }
+
+    #[test]
+    fn test_filter_include_directory() {
+        let filter = Filter::new(&options(&["src", "./docs/"], &["src/generated"])).unwrap();
+
+        assert!(filter.matches("src/main.rs"));
+        assert!(filter.matches("src/scm/walk.rs"));
+        assert!(filter.matches("docs/index.md"));
+        assert!(!filter.matches("srcs/main.rs"));
+        assert!(!filter.matches("Cargo.toml"));
+        assert!(!filter.matches("src/generated/schema.rs"));
+    }
+
+    #[tokio::test]
+    async fn test_walk_by_listing() {
+        let service = MemoryContentService {
+            dirs: HashMap::from([
+                (
+                    "app",
+                    vec![("app/main.rs", "file"), ("app/lib", "dir"), ("app/target", "dir")],
+                ),
+                ("app/lib", vec![("app/lib/mod.rs", "file")]),
+                ("app/target", vec![("app/target/app", "file")]),
+            ]),
+        };
+
+        let files = walk(
+            None,
+            &service,
+            "octocat/Hello-World",
+            "app",
+            "master",
+            &options(&[], &["target"]),
+        )
+        .await
+        .unwrap();
+
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["app/lib/mod.rs", "app/main.rs"]);
+    }
+
+    #[tokio::test]
+    async fn test_walk_falls_back_to_listing() {
+        let service = MemoryContentService {
+            dirs: HashMap::from([("", vec![("README.md", "file")])]),
+        };
+
+        let files = walk(
+            Some(&FailingGitService),
+            &service,
+            "octocat/Hello-World",
+            "",
+            "master",
+            &WalkOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(files.len(), 1);
+    }
+}
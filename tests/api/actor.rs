@@ -0,0 +1,71 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::api::constants::{API_PATH_ACTOR, API_PATH_ACTORS, API_PATH_ACTOR_LOGS, API_PATH_ACTOR_SYNC};
+use amp_common::api::Client;
+use amp_common::sync::{EventKinds, Path, Synchronization};
+use futures::TryStreamExt;
+
+use crate::common::mock;
+
+const PID: &str = "1a2b3c4d";
+const NAME: &str = "amp-example-go";
+
+#[tokio::test]
+async fn test_list_actors() {
+    let path = API_PATH_ACTORS.replace("{pid}", PID);
+    let setup = mock("GET", &path, "api/actors/list-actors-success").await;
+
+    let client = Client::from(setup.0);
+    let actors = client.actors().list(PID).await.unwrap();
+    assert_eq!(actors.len(), 1);
+    assert_eq!(actors[0].name, NAME);
+}
+
+#[tokio::test]
+async fn test_get_actor() {
+    let path = API_PATH_ACTOR.replace("{pid}", PID).replace("{name}", NAME);
+    let setup = mock("GET", &path, "api/actors/get-actor-success").await;
+
+    let client = Client::from(setup.0);
+    let actor = client.actors().get(PID, NAME).await.unwrap();
+    assert_eq!(actor.name(), "amp-example-go-8e4f4b0");
+    assert!(actor.live);
+}
+
+#[tokio::test]
+async fn test_get_actor_logs() {
+    let path = API_PATH_ACTOR_LOGS.replace("{pid}", PID).replace("{name}", NAME);
+    let setup = mock("GET", &path, "api/actors/get-actor-logs-success").await;
+
+    let client = Client::from(setup.0);
+    let logs = client.actors().logs(PID, NAME);
+    let lines: Vec<String> = logs.try_collect().await.unwrap();
+    assert_eq!(lines, ["Compiling amp-example-go", "Listening on :8080"]);
+}
+
+#[tokio::test]
+async fn test_sync_actor() {
+    let path = API_PATH_ACTOR_SYNC.replace("{pid}", PID).replace("{name}", NAME);
+    let setup = mock("POST", &path, "api/actors/sync-actor-success").await;
+
+    let client = Client::from(setup.0);
+    let synchronization = Synchronization {
+        kind: EventKinds::Modify,
+        paths: vec![Path::File("main.go".to_string())],
+        attributes: None,
+        payload: Some(b"package main".to_vec()),
+    };
+    assert!(client.actors().sync(PID, NAME, &synchronization).await.is_ok());
+}
@@ -0,0 +1,46 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::api::constants::{API_PATH_CHARACTER, API_PATH_CHARACTERS};
+use amp_common::api::Client;
+
+use crate::common::mock;
+
+const PID: &str = "1a2b3c4d";
+const NAME: &str = "amp-example-go";
+
+#[tokio::test]
+async fn test_list_characters() {
+    let path = API_PATH_CHARACTERS.replace("{pid}", PID);
+    let setup = mock("GET", &path, "api/characters/list-characters-success").await;
+
+    let client = Client::from(setup.0);
+    let characters = client.characters().list(PID).await.unwrap();
+    assert_eq!(characters.len(), 1);
+    assert_eq!(characters[0].meta.name, NAME);
+}
+
+#[tokio::test]
+async fn test_get_character() {
+    let path = API_PATH_CHARACTER.replace("{pid}", PID).replace("{name}", NAME);
+    let setup = mock("GET", &path, "api/characters/get-character-success").await;
+
+    let client = Client::from(setup.0);
+    let character = client.characters().get(PID, NAME).await.unwrap();
+    assert_eq!(
+        character.meta.repository,
+        "https://github.com/amphitheatre-app/amp-example-go"
+    );
+    assert_eq!(character.deploy.unwrap().image.unwrap(), "amp-example-go:latest");
+}
@@ -0,0 +1,89 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::api::constants::{API_PATH_PLAYBOOK, API_PATH_PLAYBOOKS};
+use amp_common::api::errors::ApiError;
+use amp_common::api::playbook::PlaybookPayload;
+use amp_common::api::Client;
+use amp_common::http::HTTPError;
+use amp_common::resource::Preface;
+use assert_matches::assert_matches;
+
+use crate::common::mock;
+
+const PID: &str = "1a2b3c4d";
+
+#[tokio::test]
+async fn test_list_playbooks() {
+    let setup = mock("GET", API_PATH_PLAYBOOKS, "api/playbooks/list-playbooks-success").await;
+
+    let client = Client::from(setup.0);
+    let playbooks = client.playbooks().list().await.unwrap();
+    assert_eq!(playbooks.len(), 1);
+    assert_eq!(playbooks[0].id, PID);
+}
+
+#[tokio::test]
+async fn test_create_playbook() {
+    let setup = mock(
+        "POST",
+        API_PATH_PLAYBOOKS,
+        "api/playbooks/create-playbook-success",
+    )
+    .await;
+
+    let client = Client::from(setup.0);
+    let payload = PlaybookPayload {
+        title: "Example Go".to_string(),
+        description: None,
+        preface: Preface::repository("https://github.com/amphitheatre-app/amp-example-go"),
+    };
+    let playbook = client.playbooks().create(&payload).await.unwrap();
+    assert_eq!(playbook.title, "Example Go");
+    assert_eq!(playbook.characters.unwrap()[0].meta.name, "amp-example-go");
+}
+
+#[tokio::test]
+async fn test_get_playbook() {
+    let path = API_PATH_PLAYBOOK.replace("{pid}", PID);
+    let setup = mock("GET", &path, "api/playbooks/get-playbook-success").await;
+
+    let client = Client::from(setup.0);
+    let playbook = client.playbooks().get(PID).await.unwrap();
+    assert_eq!(playbook.id, PID);
+    assert_eq!(playbook.namespace(), "amp-1a2b3c4d");
+}
+
+#[tokio::test]
+async fn test_get_playbook_not_found() {
+    let path = API_PATH_PLAYBOOK.replace("{pid}", PID);
+    let setup = mock("GET", &path, "api/playbooks/get-playbook-not-found").await;
+
+    let client = Client::from(setup.0);
+    let err = client.playbooks().get(PID).await.unwrap_err();
+    assert_matches!(
+        err,
+        ApiError::ClientError(HTTPError::Status { status, message, .. })
+            if status == 404 && message == "Playbook 1a2b3c4d not found"
+    );
+}
+
+#[tokio::test]
+async fn test_delete_playbook() {
+    let path = API_PATH_PLAYBOOK.replace("{pid}", PID);
+    let setup = mock("DELETE", &path, "api/playbooks/delete-playbook-success").await;
+
+    let client = Client::from(setup.0);
+    assert!(client.playbooks().delete(PID).await.is_ok());
+}
@@ -16,7 +16,7 @@
 
 use std::fs;
 
-use amp_common::http::Client;
+use amp_common::http::{Cassette, Client};
 use mockito::{Server, ServerGuard};
 
 /// Creates a mock server and a client (changing the url of the client
@@ -58,3 +58,23 @@ pub async fn mock(method: &str, path: &str, fixture: &str) -> (Client, ServerGua
 
     (client, server)
 }
+
+/// Creates a client replaying the interactions recorded in the cassette,
+/// which can answer a route with a sequence of responses, e.g. the pages
+/// of a list or an error. The cassette is returned to check that every
+/// interaction has been played.
+///
+/// # Arguments
+///
+/// `cassette`: the path to the cassette inside the `cassettes` directory
+pub fn replay(cassette: &str) -> (Client, Cassette) {
+    let file = format!("./tests/fixtures/cassettes/{cassette}.json");
+    let cassette =
+        Cassette::replay(file).unwrap_or_else(|e| panic!("Couldn't read the cassette file {cassette}: {e}"));
+    let client = Client::builder("http://localhost")
+        .middleware(cassette.clone())
+        .build()
+        .expect("Failed to create client");
+
+    (client, cassette)
+}
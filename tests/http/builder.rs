@@ -0,0 +1,75 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Client, ClientBuilder, HTTPError};
+use mockito::Server;
+use reqwest::header::{HeaderName, HeaderValue};
+use rustls::crypto::CryptoProvider;
+
+const CA: &str = "./tests/fixtures/http/tls/ca.pem";
+const IDENTITY: &str = "./tests/fixtures/http/tls/identity.pem";
+
+#[tokio::test]
+async fn test_default_headers() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/playbooks")
+        .match_header("x-request-source", "sync")
+        .match_header("accept", "application/json")
+        .with_status(200)
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = Client::builder(&server.url())
+        .header(
+            HeaderName::from_static("x-request-source"),
+            HeaderValue::from_static("sync"),
+        )
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let res = client.get::<JsonValue>("/v1/playbooks", None).await.unwrap();
+    assert_eq!(res.status, 200);
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_custom_ca_and_identity() {
+    let client = ClientBuilder::new("https://gitlab.company.com")
+        .proxy("http://proxy.company.com:3128")
+        .no_proxy(["localhost", ".svc.cluster.local"])
+        .root_certificate(std::fs::read(CA).unwrap())
+        .identity(std::fs::read(IDENTITY).unwrap())
+        .build();
+    assert!(client.is_ok());
+}
+
+#[test]
+fn test_invalid_identity() {
+    let client = ClientBuilder::new("https://gitlab.company.com")
+        .identity(std::fs::read(CA).unwrap())
+        .build();
+    assert!(matches!(client, Err(HTTPError::Tls(_))));
+}
+
+#[test]
+fn test_keep_the_process_crypto_provider() {
+    let client = ClientBuilder::new("https://gitlab.company.com").build();
+    assert!(client.is_ok());
+    assert!(CryptoProvider::get_default().is_none());
+}
@@ -0,0 +1,153 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Cassette, Client, HTTPError};
+use assert_matches::assert_matches;
+use futures::TryStreamExt;
+use mockito::{Matcher, Server};
+
+#[tokio::test]
+async fn test_record_and_replay() {
+    let path = std::env::temp_dir().join(format!("amp-common-{}-cassette.json", std::process::id()));
+
+    let mut server = Server::new_async().await;
+    for (page, body) in [("1", r#"[{"id":1}]"#), ("2", "[]")] {
+        server
+            .mock("GET", "/projects")
+            .match_query(Matcher::UrlEncoded("page".into(), page.into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("set-cookie", "session=secret")
+            .with_body(body)
+            .create_async()
+            .await;
+    }
+
+    let cassette = Cassette::record(&path);
+    let client = Client::builder(&server.url())
+        .middleware(cassette)
+        .build()
+        .unwrap();
+    for page in ["1", "2"] {
+        let query = [
+            ("page".to_string(), page.to_string()),
+            ("private_token".to_string(), "secret".to_string()),
+        ];
+        let res = client
+            .get::<JsonValue>("/projects", Some(query.into()))
+            .await
+            .unwrap();
+        assert!(res.data.is_some());
+    }
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("secret"));
+
+    // the server is not needed to replay the recorded interactions
+    drop(server);
+    let cassette = Cassette::replay(&path).unwrap();
+    let client = Client::builder("http://localhost")
+        .middleware(cassette.clone())
+        .build()
+        .unwrap();
+
+    let query = [
+        ("page".to_string(), "2".to_string()),
+        ("private_token".to_string(), "other".to_string()),
+    ];
+    let res = client
+        .get::<JsonValue>("/projects", Some(query.into()))
+        .await
+        .unwrap();
+    assert_eq!(res.data.unwrap(), serde_json::json!([]));
+    assert_eq!(cassette.unplayed().len(), 1);
+
+    // every interaction is played once
+    let query = [
+        ("page".to_string(), "2".to_string()),
+        ("private_token".to_string(), "other".to_string()),
+    ];
+    let err = client
+        .get::<JsonValue>("/projects", Some(query.into()))
+        .await
+        .unwrap_err();
+    assert_matches!(err, HTTPError::Cassette(_));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn test_record_tokens_redacted_and_binary_bodies() {
+    let path = std::env::temp_dir().join(format!("amp-common-{}-cassette-bodies.json", std::process::id()));
+    let archive: Vec<u8> = vec![0x1f, 0x8b, 0x08, 0x00, 0xff, 0xfe, 0x00, 0x80];
+
+    let mut server = Server::new_async().await;
+    server
+        .mock("POST", "/app/installations/1/access_tokens")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"token":"ghs_secret","expires_at":"2099-07-11T22:14:10Z"}"#)
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/archive.tar.gz")
+        .with_status(200)
+        .with_header("content-type", "application/gzip")
+        .with_body(archive.clone())
+        .create_async()
+        .await;
+
+    let client = Client::builder(&server.url())
+        .middleware(Cassette::record(&path))
+        .build()
+        .unwrap();
+    let res = client
+        .post::<JsonValue, _>("/app/installations/1/access_tokens", &())
+        .await
+        .unwrap();
+    assert_eq!(res.data.unwrap()["token"], "ghs_secret");
+    let res = client.get_stream("/archive.tar.gz", None, None).await.unwrap();
+    let body: Vec<u8> = res
+        .stream
+        .map_ok(|chunk| chunk.to_vec())
+        .try_concat()
+        .await
+        .unwrap();
+    assert_eq!(body, archive);
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("ghs_secret"));
+
+    drop(server);
+    let client = Client::builder("http://localhost")
+        .middleware(Cassette::replay(&path).unwrap())
+        .build()
+        .unwrap();
+    let res = client
+        .post::<JsonValue, _>("/app/installations/1/access_tokens", &())
+        .await
+        .unwrap();
+    assert_eq!(res.data.unwrap()["expires_at"], "2099-07-11T22:14:10Z");
+    let res = client.get_stream("/archive.tar.gz", None, None).await.unwrap();
+    let body: Vec<u8> = res
+        .stream
+        .map_ok(|chunk| chunk.to_vec())
+        .try_concat()
+        .await
+        .unwrap();
+    assert_eq!(body, archive);
+
+    std::fs::remove_file(path).unwrap();
+}
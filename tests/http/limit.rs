@@ -0,0 +1,132 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Client, ClientBuilder, HTTPError, Transport};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::future::join_all;
+use futures::TryStreamExt;
+use reqwest::{Request, Response};
+
+const BASE_URL: &str = "https://gitlab.com";
+
+/// Answers every request after a while, counting the requests in flight.
+#[derive(Default)]
+struct Counter {
+    sent: AtomicUsize,
+    inflight: AtomicUsize,
+    max_inflight: AtomicUsize,
+}
+
+struct CountingTransport(Arc<Counter>);
+
+#[async_trait]
+impl Transport for CountingTransport {
+    async fn send(&self, _request: Request) -> Result<Response, HTTPError> {
+        let counter = &self.0;
+        counter.sent.fetch_add(1, Ordering::SeqCst);
+        let inflight = counter.inflight.fetch_add(1, Ordering::SeqCst) + 1;
+        counter.max_inflight.fetch_max(inflight, Ordering::SeqCst);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        counter.inflight.fetch_sub(1, Ordering::SeqCst);
+
+        Ok(Response::from(http::Response::new("{}")))
+    }
+}
+
+fn build(counter: &Arc<Counter>, builder: ClientBuilder) -> Client {
+    builder
+        .transport(CountingTransport(counter.clone()))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_limit_concurrency_per_host() {
+    let counter = Arc::new(Counter::default());
+    let client = build(&counter, Client::builder(BASE_URL).max_concurrency_per_host(2));
+
+    let paths: Vec<String> = (0..8).map(|i| format!("/api/v4/projects/{i}")).collect();
+    let requests = paths.iter().map(|path| client.get::<JsonValue>(path, None));
+    assert!(join_all(requests).await.iter().all(Result::is_ok));
+    assert_eq!(counter.sent.load(Ordering::SeqCst), 8);
+    assert_eq!(counter.max_inflight.load(Ordering::SeqCst), 2);
+
+    // the hosts are limited separately
+    let counter = Arc::new(Counter::default());
+    let client = build(&counter, Client::builder(BASE_URL).max_concurrency_per_host(1));
+    let requests = ["https://gitlab.com/api/v4/user", "https://api.github.com/user"]
+        .map(|url| client.get::<JsonValue>(url, None));
+    join_all(requests).await;
+    assert_eq!(counter.max_inflight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_hold_the_slot_until_the_body_is_read() {
+    let counter = Arc::new(Counter::default());
+    let client = build(&counter, Client::builder(BASE_URL).max_concurrency_per_host(1));
+
+    let download = client
+        .get_stream("/api/v4/projects/1/repository/archive", None, None)
+        .await;
+    let request = client.get::<JsonValue>("/api/v4/projects/1", None);
+    let result = tokio::time::timeout(Duration::from_millis(200), request).await;
+    assert!(result.is_err());
+
+    let body: Vec<Bytes> = download.unwrap().stream.try_collect().await.unwrap();
+    assert_eq!(body.concat(), b"{}");
+    assert!(client.get::<JsonValue>("/api/v4/projects/1", None).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_coalesce_identical_gets() {
+    let counter = Arc::new(Counter::default());
+    let client = build(&counter, Client::builder(BASE_URL).coalesce(true));
+
+    let path = "/api/v4/projects/1/repository/commits/master";
+    let requests = (0..10).map(|_| client.get::<JsonValue>(path, None));
+    let responses = join_all(requests).await;
+    assert!(responses.iter().all(|res| res.as_ref().unwrap().data.is_some()));
+    assert_eq!(counter.sent.load(Ordering::SeqCst), 1);
+
+    // requests that are not in flight at the same time are sent again
+    client.get::<JsonValue>(path, None).await.unwrap();
+    assert_eq!(counter.sent.load(Ordering::SeqCst), 2);
+
+    // requests with side effects are never shared
+    let requests = (0..3).map(|_| client.post::<JsonValue, _>(path, &()));
+    join_all(requests).await;
+    assert_eq!(counter.sent.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn test_forget_abandoned_requests() {
+    let counter = Arc::new(Counter::default());
+    let client = build(&counter, Client::builder(BASE_URL).coalesce(true));
+
+    let path = "/api/v4/projects/1/repository/commits/master";
+    let request = client.get::<JsonValue>(path, None);
+    let result = tokio::time::timeout(Duration::from_millis(10), request).await;
+    assert!(result.is_err());
+
+    // the abandoned request is sent again rather than resumed
+    client.get::<JsonValue>(path, None).await.unwrap();
+    assert_eq!(counter.sent.load(Ordering::SeqCst), 2);
+}
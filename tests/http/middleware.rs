@@ -0,0 +1,168 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Mutex};
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{
+    Auth, Client, HTTPError, LoggingMiddleware, Middleware, SensitiveParams, TracingMiddleware,
+};
+use async_trait::async_trait;
+use http::Extensions;
+use mockito::{Matcher, Server};
+use reqwest::header::HeaderValue;
+use reqwest::{Request, Response};
+
+/// Signs the request and records the order the hooks are called in.
+struct Signer {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Middleware for Signer {
+    async fn before(
+        &self,
+        request: &mut Request,
+        _extensions: &mut Extensions,
+    ) -> Result<Option<Response>, HTTPError> {
+        request
+            .headers_mut()
+            .insert("x-signature", HeaderValue::from_static("signed"));
+        self.calls.lock().unwrap().push(format!("before {}", self.name));
+        Ok(None)
+    }
+
+    async fn after(&self, response: Response, _extensions: &mut Extensions) -> Result<Response, HTTPError> {
+        self.calls.lock().unwrap().push(format!("after {}", self.name));
+        Ok(response)
+    }
+}
+
+/// Serves every request from memory.
+struct Cache;
+
+#[async_trait]
+impl Middleware for Cache {
+    async fn before(
+        &self,
+        _request: &mut Request,
+        _extensions: &mut Extensions,
+    ) -> Result<Option<Response>, HTTPError> {
+        let response = http::Response::builder()
+            .status(200)
+            .body(r#"{"cached": true}"#)
+            .unwrap();
+        Ok(Some(Response::from(response)))
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_order() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/playbooks")
+        .match_header("x-signature", "signed")
+        .with_status(200)
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let calls = Arc::new(Mutex::new(vec![]));
+    let client = Client::builder(&server.url())
+        .middleware(TracingMiddleware)
+        .middleware(LoggingMiddleware)
+        .middleware(Signer {
+            name: "a",
+            calls: calls.clone(),
+        })
+        .middleware(Signer {
+            name: "b",
+            calls: calls.clone(),
+        })
+        .build()
+        .unwrap();
+
+    let res = client.get::<JsonValue>("/v1/playbooks", None).await.unwrap();
+    assert_eq!(res.status, 200);
+    mock.assert_async().await;
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec!["before a", "before b", "after b", "after a"]
+    );
+}
+
+#[tokio::test]
+async fn test_middleware_short_circuit() {
+    let mut server = Server::new_async().await;
+    let mock = server.mock("GET", "/v1/playbooks").expect(0).create_async().await;
+
+    let calls = Arc::new(Mutex::new(vec![]));
+    let client = Client::new(&server.url(), None)
+        .unwrap()
+        .with_middleware(Signer {
+            name: "a",
+            calls: calls.clone(),
+        })
+        .with_middleware(Cache)
+        .with_middleware(Signer {
+            name: "b",
+            calls: calls.clone(),
+        });
+
+    let res = client.get::<JsonValue>("/v1/playbooks", None).await.unwrap();
+    assert_eq!(res.data.unwrap()["cached"], true);
+    mock.assert_async().await;
+    assert_eq!(*calls.lock().unwrap(), vec!["before a", "after a"]);
+}
+
+/// Records the redacted URL of every request.
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+#[async_trait]
+impl Middleware for Recorder {
+    async fn before(
+        &self,
+        request: &mut Request,
+        extensions: &mut Extensions,
+    ) -> Result<Option<Response>, HTTPError> {
+        let url = SensitiveParams::of(extensions).redact_url(request.url());
+        self.0.lock().unwrap().push(url.to_string());
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_redact_the_auth_query_param() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/v1/playbooks")
+        .match_query(Matcher::Any)
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let urls = Arc::new(Mutex::new(vec![]));
+    let client = Client::new(&server.url(), None)
+        .unwrap()
+        .with_authentication(Auth::QueryParam {
+            name: "apikey".into(),
+            value: "secret".into(),
+        })
+        .with_middleware(Recorder(urls.clone()));
+
+    client.get::<JsonValue>("/v1/playbooks", None).await.unwrap();
+    let urls = urls.lock().unwrap();
+    assert!(urls[0].ends_with("/v1/playbooks?apikey=***"), "{}", urls[0]);
+}
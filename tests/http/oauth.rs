@@ -0,0 +1,158 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::Duration;
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Auth, Client, OAuth2, OAuth2Token, TokenSource};
+use jiff::{SignedDuration, Timestamp};
+use mockito::{Matcher, Server};
+
+fn token(access_token: &str, expires_at: Timestamp) -> OAuth2Token {
+    OAuth2Token {
+        access_token: access_token.to_string(),
+        refresh_token: Some("refresh-1".to_string()),
+        expires_at: Some(expires_at),
+    }
+}
+
+#[tokio::test]
+async fn test_refresh_expired_token() {
+    let mut server = Server::new_async().await;
+    let refresh = server
+        .mock("POST", "/oauth/token")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("grant_type".to_string(), "refresh_token".to_string()),
+            Matcher::UrlEncoded("refresh_token".to_string(), "refresh-1".to_string()),
+            Matcher::UrlEncoded("client_id".to_string(), "app".to_string()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"access_token": "access-2", "refresh_token": "refresh-2", "expires_in": 7200}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let refreshed = Arc::new(Mutex::new(None));
+    let hook = refreshed.clone();
+    let expired = Timestamp::now() - SignedDuration::from_mins(1);
+    let source = OAuth2::new(
+        &format!("{}/oauth/token", server.url()),
+        "app",
+        None,
+        token("access-1", expired),
+    )
+    .unwrap()
+    .on_refresh(move |token| *hook.lock().unwrap() = Some(token.clone()));
+
+    assert_eq!(source.token().await.unwrap(), "access-2");
+    assert_eq!(source.token().await.unwrap(), "access-2");
+    refresh.assert_async().await;
+
+    let refreshed = refreshed.lock().unwrap().clone().unwrap();
+    assert_eq!(refreshed.refresh_token, Some("refresh-2".to_string()));
+    assert!(!refreshed.expires_soon());
+}
+
+#[tokio::test]
+async fn test_refresh_rejected_token() {
+    let mut server = Server::new_async().await;
+    let rejected = server
+        .mock("GET", "/user")
+        .match_header("authorization", "Bearer access-1")
+        .with_status(401)
+        .expect(1)
+        .create_async()
+        .await;
+    server
+        .mock("POST", "/oauth/token")
+        .with_status(200)
+        .with_body(r#"{"access_token": "access-2", "expires_in": 7200}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let accepted = server
+        .mock("GET", "/user")
+        .match_header("authorization", "Bearer access-2")
+        .with_status(200)
+        .with_body(r#"{"login": "octocat"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let valid = Timestamp::now() + SignedDuration::from_hours(1);
+    let source = OAuth2::new(
+        &format!("{}/oauth/token", server.url()),
+        "app",
+        Some("secret".to_string()),
+        token("access-1", valid),
+    )
+    .unwrap();
+    let source = Arc::new(source);
+    let client = Client::with_auth(&server.url(), Auth::Source(source.clone())).unwrap();
+
+    let res = client.get::<JsonValue>("/user", None).await.unwrap();
+    assert!(res.status.is_success());
+    rejected.assert_async().await;
+    accepted.assert_async().await;
+
+    // the refresh token is kept when the provider does not rotate it.
+    assert_eq!(
+        source.current().await.refresh_token,
+        Some("refresh-1".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_refresh_hook_does_not_hold_the_token() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("POST", "/oauth/token")
+        .with_status(200)
+        .with_body(r#"{"access_token": "access-2", "expires_in": 7200}"#)
+        .create_async()
+        .await;
+
+    // the hook blocks like a slow save of the configuration, until it is released.
+    let (entered, hook_entered) = mpsc::channel();
+    let (release, hook_released) = mpsc::channel::<()>();
+    let hook_released = Mutex::new(hook_released);
+    let expired = Timestamp::now() - SignedDuration::from_mins(1);
+    let source = OAuth2::new(
+        &format!("{}/oauth/token", server.url()),
+        "app",
+        None,
+        token("access-1", expired),
+    )
+    .unwrap()
+    .on_refresh(move |_| {
+        entered.send(()).unwrap();
+        hook_released.lock().unwrap().recv().unwrap();
+    });
+    let source = Arc::new(source);
+
+    let refreshing = tokio::spawn({
+        let source = source.clone();
+        async move { source.token().await }
+    });
+    tokio::task::spawn_blocking(move || hook_entered.recv().unwrap())
+        .await
+        .unwrap();
+
+    let current = tokio::time::timeout(Duration::from_secs(1), source.token()).await;
+    assert_eq!(current.unwrap().unwrap(), "access-2");
+
+    release.send(()).unwrap();
+    assert_eq!(refreshing.await.unwrap().unwrap(), "access-2");
+}
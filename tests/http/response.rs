@@ -0,0 +1,73 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Client, HTTPError};
+use assert_matches::assert_matches;
+use mockito::Server;
+
+#[tokio::test]
+async fn test_response_headers() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/user/repos")
+        .with_status(200)
+        .with_header("etag", r#"W/"f7c5ba8a""#)
+        .with_header("x-ratelimit-remaining", "4999")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let res = client.get::<JsonValue>("/user/repos", None).await.unwrap();
+    assert_eq!(res.headers["etag"], r#"W/"f7c5ba8a""#);
+    assert_eq!(res.headers["x-ratelimit-remaining"], "4999");
+}
+
+#[tokio::test]
+async fn test_error_for_status() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/repos/octocat/missing")
+        .with_status(404)
+        .with_body(r#"{"message": "Not Found", "documentation_url": "https://docs.github.com/rest"}"#)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let res = client
+        .get::<JsonValue>("/repos/octocat/missing", None)
+        .await
+        .unwrap();
+    assert!(res.data.is_none());
+    assert_matches!(
+        res.error_for_status(),
+        Err(HTTPError::Status { status, message, .. }) if status == 404 && message == "Not Found"
+    );
+}
+
+#[tokio::test]
+async fn test_deserialization_error() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/user")
+        .with_status(200)
+        .with_body("<html>Sign in</html>")
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let res = client.get::<JsonValue>("/user", None).await;
+    assert_matches!(res, Err(HTTPError::Deserialization(_)));
+}
@@ -0,0 +1,84 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Client, RetryPolicy};
+use mockito::Server;
+
+fn client(url: &str) -> Client {
+    Client::new(url, None).unwrap().with_retry(RetryPolicy {
+        initial_backoff: Duration::from_millis(1),
+        ..Default::default()
+    })
+}
+
+#[tokio::test]
+async fn test_retry_unavailable() {
+    let mut server = Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/v1/playbooks")
+        .with_status(503)
+        .expect(3)
+        .create_async()
+        .await;
+    let res = client(&server.url())
+        .get::<JsonValue>("/v1/playbooks", None)
+        .await
+        .unwrap();
+    assert_eq!(res.status, 503);
+    unavailable.assert_async().await;
+
+    let mut server = Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/v1/playbooks")
+        .with_status(503)
+        .with_header("retry-after", "0")
+        .expect(1)
+        .create_async()
+        .await;
+    let available = server
+        .mock("GET", "/v1/playbooks")
+        .with_status(200)
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+    let res = client(&server.url())
+        .get::<JsonValue>("/v1/playbooks", None)
+        .await
+        .unwrap();
+    assert_eq!(res.status, 200);
+    unavailable.assert_async().await;
+    available.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_do_not_retry_post() {
+    let mut server = Server::new_async().await;
+    let unavailable = server
+        .mock("POST", "/v1/playbooks")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let res = client(&server.url())
+        .post::<JsonValue, _>("/v1/playbooks", &())
+        .await
+        .unwrap();
+    assert_eq!(res.status, 503);
+    unavailable.assert_async().await;
+}
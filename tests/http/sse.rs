@@ -0,0 +1,197 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use amp_common::http::{Client, EventSourceOptions, HTTPError, RetryPolicy, Transport};
+use assert_matches::assert_matches;
+use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt};
+use mockito::{Matcher, Server};
+use reqwest::{Request, Response};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Progress {
+    step: u32,
+}
+
+#[tokio::test]
+async fn test_reconnect_with_last_event_id() {
+    let mut server = Server::new_async().await;
+    let first = server
+        .mock("GET", "/events")
+        .match_header("accept", "text/event-stream")
+        .match_header("last-event-id", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "text/event-stream")
+        .with_body(
+            "retry: 10\n\nid: 1\ndata: {\"step\":1}\n\nid: 2\ndata: {\"step\":2}\n\nid: 3\ndata: {\"st",
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/events")
+        .match_header("last-event-id", "2")
+        .with_status(200)
+        .with_header("content-type", "text/event-stream")
+        .with_body("id: 3\ndata: {\"step\":3}\n\n")
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/events")
+        .match_header("last-event-id", "3")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let events: Vec<Progress> = client
+        .events("/events", EventSourceOptions::default())
+        .json()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        [Progress { step: 1 }, Progress { step: 2 }, Progress { step: 3 }]
+    );
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+/// Sends the requests to the server, except the second one which is refused
+/// as if the server was restarting.
+struct RestartingTransport {
+    client: reqwest::Client,
+    sent: AtomicUsize,
+}
+
+impl RestartingTransport {
+    fn new() -> Self {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let tls = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_root_certificates(rustls::RootCertStore::empty())
+            .with_no_client_auth();
+        Self {
+            client: reqwest::Client::builder()
+                .tls_backend_preconfigured(tls)
+                .build()
+                .unwrap(),
+            sent: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for RestartingTransport {
+    async fn send(&self, mut request: Request) -> Result<Response, HTTPError> {
+        if self.sent.fetch_add(1, Ordering::SeqCst) == 1 {
+            let port = TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap()
+                .port();
+            request.url_mut().set_port(Some(port)).unwrap();
+        }
+        self.client
+            .execute(request)
+            .await
+            .map_err(HTTPError::ReqwestError)
+    }
+}
+
+#[tokio::test]
+async fn test_retry_refused_reconnection() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/events")
+        .match_header("last-event-id", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "text/event-stream")
+        .with_body("retry: 10\n\nid: 1\ndata: {\"step\":1}\n\n")
+        .create_async()
+        .await;
+    let resumed = server
+        .mock("GET", "/events")
+        .match_header("last-event-id", "1")
+        .with_status(200)
+        .with_header("content-type", "text/event-stream")
+        .with_body("id: 2\ndata: {\"step\":2}\n\n")
+        .create_async()
+        .await;
+
+    let client = Client::builder(&server.url())
+        .transport(RestartingTransport::new())
+        .build()
+        .unwrap()
+        .with_retry(RetryPolicy::none());
+    let options = EventSourceOptions {
+        max_reconnects: Some(2),
+        ..Default::default()
+    };
+    let events: Vec<Progress> = client
+        .events("/events", options)
+        .json()
+        .take(2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(events, [Progress { step: 1 }, Progress { step: 2 }]);
+    resumed.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_max_reconnects() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/events")
+        .with_status(200)
+        .with_header("content-type", "text/event-stream")
+        .with_body(": no events\n\n")
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let options = EventSourceOptions {
+        retry: Some(Duration::from_millis(10)),
+        max_reconnects: Some(2),
+        ..Default::default()
+    };
+    let events: Vec<_> = client.events("/events", options).collect().await;
+    assert!(events.is_empty());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_fail_on_error_status() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/events")
+        .with_status(403)
+        .with_body(r#"{"message":"Forbidden"}"#)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let mut events = client.events("/events", EventSourceOptions::default());
+    assert_matches!(events.next().await, Some(Err(HTTPError::Status { status, .. })) if status == 403);
+    assert!(events.next().await.is_none());
+}
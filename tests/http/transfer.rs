@@ -0,0 +1,151 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Checksum, Client, DownloadOptions, HTTPError, Payload};
+use assert_matches::assert_matches;
+use mockito::{Matcher, Server};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+
+// sha256 of "hello world"
+const DIGEST: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("amp-common-{}-{name}", std::process::id()))
+}
+
+#[tokio::test]
+async fn test_download_to() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/archive.tar.gz")
+        .with_status(200)
+        .with_header("content-type", "application/gzip")
+        .with_body("hello world")
+        .create_async()
+        .await;
+
+    let reports = Arc::new(Mutex::new(vec![]));
+    let callback = reports.clone();
+    let options = DownloadOptions {
+        checksum: Some(Checksum::Sha256(DIGEST.to_string())),
+        progress: Some(Arc::new(move |p| callback.lock().unwrap().push(p))),
+        ..Default::default()
+    };
+
+    let dest = temp_path("download");
+    let client = Client::new(&server.url(), None).unwrap();
+    let size = client
+        .download_to("/archive.tar.gz", &dest, options)
+        .await
+        .unwrap();
+    assert_eq!(size, 11);
+    assert_eq!(std::fs::read(&dest).unwrap(), b"hello world");
+
+    let last = *reports.lock().unwrap().last().unwrap();
+    assert_eq!(last.transferred, 11);
+    assert_eq!(last.total, Some(11));
+
+    std::fs::remove_file(dest).unwrap();
+}
+
+#[tokio::test]
+async fn test_download_to_checksum_mismatch() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/archive.tar.gz")
+        .with_status(200)
+        .with_body("hello world")
+        .create_async()
+        .await;
+
+    let options = DownloadOptions {
+        checksum: Some(Checksum::Sha256("0".repeat(64))),
+        ..Default::default()
+    };
+
+    let dest = temp_path("mismatch");
+    let client = Client::new(&server.url(), None).unwrap();
+    let err = client
+        .download_to("/archive.tar.gz", &dest, options)
+        .await
+        .unwrap_err();
+    assert_matches!(err, HTTPError::Checksum { actual, .. } if actual == DIGEST);
+    assert!(!dest.exists());
+    assert!(!temp_path("mismatch.part").exists());
+}
+
+#[tokio::test]
+async fn test_download_to_error_status() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/archive.tar.gz")
+        .with_status(404)
+        .with_body(r#"{"message":"Not Found"}"#)
+        .create_async()
+        .await;
+
+    let dest = temp_path("not-found");
+    let client = Client::new(&server.url(), None).unwrap();
+    let err = client
+        .download_to("/archive.tar.gz", &dest, DownloadOptions::default())
+        .await
+        .unwrap_err();
+    assert_matches!(err, HTTPError::Status { message, .. } if message == "Not Found");
+    assert!(!dest.exists());
+}
+
+#[tokio::test]
+async fn test_post_stream_from_file() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/assets")
+        .match_query(Matcher::UrlEncoded("name".into(), "asset.txt".into()))
+        .match_header("content-type", "text/plain")
+        .match_header("content-length", "11")
+        .match_body("hello world")
+        .with_status(201)
+        .with_body(r#"{"id":1}"#)
+        .create_async()
+        .await;
+
+    let source = temp_path("upload");
+    std::fs::write(&source, "hello world").unwrap();
+
+    let transferred = Arc::new(Mutex::new(0));
+    let callback = transferred.clone();
+    let payload = Payload::from_file(&source)
+        .await
+        .unwrap()
+        .with_checksum(Checksum::Sha256(DIGEST.to_string()))
+        .with_progress(move |p| *callback.lock().unwrap() = p.transferred);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+    let options = Some([("name".to_string(), "asset.txt".to_string())].into());
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let res = client
+        .post_stream::<JsonValue>("/assets", options, payload, headers)
+        .await
+        .unwrap();
+    assert_eq!(res.status, 201);
+    assert_eq!(*transferred.lock().unwrap(), 11);
+    mock.assert_async().await;
+
+    std::fs::remove_file(source).unwrap();
+}
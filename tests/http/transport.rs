@@ -0,0 +1,92 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use amp_common::api;
+use amp_common::http::endpoint::JsonValue;
+use amp_common::http::{Client, HTTPError, ServiceTransport};
+use assert_matches::assert_matches;
+use http_body_util::BodyExt;
+use reqwest::{Body, Method, StatusCode};
+use tower_service::Service;
+
+/// A stand-in of the Amphitheatre API server, answering in the same process.
+#[derive(Clone)]
+struct StandIn;
+
+impl Service<http::Request<Body>> for StandIn {
+    type Response = http::Response<String>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = body.collect().await.unwrap().to_bytes();
+
+            let (status, body) = match (parts.method, parts.uri.path()) {
+                (Method::GET, "/v1/playbooks") => (
+                    StatusCode::OK,
+                    r#"[{"id":"1a2b3c4d","title":"Example Go","preface":{}}]"#.to_string(),
+                ),
+                (Method::POST, "/echo") => (StatusCode::OK, String::from_utf8(body.to_vec()).unwrap()),
+                _ => (StatusCode::NOT_FOUND, r#"{"message":"Not Found"}"#.to_string()),
+            };
+
+            Ok(http::Response::builder()
+                .status(status)
+                .header("content-type", "application/json")
+                .body(body)
+                .unwrap())
+        })
+    }
+}
+
+fn client() -> Client {
+    Client::builder("http://localhost")
+        .transport(ServiceTransport::new(StandIn))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_in_process_transport() {
+    let client = client();
+    let data = serde_json::json!({"name": "amp-example-go"});
+    let res = client.post::<JsonValue, _>("/echo", &data).await.unwrap();
+    assert_eq!(res.data.unwrap(), data);
+
+    let err = client
+        .get::<JsonValue>("/missing", None)
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap_err();
+    assert_matches!(err, HTTPError::Status { status, .. } if status == 404);
+}
+
+#[tokio::test]
+async fn test_api_client_over_in_process_transport() {
+    let client = api::Client::from(client());
+    let playbooks = client.playbooks().list().await.unwrap();
+    assert_eq!(playbooks[0].title, "Example Go");
+}
@@ -12,5 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod api;
 mod common;
+mod http;
 mod scm;
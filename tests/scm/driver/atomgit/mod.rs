@@ -14,4 +14,6 @@
 
 mod content;
 mod git;
+mod release;
 mod repo;
+mod search;
@@ -0,0 +1,40 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::http::{Client, Payload};
+use amp_common::scm::driver::atomgit::constants::ATOMGIT_ENDPOINT;
+use amp_common::scm::driver::atomgit::release::AtomGitReleaseService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::release::ReleaseService;
+use assert_matches::assert_matches;
+
+const REPO: &str = "jia-hao-li/atomgit_evaluation";
+
+#[tokio::test]
+async fn test_unsupported_operations() {
+    let client = Client::new(ATOMGIT_ENDPOINT, None).unwrap();
+    let service = AtomGitReleaseService { client };
+
+    assert_matches!(service.delete(REPO, "1").await, Err(SCMError::Unsupported(_)));
+    assert_matches!(
+        service.list_assets(REPO, "1").await,
+        Err(SCMError::Unsupported(_))
+    );
+
+    let data = Payload::from(b"hello".to_vec());
+    let result = service
+        .upload_asset(REPO, "1", "hello.txt", "text/plain", data)
+        .await;
+    assert_matches!(result, Err(SCMError::Unsupported(_)));
+}
@@ -0,0 +1,36 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::http::Client;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::atomgit::constants::ATOMGIT_ENDPOINT;
+use amp_common::scm::driver::atomgit::search::AtomGitSearchService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::search::{SearchOptions, SearchService};
+use assert_matches::assert_matches;
+
+#[tokio::test]
+async fn test_unsupported_search() {
+    let client = Client::new(ATOMGIT_ENDPOINT, None).unwrap();
+    let service = AtomGitSearchService { client };
+    let opts = SearchOptions {
+        query: "evaluation".to_string(),
+        ..Default::default()
+    };
+
+    let result = service.code(&opts, ListOptions::default()).await;
+    assert_matches!(result, Err(SCMError::Unsupported(_)));
+    let result = service.repositories(&opts, ListOptions::default()).await;
+    assert_matches!(result, Err(SCMError::Unsupported(_)));
+}
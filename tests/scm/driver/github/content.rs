@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use futures::TryStreamExt;
+
 use crate::common::mock;
 use amp_common::scm::content::ContentService;
 use amp_common::scm::driver::github::constants::GITHUB_PATH_CONTENTS;
@@ -39,3 +41,20 @@ async fn test_find() {
     );
     assert_eq!(content.data, "Hello World!\n".as_bytes());
 }
+
+#[tokio::test]
+async fn test_raw() {
+    let repo = "octocat/Hello-World";
+    let file = "README";
+
+    let path = GITHUB_PATH_CONTENTS
+        .replace("{repo}", repo)
+        .replace("{file}", file);
+    let setup = mock("GET", &path, "scm/github/contents/get-readme-raw-success").await;
+
+    let service = GithubContentService { client: setup.0 };
+    let stream = service.raw(repo, file, "master").await.unwrap();
+
+    let data: Vec<u8> = stream.map_ok(|v| v.to_vec()).try_concat().await.unwrap();
+    assert_eq!(data, "Hello World!\n".as_bytes());
+}
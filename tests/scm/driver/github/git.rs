@@ -13,12 +13,18 @@
 // limitations under the License.
 
 use crate::common::mock;
+use amp_common::http::Client;
+use amp_common::scm::archive::ArchiveFormat;
 use amp_common::scm::client::ListOptions;
 use amp_common::scm::driver::github::constants::{
-    GITHUB_PATH_BRANCHES, GITHUB_PATH_COMMITS, GITHUB_PATH_GIT_TREES, GITHUB_PATH_TAGS,
+    GITHUB_PATH_ARCHIVE, GITHUB_PATH_BRANCH, GITHUB_PATH_BRANCHES, GITHUB_PATH_COMMITS, GITHUB_PATH_GIT_REFS,
+    GITHUB_PATH_GIT_REFS_DELETE, GITHUB_PATH_GIT_TREES, GITHUB_PATH_TAGS,
 };
 use amp_common::scm::driver::github::git::GithubGitService;
-use amp_common::scm::git::GitService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::git::{GitService, ReferenceInput};
+use assert_matches::assert_matches;
+use mockito::Server;
 
 const REPO: &str = "octocat/Hello-World";
 const REFERENCE: &str = "master";
@@ -71,3 +77,76 @@ async fn test_git_trees() {
     let result = service.get_tree(REPO, REFERENCE, Some(true)).await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_find_branch() {
+    let path = GITHUB_PATH_BRANCH
+        .replace("{repo}", REPO)
+        .replace("{branch}", REFERENCE);
+    let setup = mock("GET", &path, "scm/github/git/find-branch-success").await;
+
+    let service = GithubGitService { client: setup.0 };
+    let result = service.find_branch(REPO, REFERENCE).await;
+    assert!(result.is_ok());
+
+    let branch = result.unwrap().unwrap();
+    assert_eq!(branch.path, "refs/heads/master");
+    assert_eq!(branch.sha, "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d");
+}
+
+#[tokio::test]
+async fn test_create_branch() {
+    let path = GITHUB_PATH_GIT_REFS.replace("{repo}", REPO);
+    let setup = mock("POST", &path, "scm/github/git/create-ref-success").await;
+
+    let service = GithubGitService { client: setup.0 };
+    let input = ReferenceInput {
+        name: "preview".to_string(),
+        sha: "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d".to_string(),
+    };
+    let result = service.create_branch(REPO, &input).await;
+    assert!(result.is_ok());
+
+    let branch = result.unwrap();
+    assert_eq!(branch.name, "preview");
+    assert_eq!(branch.path, "refs/heads/preview");
+}
+
+#[tokio::test]
+async fn test_delete_branch() {
+    let path = GITHUB_PATH_GIT_REFS_DELETE
+        .replace("{repo}", REPO)
+        .replace("{ref}", "heads/preview");
+    let setup = mock("DELETE", &path, "scm/github/git/delete-ref-success").await;
+
+    let service = GithubGitService { client: setup.0 };
+    let result = service.delete_branch(REPO, "preview").await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_archive_errors() {
+    let path = GITHUB_PATH_ARCHIVE
+        .replace("{repo}", REPO)
+        .replace("{format}", "tarball")
+        .replace("{reference}", REFERENCE);
+    let mut server = Server::new_async().await;
+    let service = GithubGitService {
+        client: Client::new(&server.url(), None).unwrap(),
+    };
+
+    for (status, kind) in [(404, "not found"), (403, "forbidden")] {
+        let mock = server
+            .mock("GET", path.as_str())
+            .with_status(status)
+            .with_body(format!(r#"{{"message":"{kind}"}}"#))
+            .create_async()
+            .await;
+        let result = service.archive(REPO, REFERENCE, ArchiveFormat::TarGz, None).await;
+        match status {
+            404 => assert_matches!(result.err(), Some(SCMError::NotFound(_))),
+            _ => assert_matches!(result.err(), Some(SCMError::UnexpectedStatus(_, s)) if s == 403),
+        }
+        mock.remove_async().await;
+    }
+}
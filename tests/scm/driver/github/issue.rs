@@ -0,0 +1,55 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::mock;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::github::constants::{GITHUB_PATH_ISSUES, GITHUB_PATH_ISSUE_COMMENTS};
+use amp_common::scm::driver::github::issue::GithubIssueService;
+use amp_common::scm::issue::{CommentInput, IssueService, Thread};
+
+const REPO: &str = "octocat/Hello-World";
+
+#[tokio::test]
+async fn test_list_issues() {
+    let path = GITHUB_PATH_ISSUES.replace("{repo}", REPO);
+    let setup = mock("GET", &path, "scm/github/issue/list-issues-success").await;
+
+    let service = GithubIssueService { client: setup.0 };
+    let result = service.list(REPO, ListOptions::default()).await;
+    assert!(result.is_ok());
+
+    let issues = result.unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].number, 1347);
+    assert_eq!(issues[0].labels, vec!["bug".to_string()]);
+}
+
+#[tokio::test]
+async fn test_create_comment() {
+    let path = GITHUB_PATH_ISSUE_COMMENTS
+        .replace("{repo}", REPO)
+        .replace("{number}", "1347");
+    let setup = mock("POST", &path, "scm/github/issue/create-comment-success").await;
+
+    let service = GithubIssueService { client: setup.0 };
+    let input = CommentInput {
+        body: "Me too".to_string(),
+    };
+    let result = service.create_comment(REPO, Thread::Issue(1347), &input).await;
+    assert!(result.is_ok());
+
+    let comment = result.unwrap();
+    assert_eq!(comment.id, 1);
+    assert_eq!(comment.author.login, "octocat");
+}
@@ -0,0 +1,37 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::mock;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::github::constants::GITHUB_PATH_KEYS;
+use amp_common::scm::driver::github::key::GithubDeployKeyService;
+use amp_common::scm::key::DeployKeyService;
+
+const REPO: &str = "octocat/Hello-World";
+
+#[tokio::test]
+async fn test_list_keys() {
+    let path = GITHUB_PATH_KEYS.replace("{repo}", REPO);
+    let setup = mock("GET", &path, "scm/github/key/list-keys-success").await;
+
+    let service = GithubDeployKeyService { client: setup.0 };
+    let result = service.list(REPO, ListOptions::default()).await;
+    assert!(result.is_ok());
+
+    let keys = result.unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].id, "1");
+    assert_eq!(keys[0].title, "octocat@octomac");
+    assert!(keys[0].read_only);
+}
@@ -12,6 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod app;
 mod content;
 mod git;
+mod issue;
+mod key;
+mod org;
+mod release;
 mod repo;
+mod search;
+mod user;
@@ -0,0 +1,44 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::replay;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::github::org::GithubOrganizationService;
+use amp_common::scm::org::{OrganizationService, Role};
+
+#[tokio::test]
+async fn test_list_members_pages() {
+    let (client, cassette) = replay("scm/github/org/list-members-pages");
+    let service = GithubOrganizationService { client };
+    let page = |page| ListOptions {
+        page,
+        size: 2,
+        ..Default::default()
+    };
+
+    // the last admin and the first member
+    let members = service.list_members("github", page(2)).await.unwrap();
+    let logins: Vec<&str> = members.iter().map(|m| m.login.as_str()).collect();
+    assert_eq!(logins, vec!["hubot", "octocat"]);
+    assert_eq!(members[0].role, Role::Admin);
+    assert_eq!(members[1].role, Role::Member);
+
+    // past the admins, which are counted to find where the page starts
+    let members = service.list_members("github", page(3)).await.unwrap();
+    let logins: Vec<&str> = members.iter().map(|m| m.login.as_str()).collect();
+    assert_eq!(logins, vec!["monalisa", "mona"]);
+    assert!(members.iter().all(|m| m.role == Role::Member));
+
+    assert!(cassette.unplayed().is_empty());
+}
@@ -0,0 +1,38 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::mock;
+use amp_common::scm::driver::github::constants::GITHUB_PATH_RELEASE_BY_TAG;
+use amp_common::scm::driver::github::release::GithubReleaseService;
+use amp_common::scm::release::ReleaseService;
+
+const REPO: &str = "octocat/Hello-World";
+
+#[tokio::test]
+async fn test_find_by_tag() {
+    let path = GITHUB_PATH_RELEASE_BY_TAG
+        .replace("{repo}", REPO)
+        .replace("{tag}", "v1.0.0");
+    let setup = mock("GET", &path, "scm/github/release/find-release-by-tag-success").await;
+
+    let service = GithubReleaseService { client: setup.0 };
+    let result = service.find_by_tag(REPO, "v1.0.0").await;
+    assert!(result.is_ok());
+
+    let release = result.unwrap().unwrap();
+    assert_eq!(release.id, "1");
+    assert_eq!(release.tag, "v1.0.0");
+    assert_eq!(release.version(), "1.0.0");
+    assert!(!release.draft);
+}
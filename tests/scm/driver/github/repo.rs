@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use amp_common::http::Client;
 use amp_common::scm::{
-    driver::github::{constants::GITHUB_PATH_REPOS, repo::GithubRepoService},
-    repo::RepositoryService,
+    driver::github::{
+        constants::{GITHUB_PATH_COLLABORATOR_PERMISSION, GITHUB_PATH_LANGUAGES, GITHUB_PATH_REPOS},
+        repo::GithubRepoService,
+    },
+    errors::SCMError,
+    repo::{Permission, RepositoryService},
 };
+use assert_matches::assert_matches;
+use mockito::Server;
 
 use crate::common::mock;
 
@@ -41,3 +48,59 @@ async fn test_find() {
         DESCRIPTION.unwrap_or_default()
     );
 }
+
+#[tokio::test]
+async fn test_permission() {
+    let path = GITHUB_PATH_COLLABORATOR_PERMISSION
+        .replace("{repo}", REPO)
+        .replace("{user}", "octocat");
+    let setup = mock("GET", &path, "scm/github/repo/get-permission-success").await;
+
+    let service = GithubRepoService { client: setup.0 };
+    let result = service.permission(REPO, "octocat").await;
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), Permission::Write);
+}
+
+#[tokio::test]
+async fn test_permission_errors() {
+    let mut server = Server::new_async().await;
+    let path = GITHUB_PATH_COLLABORATOR_PERMISSION
+        .replace("{repo}", REPO)
+        .replace("{user}", "octocat");
+    server
+        .mock("GET", path.as_str())
+        .with_status(404)
+        .create_async()
+        .await;
+    let path = GITHUB_PATH_COLLABORATOR_PERMISSION
+        .replace("{repo}", REPO)
+        .replace("{user}", "hubot");
+    server
+        .mock("GET", path.as_str())
+        .with_status(403)
+        .create_async()
+        .await;
+
+    let service = GithubRepoService {
+        client: Client::new(&server.url(), None).unwrap(),
+    };
+    let result = service.permission(REPO, "octocat").await;
+    assert_eq!(result.unwrap(), Permission::None);
+    let result = service.permission(REPO, "hubot").await;
+    assert_matches!(result, Err(SCMError::UnexpectedStatus(_, status)) if status == 403);
+}
+
+#[tokio::test]
+async fn test_languages() {
+    let path = GITHUB_PATH_LANGUAGES.replace("{repo}", REPO);
+    let setup = mock("GET", &path, "scm/github/repo/list-languages-success").await;
+
+    let service = GithubRepoService { client: setup.0 };
+    let result = service.languages(REPO).await;
+    assert!(result.is_ok());
+
+    let languages = result.unwrap();
+    assert!((languages["C"] - 91.02).abs() < 0.01);
+    assert!((languages.values().sum::<f64>() - 100.0).abs() < 1e-9);
+}
@@ -0,0 +1,45 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::mock;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::github::constants::GITHUB_PATH_SEARCH_CODE;
+use amp_common::scm::driver::github::search::GithubSearchService;
+use amp_common::scm::search::{SearchOptions, SearchScope, SearchService};
+
+#[tokio::test]
+async fn test_search_code() {
+    let setup = mock(
+        "GET",
+        GITHUB_PATH_SEARCH_CODE,
+        "scm/github/search/search-code-success",
+    )
+    .await;
+
+    let service = GithubSearchService { client: setup.0 };
+    assert!(service.capabilities().code);
+
+    let opts = SearchOptions {
+        query: "filename:.amp.toml".to_string(),
+        scope: SearchScope::Organization("amphitheatre-app".to_string()),
+        path: None,
+    };
+    let result = service.code(&opts, ListOptions::default()).await;
+    assert!(result.is_ok());
+
+    let files = result.unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].repo, "amphitheatre-app/amp-example-rust");
+    assert_eq!(files[0].path, ".amp.toml");
+}
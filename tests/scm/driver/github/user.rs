@@ -0,0 +1,51 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::mock;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::github::constants::{GITHUB_PATH_USER, GITHUB_PATH_USER_EMAILS};
+use amp_common::scm::driver::github::user::GithubUserService;
+use amp_common::scm::user::UserService;
+
+#[tokio::test]
+async fn test_find() {
+    let setup = mock("GET", GITHUB_PATH_USER, "scm/github/user/find-user-success").await;
+
+    let service = GithubUserService { client: setup.0 };
+    let result = service.find().await;
+    assert!(result.is_ok());
+
+    let user = result.unwrap().unwrap();
+    assert_eq!(user.login, "octocat");
+    assert_eq!(user.name, "The Octocat");
+    assert_eq!(user.email, None);
+}
+
+#[tokio::test]
+async fn test_list_emails() {
+    let setup = mock(
+        "GET",
+        GITHUB_PATH_USER_EMAILS,
+        "scm/github/user/list-emails-success",
+    )
+    .await;
+
+    let service = GithubUserService { client: setup.0 };
+    let result = service.list_emails(ListOptions::default()).await;
+    assert!(result.is_ok());
+
+    let emails = result.unwrap();
+    assert_eq!(emails.len(), 2);
+    assert!(emails[0].primary);
+}
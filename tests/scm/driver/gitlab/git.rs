@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::common::mock;
+use crate::common::{mock, replay};
+use amp_common::http::Client;
 use amp_common::scm::client::ListOptions;
 use amp_common::scm::driver::gitlab::constants::{
-    GITLAB_PATH_BRANCHES, GITLAB_PATH_COMMITS, GITLAB_PATH_TAGS,
+    GITLAB_PATH_BRANCHES, GITLAB_PATH_COMMITS, GITLAB_PATH_TAGS, GITLAB_PATH_TREE,
 };
 use amp_common::scm::driver::gitlab::git::GitlabGitService;
 use amp_common::scm::driver::gitlab::utils::encode;
-use amp_common::scm::git::GitService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::git::{GitService, TagInput};
+use assert_matches::assert_matches;
+use mockito::{Matcher, Server};
 
 const REPO: &str = "gitlab-org/gitlab-test";
 const REFERENCE: &str = "master";
@@ -45,6 +49,25 @@ async fn test_list_tags() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_list_tags_pages() {
+    let (client, cassette) = replay("scm/gitlab/git/list-tags-pages");
+
+    let service = GitlabGitService { client };
+    let mut names = vec![];
+    for page in 1..=2 {
+        let opts = ListOptions {
+            page,
+            size: 2,
+            ..Default::default()
+        };
+        let tags = service.list_tags(REPO, opts).await.unwrap();
+        names.extend(tags.into_iter().map(|v| v.name));
+    }
+    assert_eq!(names, ["v1.1.1", "v1.1.0", "v1.0.0"]);
+    assert!(cassette.unplayed().is_empty());
+}
+
 #[tokio::test]
 async fn test_find_commit() {
     let path = GITLAB_PATH_COMMITS
@@ -59,3 +82,78 @@ async fn test_find_commit() {
     let commit = result.unwrap().unwrap();
     assert_eq!(commit.sha, "ddd0f15ae83993f5cb66a927a28673882e99100b".to_string());
 }
+
+#[tokio::test]
+async fn test_find_commit_not_found() {
+    let (client, cassette) = replay("scm/gitlab/git/find-commit-not-found");
+
+    let service = GitlabGitService { client };
+    let result = service.find_commit(REPO, "missing").await;
+    assert!(result.unwrap().is_none());
+    assert!(cassette.unplayed().is_empty());
+}
+
+#[tokio::test]
+async fn test_git_trees() {
+    let path = GITLAB_PATH_TREE.replace("{repo}", &encode(REPO));
+    let setup = mock("GET", &path, "scm/gitlab/git/list-tree-success").await;
+
+    let service = GitlabGitService { client: setup.0 };
+    let result = service.get_tree(REPO, REFERENCE, Some(true)).await;
+    assert!(result.is_ok());
+
+    let tree = result.unwrap().unwrap();
+    assert_eq!(tree.tree.len(), 3);
+    assert!(tree
+        .tree
+        .iter()
+        .any(|v| v.path == "files/example.txt" && v.kind == "blob"));
+}
+
+#[tokio::test]
+async fn test_git_trees_fail_on_a_later_page() {
+    let path = GITLAB_PATH_TREE.replace("{repo}", &encode(REPO));
+    let entries: Vec<String> = (0..100)
+        .map(|i| format!(r#"{{"id":"{i}","name":"{i}.txt","type":"blob","path":"{i}.txt","mode":"100644"}}"#))
+        .collect();
+
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", path.as_str())
+        .match_query(Matcher::UrlEncoded("page".to_string(), "1".to_string()))
+        .with_status(200)
+        .with_body(format!("[{}]", entries.join(",")))
+        .create_async()
+        .await;
+    server
+        .mock("GET", path.as_str())
+        .match_query(Matcher::UrlEncoded("page".to_string(), "2".to_string()))
+        .with_status(429)
+        .with_body(r#"{"message":"Too Many Requests"}"#)
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), None).unwrap();
+    let service = GitlabGitService { client };
+    let result = service.get_tree(REPO, REFERENCE, Some(true)).await;
+    assert_matches!(result, Err(SCMError::UnexpectedStatus(_, status)) if status == 429);
+}
+
+#[tokio::test]
+async fn test_create_tag() {
+    let path = GITLAB_PATH_TAGS.replace("{repo}", &encode(REPO));
+    let setup = mock("POST", &path, "scm/gitlab/git/create-tag-success").await;
+
+    let service = GitlabGitService { client: setup.0 };
+    let input = TagInput {
+        name: "v1.0.0".to_string(),
+        sha: REFERENCE.to_string(),
+        message: Some("Release v1.0.0".to_string()),
+    };
+    let result = service.create_tag(REPO, &input).await;
+    assert!(result.is_ok());
+
+    let tag = result.unwrap();
+    assert_eq!(tag.path, "refs/tags/v1.0.0");
+    assert_eq!(tag.sha, "ddd0f15ae83993f5cb66a927a28673882e99100b");
+}
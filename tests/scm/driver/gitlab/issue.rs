@@ -0,0 +1,40 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::mock;
+use amp_common::scm::driver::gitlab::constants::GITLAB_PATH_MERGE_REQUEST_NOTES;
+use amp_common::scm::driver::gitlab::issue::GitlabIssueService;
+use amp_common::scm::driver::gitlab::utils::encode;
+use amp_common::scm::issue::{CommentInput, IssueService, Thread};
+
+const REPO: &str = "gitlab-org/gitlab-test";
+
+#[tokio::test]
+async fn test_create_merge_request_comment() {
+    let path = GITLAB_PATH_MERGE_REQUEST_NOTES
+        .replace("{repo}", &encode(REPO))
+        .replace("{number}", "1");
+    let setup = mock("POST", &path, "scm/gitlab/issue/create-note-success").await;
+
+    let service = GitlabIssueService { client: setup.0 };
+    let input = CommentInput {
+        body: "Preview is ready".to_string(),
+    };
+    let result = service.create_comment(REPO, Thread::PullRequest(1), &input).await;
+    assert!(result.is_ok());
+
+    let comment = result.unwrap();
+    assert_eq!(comment.id, 302);
+    assert_eq!(comment.author.login, "pipin");
+}
@@ -14,4 +14,7 @@
 
 mod content;
 mod git;
+mod issue;
+mod org;
 mod repo;
+mod search;
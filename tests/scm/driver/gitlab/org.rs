@@ -0,0 +1,36 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::mock;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::gitlab::constants::GITLAB_PATH_GROUP_MEMBERS;
+use amp_common::scm::driver::gitlab::org::GitlabOrganizationService;
+use amp_common::scm::driver::gitlab::utils::encode;
+use amp_common::scm::org::{OrganizationService, Role};
+
+const GROUP: &str = "gitlab-org";
+
+#[tokio::test]
+async fn test_list_members() {
+    let path = GITLAB_PATH_GROUP_MEMBERS.replace("{group}", &encode(GROUP));
+    let setup = mock("GET", &path, "scm/gitlab/org/list-members-success").await;
+
+    let service = GitlabOrganizationService { client: setup.0 };
+    let result = service.list_members(GROUP, ListOptions::default()).await;
+    assert!(result.is_ok());
+
+    let members = result.unwrap();
+    assert_eq!(members[0].role, Role::Admin);
+    assert_eq!(members[1].role, Role::Member);
+}
@@ -13,8 +13,12 @@
 // limitations under the License.
 
 use amp_common::scm::{
-    driver::gitlab::{constants::GITLAB_PATH_REPOS, repo::GitlabRepoService, utils::encode},
-    repo::RepositoryService,
+    driver::gitlab::{
+        constants::{GITLAB_PATH_LANGUAGES, GITLAB_PATH_MEMBERS_ALL, GITLAB_PATH_REPOS},
+        repo::GitlabRepoService,
+        utils::encode,
+    },
+    repo::{Permission, RepositoryService},
 };
 
 use crate::common::mock;
@@ -41,3 +45,27 @@ async fn test_find() {
         DESCRIPTION.unwrap_or_default()
     );
 }
+
+#[tokio::test]
+async fn test_permission() {
+    let path = GITLAB_PATH_MEMBERS_ALL.replace("{repo}", &encode(REPO));
+    let setup = mock("GET", &path, "scm/gitlab/repo/list-members-all-success").await;
+
+    let service = GitlabRepoService { client: setup.0 };
+    let result = service.permission(REPO, "raymond_smith").await;
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), Permission::Write);
+}
+
+#[tokio::test]
+async fn test_languages() {
+    let path = GITLAB_PATH_LANGUAGES.replace("{repo}", &encode(REPO));
+    let setup = mock("GET", &path, "scm/gitlab/repo/list-languages-success").await;
+
+    let service = GitlabRepoService { client: setup.0 };
+    let result = service.languages(REPO).await;
+    assert!(result.is_ok());
+
+    let languages = result.unwrap();
+    assert_eq!(languages.get("Ruby"), Some(&66.69));
+}
@@ -0,0 +1,70 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::http::Client;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::gitlab::constants::{GITLAB_PATH_GROUP_SEARCH, GITLAB_PATH_SEARCH};
+use amp_common::scm::driver::gitlab::search::GitlabSearchService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::search::{SearchOptions, SearchScope, SearchService};
+use assert_matches::assert_matches;
+use mockito::{Matcher, Server};
+
+#[tokio::test]
+async fn test_code_search_without_advanced_search() {
+    let path = GITLAB_PATH_GROUP_SEARCH.replace("{group}", "gitlab-org");
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", path.as_str())
+        .match_query(Matcher::UrlEncoded("scope".into(), "blobs".into()))
+        .with_status(400)
+        .with_body(r#"{"error":"Scope supported only with advanced search or exact code search"}"#)
+        .create_async()
+        .await;
+
+    let service = GitlabSearchService {
+        client: Client::new(&server.url(), None).unwrap(),
+    };
+    let opts = SearchOptions {
+        query: "filename:.amp.toml".to_string(),
+        scope: SearchScope::Organization("gitlab-org".to_string()),
+        ..Default::default()
+    };
+    let result = service.code(&opts, ListOptions::default()).await;
+    assert_matches!(result, Err(SCMError::Unsupported(_)));
+}
+
+#[tokio::test]
+async fn test_search_fails_with_the_status() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", GITLAB_PATH_SEARCH)
+        .match_query(Matcher::Any)
+        .with_status(403)
+        .with_body(r#"{"message":"403 Forbidden"}"#)
+        .create_async()
+        .await;
+
+    let service = GitlabSearchService {
+        client: Client::new(&server.url(), None).unwrap(),
+    };
+    let opts = SearchOptions {
+        query: "amp".to_string(),
+        ..Default::default()
+    };
+    let result = service.code(&opts, ListOptions::default()).await;
+    assert_matches!(result, Err(SCMError::UnexpectedStatus(_, status)) if status == 403);
+    let result = service.repositories(&opts, ListOptions::default()).await;
+    assert_matches!(result, Err(SCMError::UnexpectedStatus(_, status)) if status == 403);
+}
@@ -0,0 +1,74 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use amp_common::http::Client;
+use amp_common::scm::driver::gogs::constants::{GOGS_ENDPOINT, GOGS_PATH_TAGS};
+use amp_common::scm::driver::gogs::driver::Flavour;
+use amp_common::scm::driver::gogs::git::GogsService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::git::{GitService, ReferenceInput, TagInput};
+use assert_matches::assert_matches;
+
+use crate::common::mock;
+
+const REPO: &str = "gogs/gogs";
+
+#[tokio::test]
+async fn test_find_tag_in_the_list_of_tags() {
+    let path = GOGS_PATH_TAGS.replace("{repo}", REPO);
+    let setup = mock("GET", &path, "scm/gogs/git/list-tags-success").await;
+
+    let service = GogsService {
+        client: setup.0,
+        flavour: Flavour::Gogs,
+    };
+    let tag = service.find_tag(REPO, "v0.11.91").await.unwrap().unwrap();
+    assert_eq!(tag.path, "refs/tags/v0.11.91");
+    assert_eq!(tag.sha, "0e7cfd6e3a4c6e0b1d8fd6a3f1fa0b1d8a7c6e91");
+    assert!(service.find_tag(REPO, "v1.0.0").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_unsupported_branch_and_tag_writes() {
+    let client = Client::new(GOGS_ENDPOINT, None).unwrap();
+    let service = GogsService {
+        client,
+        flavour: Flavour::Gogs,
+    };
+
+    let branch = ReferenceInput {
+        name: "feature".to_string(),
+        sha: "5f7b6d9ba0d6a6e3b3bb6e1ec0b4ee2f7b87e5c1".to_string(),
+    };
+    let tag = TagInput {
+        name: "v1.0.0".to_string(),
+        sha: "5f7b6d9ba0d6a6e3b3bb6e1ec0b4ee2f7b87e5c1".to_string(),
+        message: None,
+    };
+    assert_matches!(
+        service.create_branch(REPO, &branch).await,
+        Err(SCMError::Unsupported(_))
+    );
+    assert_matches!(
+        service.delete_branch(REPO, "feature").await,
+        Err(SCMError::Unsupported(_))
+    );
+    assert_matches!(
+        service.create_tag(REPO, &tag).await,
+        Err(SCMError::Unsupported(_))
+    );
+    assert_matches!(
+        service.delete_tag(REPO, "v1.0.0").await,
+        Err(SCMError::Unsupported(_))
+    );
+}
@@ -0,0 +1,54 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use amp_common::http::{Client, Payload};
+use amp_common::scm::driver::gogs::constants::GOGS_ENDPOINT;
+use amp_common::scm::driver::gogs::driver::Flavour;
+use amp_common::scm::driver::gogs::release::GogsReleaseService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::release::{ReleaseInput, ReleaseService};
+use assert_matches::assert_matches;
+
+const REPO: &str = "gogs/gogs";
+
+#[tokio::test]
+async fn test_unsupported_release_operations() {
+    let client = Client::new(GOGS_ENDPOINT, None).unwrap();
+    let service = GogsReleaseService {
+        client,
+        flavour: Flavour::Gogs,
+    };
+
+    let input = ReleaseInput::default();
+    assert_matches!(
+        service.find_by_tag(REPO, "v0.12.0").await,
+        Err(SCMError::Unsupported(_))
+    );
+    assert_matches!(service.latest(REPO).await, Err(SCMError::Unsupported(_)));
+    assert_matches!(service.create(REPO, &input).await, Err(SCMError::Unsupported(_)));
+    assert_matches!(
+        service.update(REPO, "1", &input).await,
+        Err(SCMError::Unsupported(_))
+    );
+    assert_matches!(service.delete(REPO, "1").await, Err(SCMError::Unsupported(_)));
+    assert_matches!(
+        service.list_assets(REPO, "1").await,
+        Err(SCMError::Unsupported(_))
+    );
+
+    let data = Payload::from(b"binary".to_vec());
+    let result = service
+        .upload_asset(REPO, "1", "gogs.zip", "application/zip", data)
+        .await;
+    assert_matches!(result, Err(SCMError::Unsupported(_)));
+}
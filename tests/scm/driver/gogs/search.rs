@@ -0,0 +1,35 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::http::Client;
+use amp_common::scm::client::ListOptions;
+use amp_common::scm::driver::gogs::constants::GOGS_ENDPOINT;
+use amp_common::scm::driver::gogs::search::GogsSearchService;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::search::{SearchOptions, SearchService};
+use assert_matches::assert_matches;
+
+#[tokio::test]
+async fn test_unsupported_code_search() {
+    let client = Client::new(GOGS_ENDPOINT, None).unwrap();
+    let service = GogsSearchService { client };
+    assert!(!service.capabilities().code);
+
+    let opts = SearchOptions {
+        query: "func main".to_string(),
+        ..Default::default()
+    };
+    let result = service.code(&opts, ListOptions::default()).await;
+    assert_matches!(result, Err(SCMError::Unsupported(_)));
+}
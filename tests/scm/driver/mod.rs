@@ -15,3 +15,4 @@
 mod atomgit;
 mod github;
 mod gitlab;
+mod gogs;
@@ -0,0 +1,103 @@
+// Copyright (c) The Amphitheatre Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use amp_common::http::Client;
+use amp_common::scm::errors::SCMError;
+use amp_common::scm::lfs::{download, Pointer};
+use assert_matches::assert_matches;
+use futures::TryStreamExt;
+use mockito::{Matcher, Server};
+
+const OID: &str = "4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393";
+
+#[tokio::test]
+async fn test_download_without_provider_credentials() {
+    let mut server = Server::new_async().await;
+    let batch = server
+        .mock("POST", "/octocat/Hello-World.git/info/lfs/objects/batch")
+        .match_header("authorization", "Bearer AUTH_TOKEN")
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"objects": [{{"oid": "{OID}", "size": 5, "actions": {{"download": {{
+                "href": "{}/storage/{OID}?X-Amz-Signature=abc",
+                "header": {{"x-amz-content-sha256": "UNSIGNED-PAYLOAD"}}
+            }}}}}}]}}"#,
+            server.url()
+        ))
+        .create_async()
+        .await;
+    let storage = server
+        .mock("GET", format!("/storage/{OID}").as_str())
+        .match_query(Matcher::UrlEncoded("X-Amz-Signature".into(), "abc".into()))
+        .match_header("x-amz-content-sha256", "UNSIGNED-PAYLOAD")
+        .match_header("authorization", Matcher::Missing)
+        .with_status(200)
+        .with_body("hello")
+        .create_async()
+        .await;
+
+    let client = Client::new(&server.url(), Some("AUTH_TOKEN".to_string())).unwrap();
+    let clone_url = format!("{}/octocat/Hello-World", server.url());
+    let pointer = Pointer {
+        oid: OID.to_string(),
+        size: 5,
+    };
+    let stream = download(&client, &clone_url, &pointer).await.unwrap();
+    let data: Vec<u8> = stream.map_ok(|v| v.to_vec()).try_concat().await.unwrap();
+    assert_eq!(data, b"hello");
+
+    batch.assert_async().await;
+    storage.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_download_fails_with_the_status() {
+    let mut server = Server::new_async().await;
+    let clone_url = format!("{}/octocat/Hello-World", server.url());
+    let client = Client::new(&server.url(), Some("AUTH_TOKEN".to_string())).unwrap();
+    let pointer = Pointer {
+        oid: OID.to_string(),
+        size: 5,
+    };
+
+    let batch = server
+        .mock("POST", "/octocat/Hello-World.git/info/lfs/objects/batch")
+        .with_status(401)
+        .with_body(r#"{"message": "Credentials needed"}"#)
+        .create_async()
+        .await;
+    let result = download(&client, &clone_url, &pointer).await;
+    assert_matches!(result.err(), Some(SCMError::UnexpectedStatus(_, status)) if status == 401);
+    batch.remove_async().await;
+
+    server
+        .mock("POST", "/octocat/Hello-World.git/info/lfs/objects/batch")
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"objects": [{{"oid": "{OID}", "size": 5, "actions": {{"download": {{
+                "href": "{}/storage/{OID}"
+            }}}}}}]}}"#,
+            server.url()
+        ))
+        .create_async()
+        .await;
+    server
+        .mock("GET", format!("/storage/{OID}").as_str())
+        .with_status(403)
+        .with_body("Request has expired")
+        .create_async()
+        .await;
+    let result = download(&client, &clone_url, &pointer).await;
+    assert_matches!(result.err(), Some(SCMError::UnexpectedStatus(_, status)) if status == 403);
+}